@@ -761,6 +761,7 @@ impl ComponentSchema {
         let mut tokens = TokenStream::new();
         let mut name_tokens = TokenStream::new();
         let mut schema_references = Vec::<SchemaReference>::new();
+        let outer_features = Self::pop_outer_schema_features(type_tree, &mut features)?;
 
         match type_tree.generic_type {
             Some(GenericType::Map) => ComponentSchema::map_to_tokens(
@@ -875,6 +876,17 @@ impl ComponentSchema {
             )?,
         };
 
+        if let Some(outer_features) = outer_features {
+            tokens = quote! {
+                utoipa::openapi::schema::AllOfBuilder::new()
+                    .item(#tokens)
+                    .item(utoipa::openapi::ObjectBuilder::new()
+                        .schema_type(utoipa::openapi::schema::SchemaType::AnyValue)
+                        #outer_features
+                    )
+            };
+        }
+
         Ok(Self {
             tokens,
             name_tokens,
@@ -882,6 +894,37 @@ impl ComponentSchema {
         })
     }
 
+    /// Pop `const` and `not` features for schemas that cannot carry them directly. E.g. arrays,
+    /// maps and references will be wrapped in `allOf` with the features in separate schema so that
+    /// they apply to the whole value instead of being lost or forwarded to the items.
+    fn pop_outer_schema_features(
+        type_tree: &TypeTree,
+        features: &mut Vec<Feature>,
+    ) -> Result<Option<TokenStream>, Diagnostics> {
+        let is_outer = match type_tree.generic_type {
+            Some(
+                GenericType::Map | GenericType::Vec | GenericType::LinkedList | GenericType::Set,
+            ) => true,
+            #[cfg(feature = "smallvec")]
+            Some(GenericType::SmallVec) => true,
+            None => matches!(type_tree.value_type, ValueType::Object | ValueType::Tuple),
+            _ => false,
+        };
+        if !is_outer {
+            return Ok(None);
+        }
+
+        let const_value = pop_feature!(features => Feature::Const(_));
+        let not = pop_feature!(features => Feature::Not(_));
+        if const_value.is_none() && not.is_none() {
+            return Ok(None);
+        }
+
+        let const_value_tokens = const_value.try_to_token_stream()?;
+        let not_tokens = not.try_to_token_stream()?;
+        Ok(Some(quote! { #const_value_tokens #not_tokens }))
+    }
+
     /// Create `.schema_type(...)` override token stream if nullable is true from given [`SchemaTypeInner`].
     fn get_schema_type_override(
         nullable: Option<Nullable>,
@@ -1156,10 +1199,15 @@ impl ComponentSchema {
                 // since OpenAPI 3.1 the type is an array, thus nullable should not be necessary
                 // for value type that is going to allow all types of content.
                 if type_tree.is_value() {
+                    let const_value = pop_feature!(features => Feature::Const(_));
+                    let not = pop_feature!(features => Feature::Not(_));
+                    let const_value_tokens = as_tokens_or_diagnostics!(&const_value);
+                    let not_tokens = as_tokens_or_diagnostics!(&not);
                     tokens.extend(quote! {
                         utoipa::openapi::ObjectBuilder::new()
                             .schema_type(utoipa::openapi::schema::SchemaType::AnyValue)
                             #description_stream #deprecated
+                            #const_value_tokens #not_tokens
                     })
                 }
             }
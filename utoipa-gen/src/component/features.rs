@@ -76,6 +76,7 @@ pub trait Parse {
 pub enum Feature {
     Example(attributes::Example),
     Examples(attributes::Examples),
+    Const(attributes::Const),
     Default(attributes::Default),
     Inline(attributes::Inline),
     XmlAttr(attributes::XmlAttr),
@@ -92,11 +93,14 @@ pub enum Feature {
     Explode(attributes::Explode),
     ParameterIn(attributes::ParameterIn),
     IntoParamsNames(attributes::IntoParamsNames),
+    IntoParamsComponent(attributes::IntoParamsComponent),
     SchemaWith(attributes::SchemaWith),
     Description(attributes::Description),
     Deprecated(attributes::Deprecated),
     As(attributes::As),
     AdditionalProperties(attributes::AdditionalProperties),
+    Not(attributes::Not),
+    PatternProperties(attributes::PatternProperties),
     Required(attributes::Required),
     ContentEncoding(attributes::ContentEncoding),
     ContentMediaType(attributes::ContentMediaType),
@@ -173,6 +177,7 @@ impl ToTokensDiagnostics for Feature {
             Feature::Default(default) => quote! { .default(#default) },
             Feature::Example(example) => quote! { .example(Some(#example)) },
             Feature::Examples(examples) => quote! { .examples(#examples) },
+            Feature::Const(const_value) => quote! { .const_value(Some(#const_value)) },
             Feature::XmlAttr(xml) => quote! { .xml(Some(#xml)) },
             Feature::Format(format) => quote! { .format(Some(#format)) },
             Feature::WriteOnly(write_only) => quote! { .write_only(Some(#write_only)) },
@@ -212,6 +217,8 @@ impl ToTokensDiagnostics for Feature {
             Feature::AdditionalProperties(additional_properties) => {
                 quote! { .additional_properties(Some(#additional_properties)) }
             }
+            Feature::Not(not) => quote! { .not(Some(#not)) },
+            Feature::PatternProperties(pattern_properties) => pattern_properties.to_token_stream(),
             Feature::ContentEncoding(content_encoding) => quote! { .content_encoding(#content_encoding) },
             Feature::ContentMediaType(content_media_type) => quote! { .content_media_type(#content_media_type) },
             Feature::Discriminator(discriminator) => quote! { .discriminator(Some(#discriminator)) },
@@ -235,6 +242,10 @@ impl ToTokensDiagnostics for Feature {
                 return Err(Diagnostics::new("Names feature does not support `ToTokens`")
                     .help("Names is only used with IntoParams to artificially give names for unnamed struct type `IntoParams`."))
             }
+            Feature::IntoParamsComponent(_) => {
+                return Err(Diagnostics::new("Component feature does not support `ToTokens`")
+                    .help("Component is only used with IntoParams to register parameters as reusable components."))
+            }
             Feature::As(_) => {
                 return Err(Diagnostics::new("As does not support `ToTokens`"))
             }
@@ -267,6 +278,7 @@ impl Display for Feature {
             Feature::Default(default) => default.fmt(f),
             Feature::Example(example) => example.fmt(f),
             Feature::Examples(examples) => examples.fmt(f),
+            Feature::Const(const_value) => const_value.fmt(f),
             Feature::XmlAttr(xml) => xml.fmt(f),
             Feature::Format(format) => format.fmt(f),
             Feature::WriteOnly(write_only) => write_only.fmt(f),
@@ -282,6 +294,7 @@ impl Display for Feature {
             Feature::ValueType(value_type) => value_type.fmt(f),
             Feature::Inline(inline) => inline.fmt(f),
             Feature::IntoParamsNames(names) => names.fmt(f),
+            Feature::IntoParamsComponent(component) => component.fmt(f),
             Feature::MultipleOf(multiple_of) => multiple_of.fmt(f),
             Feature::Maximum(maximum) => maximum.fmt(f),
             Feature::Minimum(minimum) => minimum.fmt(f),
@@ -299,6 +312,8 @@ impl Display for Feature {
             Feature::Deprecated(deprecated) => deprecated.fmt(f),
             Feature::As(as_feature) => as_feature.fmt(f),
             Feature::AdditionalProperties(additional_properties) => additional_properties.fmt(f),
+            Feature::Not(not) => not.fmt(f),
+            Feature::PatternProperties(pattern_properties) => pattern_properties.fmt(f),
             Feature::Required(required) => required.fmt(f),
             Feature::ContentEncoding(content_encoding) => content_encoding.fmt(f),
             Feature::ContentMediaType(content_media_type) => content_media_type.fmt(f),
@@ -316,6 +331,7 @@ impl Validatable for Feature {
             Feature::Default(default) => default.is_validatable(),
             Feature::Example(example) => example.is_validatable(),
             Feature::Examples(examples) => examples.is_validatable(),
+            Feature::Const(const_value) => const_value.is_validatable(),
             Feature::XmlAttr(xml) => xml.is_validatable(),
             Feature::Format(format) => format.is_validatable(),
             Feature::WriteOnly(write_only) => write_only.is_validatable(),
@@ -331,6 +347,7 @@ impl Validatable for Feature {
             Feature::ValueType(value_type) => value_type.is_validatable(),
             Feature::Inline(inline) => inline.is_validatable(),
             Feature::IntoParamsNames(names) => names.is_validatable(),
+            Feature::IntoParamsComponent(component) => component.is_validatable(),
             Feature::MultipleOf(multiple_of) => multiple_of.is_validatable(),
             Feature::Maximum(maximum) => maximum.is_validatable(),
             Feature::Minimum(minimum) => minimum.is_validatable(),
@@ -350,6 +367,8 @@ impl Validatable for Feature {
             Feature::AdditionalProperties(additional_properties) => {
                 additional_properties.is_validatable()
             }
+            Feature::Not(not) => not.is_validatable(),
+            Feature::PatternProperties(pattern_properties) => pattern_properties.is_validatable(),
             Feature::Required(required) => required.is_validatable(),
             Feature::ContentEncoding(content_encoding) => content_encoding.is_validatable(),
             Feature::ContentMediaType(content_media_type) => content_media_type.is_validatable(),
@@ -379,6 +398,7 @@ is_validatable! {
     attributes::Default,
     attributes::Example,
     attributes::Examples,
+    attributes::Const,
     attributes::XmlAttr,
     attributes::Format,
     attributes::WriteOnly,
@@ -394,11 +414,14 @@ is_validatable! {
     attributes::ValueType,
     attributes::Inline,
     attributes::IntoParamsNames,
+    attributes::IntoParamsComponent,
     attributes::SchemaWith,
     attributes::Description,
     attributes::Deprecated,
     attributes::As,
     attributes::AdditionalProperties,
+    attributes::Not,
+    attributes::PatternProperties,
     attributes::Required,
     attributes::ContentEncoding,
     attributes::ContentMediaType,
@@ -431,6 +454,8 @@ macro_rules! parse_features {
                 while !input.is_empty() {
                     let ident = input.parse::<syn::Ident>().or_else(|_| {
                         input.parse::<syn::Token![as]>().map(|as_| syn::Ident::new("as", as_.span))
+                    }).or_else(|_| {
+                        input.parse::<syn::Token![const]>().map(|const_| syn::Ident::new("const", const_.span))
                     }).map_err(|error| {
                         syn::Error::new(
                             error.span(),
@@ -610,6 +635,7 @@ macro_rules! impl_feature_into_inner {
 impl_feature_into_inner! {
     attributes::Example,
     attributes::Examples,
+    attributes::Const,
     attributes::Default,
     attributes::Inline,
     attributes::XmlAttr,
@@ -626,6 +652,7 @@ impl_feature_into_inner! {
     attributes::Explode,
     attributes::ParameterIn,
     attributes::IntoParamsNames,
+    attributes::IntoParamsComponent,
     attributes::SchemaWith,
     attributes::Description,
     attributes::Deprecated,
@@ -78,6 +78,30 @@ impl From<Example> for Feature {
     }
 }
 
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Const(AnyValue);
+}
+
+impl Parse for Const {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_next(input, || AnyValue::parse_any(input)).map(Self)
+    }
+}
+
+impl ToTokens for Const {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.0.to_token_stream())
+    }
+}
+
+impl From<Const> for Feature {
+    fn from(value: Const) -> Self {
+        Feature::Const(value)
+    }
+}
+
 impl_feature! {
     #[derive(Clone)]
     #[cfg_attr(feature = "debug", derive(Debug))]
@@ -559,6 +583,26 @@ impl From<IntoParamsNames> for Feature {
     }
 }
 
+impl_feature! {"component" =>
+    /// Register parameters of `IntoParams` as reusable components with `component` attribute
+    /// and reference them with `$ref` from path operations.
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct IntoParamsComponent(pub(crate) bool);
+}
+
+impl Parse for IntoParamsComponent {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self> {
+        parse_utils::parse_bool_or_true(input).map(Self)
+    }
+}
+
+impl From<IntoParamsComponent> for Feature {
+    fn from(value: IntoParamsComponent) -> Self {
+        Feature::IntoParamsComponent(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -694,6 +738,78 @@ impl From<As> for Feature {
     }
 }
 
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct Not(syn::Type);
+}
+
+impl Parse for Not {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        parse_utils::parse_next(input, || input.parse::<syn::Type>()).map(Self)
+    }
+}
+
+impl ToTokens for Not {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = &self.0;
+        tokens.extend(quote! { <#ty as utoipa::PartialSchema>::schema() })
+    }
+}
+
+impl From<Not> for Feature {
+    fn from(value: Not) -> Self {
+        Self::Not(value)
+    }
+}
+
+impl_feature! {
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[derive(Clone)]
+    pub struct PatternProperties(Vec<(LitStr, syn::Type)>);
+}
+
+impl Parse for PatternProperties {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        let pattern_properties;
+        syn::parenthesized!(pattern_properties in input);
+
+        Punctuated::<(LitStr, syn::Type), Token![,]>::parse_terminated_with(
+            &pattern_properties,
+            |input| {
+                let pattern = input.parse::<LitStr>()?;
+                input.parse::<Token![=]>()?;
+                let ty = input.parse::<syn::Type>()?;
+
+                Ok((pattern, ty))
+            },
+        )
+        .map(|pattern_properties| Self(pattern_properties.into_iter().collect()))
+    }
+}
+
+impl ToTokens for PatternProperties {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (pattern, ty) in &self.0 {
+            tokens.extend(quote! {
+                .pattern_property(#pattern, <#ty as utoipa::PartialSchema>::schema())
+            })
+        }
+    }
+}
+
+impl From<PatternProperties> for Feature {
+    fn from(value: PatternProperties) -> Self {
+        Self::PatternProperties(value)
+    }
+}
+
 impl_feature! {
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[derive(Clone)]
@@ -14,8 +14,8 @@ use crate::{
             self,
             attributes::{
                 AdditionalProperties, AllowReserved, Example, Explode, Format, Ignore, Inline,
-                IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
-                WriteOnly, XmlAttr,
+                IntoParamsComponent, IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll,
+                SchemaWith, Style, WriteOnly, XmlAttr,
             },
             validation::{
                 ExclusiveMaximum, ExclusiveMinimum, MaxItems, MaxLength, Maximum, MinItems,
@@ -49,6 +49,7 @@ impl Parse for IntoParamsFeatures {
             input as Style,
             features::attributes::ParameterIn,
             IntoParamsNames,
+            IntoParamsComponent,
             RenameAll
         )))
     }
@@ -103,10 +104,25 @@ impl ToTokensDiagnostics for IntoParams {
                 .map(|names| names.into_values())
         });
 
+        let component = into_params_features.as_mut().and_then(|features| {
+            let component = pop_feature!(features => Feature::IntoParamsComponent(_));
+            IntoInner::<Option<IntoParamsComponent>>::into_inner(component)
+        });
+
         let style = pop_feature!(into_params_features => Feature::Style(_));
         let parameter_in = pop_feature!(into_params_features => Feature::ParameterIn(_));
         let rename_all = pop_feature!(into_params_features => Feature::RenameAll(_));
 
+        let is_component = component.is_some_and(|component| component.0);
+        if is_component && parameter_in.is_none() {
+            return Err(Diagnostics::with_span(
+                ident.span(),
+                "`#[into_params(component)]` requires `parameter_in` to be defined",
+            )
+            .help("Define location of the parameters e.g. `#[into_params(component, parameter_in = Query)]`")
+            .note("Parameters registered as components cannot resolve their location from the path operation"));
+        }
+
         let params = self
             .get_struct_fields(&names.as_ref())?
             .enumerate()
@@ -156,11 +172,28 @@ impl ToTokensDiagnostics for IntoParams {
             })
             .collect::<Result<Array<TokenStream>, Diagnostics>>()?;
 
+        let into_params_or_refs = if is_component {
+            quote! {
+                fn into_params_or_refs(parameter_in_provider: impl Fn() -> Option<utoipa::openapi::path::ParameterIn>) -> Vec<utoipa::openapi::RefOr<utoipa::openapi::path::Parameter>> {
+                    Self::into_params(parameter_in_provider)
+                        .into_iter()
+                        .map(|parameter| utoipa::openapi::RefOr::Ref(utoipa::openapi::Ref::from_parameter_name(
+                            format!("{}.{}", <Self as utoipa::IntoParams>::name(), parameter.name)
+                        )))
+                        .collect()
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
         tokens.extend(quote! {
             impl #impl_generics utoipa::IntoParams for #ident #ty_generics #where_clause {
                 fn into_params(parameter_in_provider: impl Fn() -> Option<utoipa::openapi::path::ParameterIn>) -> Vec<utoipa::openapi::path::Parameter> {
                     #params.into_iter().filter(Option::is_some).flatten().collect()
                 }
+
+                #into_params_or_refs
             }
         });
 
@@ -6,10 +6,10 @@ use syn::{
 use crate::{
     component::features::{
         attributes::{
-            AdditionalProperties, As, Bound, ContentEncoding, ContentMediaType, Deprecated,
+            AdditionalProperties, As, Bound, Const, ContentEncoding, ContentMediaType, Deprecated,
             Description, Discriminator, Example, Examples, Format, Ignore, Inline, NoRecursion,
-            Nullable, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title, ValueType,
-            WriteOnly, XmlAttr,
+            Not, Nullable, PatternProperties, ReadOnly, Rename, RenameAll, Required, SchemaWith,
+            Title, ValueType, WriteOnly, XmlAttr,
         },
         impl_into_inner, impl_merge, parse_features,
         validation::{
@@ -34,6 +34,7 @@ impl Parse for NamedFieldStructFeatures {
             RenameAll,
             MaxProperties,
             MinProperties,
+            PatternProperties,
             As,
             crate::component::features::attributes::Default,
             Deprecated,
@@ -140,6 +141,8 @@ impl Parse for NamedFieldFeatures {
             MinItems,
             SchemaWith,
             AdditionalProperties,
+            Const,
+            Not,
             Required,
             Deprecated,
             ContentEncoding,
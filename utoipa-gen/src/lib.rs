@@ -134,6 +134,9 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   contain. Value must be a number.
 /// * `min_properties = ...` Can be used to define minimum number of properties this struct can
 ///   contain. Value must be a number.
+/// * `pattern_properties("regex" = Type, ...)` Can be used to define schemas for properties whose
+///   names match the given regular expressions, e.g. _`pattern_properties("^x-" = String)`_.
+///   Each type must implement [`PartialSchema`][partial_schema] and its schema will be inlined.
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**. On
@@ -188,6 +191,14 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 ///   [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap).
 ///   Free form type enables use of arbitrary types within map values.
 ///   Supports formats _`additional_properties`_ and _`additional_properties = true`_.
+/// * `const = ...` Can be used to restrict the field to a single constant value. Can be any value
+///   e.g. literal, method reference or _`json!(...)`_.
+/// * `not = ...` Can be used to define a type the field value must **not** validate against,
+///   e.g. _`not = String`_. The type must implement [`PartialSchema`][partial_schema] and its
+///   schema will be inlined.
+///
+///   On array, map, tuple and referenced fields _`const`_ and _`not`_ are added within `allOf`
+///   next to the field schema so that they apply to the whole field value.
 /// * `deprecated` Can be used to mark the field as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
@@ -931,6 +942,19 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// }
 /// ```
 ///
+/// _**Use JSON Schema `const`, `not` and `patternProperties` keywords.**_
+/// ```rust
+/// # use utoipa::ToSchema;
+/// #[derive(ToSchema)]
+/// #[schema(pattern_properties("^x-" = String))]
+/// struct Event {
+///     #[schema(const = "event")]
+///     kind: String,
+///     #[schema(not = String)]
+///     payload: serde_json::Value,
+/// }
+/// ```
+///
 /// [to_schema]: trait.ToSchema.html
 /// [known_format]: openapi/schema/enum.KnownFormat.html
 /// [binary]: openapi/schema/enum.KnownFormat.html#variant.Binary
@@ -946,6 +970,7 @@ static CONFIG: once_cell::sync::Lazy<utoipa_config::Config> =
 /// [schema_object_media_type]: openapi/schema/struct.Object.html#structfield.content_media_type
 /// [path_macro]: macro@path
 /// [const]: https://doc.rust-lang.org/std/keyword.const.html
+/// [partial_schema]: trait.PartialSchema.html
 pub fn derive_to_schema(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -1024,6 +1049,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `security(...)` List of [`SecurityRequirement`][security]s local to the path operation.
 ///
+/// * `callbacks(...)` List of out-of band [`Callback`][callback]s of the path operation. See
+///   [callbacks attribute syntax](#callbacks-attributes).
+///
 /// # Request Body Attributes
 ///
 /// ## Simple format definition by `request_body = ...`
@@ -1457,6 +1485,44 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// ("key" = [], "key2" = []),
 /// ```
 ///
+/// # Callbacks Attributes
+///
+/// Callbacks are defined as comma separated list of other handler functions annotated with
+/// `#[utoipa::path(...)]`. The _`path`_ of the callback handler is used as [runtime
+/// expression][expression] of the callback request e.g. `"{$request.body#/callbackUrl}"`.
+///
+/// * `handler` Path to the callback handler function. Function name is used as name of the
+///   callback.
+/// * `("name" = handler)` Define custom name for the callback. Handlers with same name will be
+///   grouped to the same callback.
+///
+/// Schemas used in callback handlers will be automatically collected to the OpenAPI along with
+/// the schemas of the path operation.
+///
+/// _**Define payment status callback for payment operation.**_
+/// ```rust
+/// # #[derive(utoipa::ToSchema)]
+/// # struct PaymentStatus {
+/// #     id: i64,
+/// # }
+/// /// Receive payment status change
+/// #[utoipa::path(
+///     post,
+///     path = "{$request.body#/callbackUrl}",
+///     request_body = PaymentStatus,
+///     responses((status = 200, description = "Payment status received"))
+/// )]
+/// async fn payment_status() {}
+///
+/// #[utoipa::path(
+///     post,
+///     path = "/payments",
+///     responses((status = 201, description = "Payment created")),
+///     callbacks(("paymentStatus" = payment_status))
+/// )]
+/// async fn create_payment() {}
+/// ```
+///
 /// # actix_extras feature support for actix-web
 ///
 /// **actix_extras** feature gives **utoipa** ability to parse path operation information from **actix-web** types and macros.
@@ -1859,6 +1925,7 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [server_derive_syntax]: derive.OpenApi.html#servers-attribute-syntax
 /// [server]: openapi/server/struct.Server.html
 /// [file_uploads]: <https://spec.openapis.org/oas/v3.1.0.html#considerations-for-file-uploads>
+/// [callback]: openapi/callback/struct.Callback.html
 pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     let path_attribute = syn::parse_macro_input!(attr as PathAttr);
 
@@ -1943,6 +2010,114 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     handler.to_token_stream().into()
 }
 
+#[proc_macro_attribute]
+/// Webhook attribute macro implements OpenAPI webhook for the decorated function.
+///
+/// Webhook describes a request that the API provider may initiate to the API consumer. Unlike
+/// path operations webhooks are not served at a path but are identified by a unique name. The
+/// webhook is registered to the OpenAPI with [`#[openapi(webhooks(...))]`][openapi] attribute and
+/// is rendered under top level _`webhooks`_ of the OpenAPI document.
+///
+/// Webhook attribute macro mirrors the [`#[utoipa::path(...)]`][path_macro] attribute macro and
+/// supports the same attributes except the ones related to the path of the operation. The webhook
+/// is implemented with the [`Path`][path] trait where [`Path::path`][path] returns the name of the
+/// webhook.
+///
+/// Doc comment at decorated function will be used for _`description`_ and _`summary`_ of the
+/// webhook operation in the same way as with [`#[utoipa::path(...)]`][path_macro].
+///
+/// # Webhook Attributes
+///
+/// * `operation` _**Must be first parameter!**_ Accepted values are known HTTP operations such as
+///   _`get, post, put, delete, head, options, patch, trace`_. Either _`operation`_ or
+///   _`method(...)`_ _**must be provided.**_
+///
+/// * `method(get, head, ...)` Http methods for the webhook operation.
+///
+/// * `name = ...` Unique name of the webhook. By default this is the name of the function. Value
+///   can be literal string or Rust expression e.g. [_`const`_][const] reference.
+///
+/// * `operation_id = ...` Unique operation id for the webhook. By default this is mapped to
+///   function name.
+///
+/// * `tag = "..."` and `tags = ["tag1", ...]` Can be used to group webhooks in same way as with
+///   [`#[utoipa::path(...)]`][path_macro].
+///
+/// * `request_body = ... | request_body(...)` Request body of the webhook request. See
+///   [request body attributes][request_body].
+///
+/// * `responses(...)` Slice of responses the API consumer is expected to return.
+///
+/// * `params(...)` Slice of params of the webhook request e.g. headers sent by the API provider.
+///
+/// * `security(...)` List of [`SecurityRequirement`][security]s of the webhook request.
+///
+/// * `description = ...` and `summary = ...` Override the description and summary resolved from
+///   the doc comment.
+///
+/// * `callbacks(...)` List of out-of band callbacks of the webhook. See [callbacks
+///   attributes][callbacks].
+///
+/// # Examples
+///
+/// _**Define `newPet` webhook and register it to the OpenAPI.**_
+/// ```rust
+/// # use utoipa::{OpenApi, ToSchema};
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// /// New pet was added to the store
+/// #[utoipa::webhook(
+///     post,
+///     name = "newPet",
+///     request_body = Pet,
+///     responses(
+///         (status = 200, description = "Webhook processed successfully")
+///     ),
+///     security(("api_key" = []))
+/// )]
+/// async fn new_pet_webhook() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(webhooks(new_pet_webhook))]
+/// struct ApiDoc;
+/// ```
+///
+/// [openapi]: derive.OpenApi.html
+/// [path_macro]: attr.path.html
+/// [path]: trait.Path.html
+/// [const]: https://doc.rust-lang.org/std/keyword.const.html
+/// [request_body]: attr.path.html#request-body-attributes
+/// [security]: openapi/security/struct.SecurityRequirement.html
+/// [callbacks]: attr.path.html#callbacks-attributes
+pub fn webhook(attr: TokenStream, item: TokenStream) -> TokenStream {
+    use syn::parse::Parser;
+
+    let webhook_attribute = match PathAttr::parse_webhook.parse(attr) {
+        Ok(webhook_attribute) => webhook_attribute,
+        Err(error) => return error.into_compile_error().into_token_stream().into(),
+    };
+
+    let ast_fn = match syn::parse::<ItemFn>(item) {
+        Ok(ast_fn) => ast_fn,
+        Err(error) => return error.into_compile_error().into_token_stream().into(),
+    };
+
+    let webhook = Path::new(webhook_attribute, &ast_fn.sig.ident)
+        .path(Some(ast_fn.sig.ident.to_string()))
+        .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
+        .deprecated(ast_fn.attrs.has_deprecated());
+
+    let handler = path::handler::Handler {
+        path: webhook,
+        handler_fn: &ast_fn,
+    };
+    handler.to_token_stream().into()
+}
+
 #[proc_macro_derive(OpenApi, attributes(openapi))]
 /// Generate OpenApi base object with defaults from
 /// project settings.
@@ -1952,10 +2127,15 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// # OpenApi `#[openapi(...)]` attributes
 ///
 /// * `paths(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
-/// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
-///    _`schema`_ and _`response`_ components are supported.
+/// * `webhooks(...)` List of method references having attribute [`#[utoipa::webhook]`][webhook]
+///   macro. Webhooks are added to the top level _`webhooks`_ of the OpenAPI document by their name.
+/// * `components(schemas(...), responses(...), parameters(...))` Takes available _`component`_
+///    configurations. Currently only _`schema`_, _`response`_ and _`parameter`_ components are supported.
 ///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
 ///    * `responses(...)` List of types that implement [`ToResponse`][to_response_trait].
+///    * `parameters(...)` List of types that implement [`IntoParams`][into_params_trait]. Each
+///      parameter is registered as reusable parameter named _`{type}.{parameter}`_. See
+///      [`#[into_params(component)]`][into_params_component] for referencing them from path operations.
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
 ///   See the [trait documentation][modify] for more details.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
@@ -1977,7 +2157,18 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   implement [`OpenApi`][openapi] trait. Nesting allows defining one `OpenApi` per defined path.
 ///   If more instances is defined only latest one will be rentained.
 ///   See the _[nest(...) attribute syntax below]( #nest-attribute-syntax )_
-///
+/// * `openapi_version = "..."` Define the OpenAPI version of the serialized document. Supported
+///   values are `"3.1"` _(default)_ and `"3.0"`. With `"3.0"` the document will be serialized as
+///   OpenAPI 3.0.3 document. See [`OpenApiVersion`][openapi_version] for more details.
+/// * `prune_unused_components` Remove components not reachable from paths, webhooks or top level
+///   security requirements from the generated document. Pruning is done after _`nest(...)`_ and
+///   _`modifiers(...)`_ have been applied. See
+///   [`OpenApi::prune_unused_components`][prune_unused_components] for more details.
+///
+/// The derive also implements [`OpenApi::check_schema_collisions`][check_schema_collisions]
+/// reporting different schemas registered with the same name, which would otherwise silently
+/// replace each other. The check is not run by the generated _`openapi()`_ and should be called
+/// e.g. from a test.
 ///
 /// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
 /// environment variables.
@@ -2190,6 +2381,10 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// [openapi]: trait.OpenApi.html
 /// [openapi_struct]: openapi/struct.OpenApi.html
+/// [openapi_version]: openapi/enum.OpenApiVersion.html
+/// [prune_unused_components]: openapi/struct.OpenApi.html#method.prune_unused_components
+/// [check_schema_collisions]: trait.OpenApi.html#method.check_schema_collisions
+/// [webhook]: attr.webhook.html
 /// [to_schema]: derive.ToSchema.html
 /// [path]: attr.path.html
 /// [modify]: trait.Modify.html
@@ -2198,6 +2393,8 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// [path_security]: attr.path.html#security-requirement-attributes
 /// [tags]: openapi/tag/struct.Tag.html
 /// [to_response_trait]: trait.ToResponse.html
+/// [into_params_trait]: trait.IntoParams.html
+/// [into_params_component]: derive.IntoParams.html#intoparams-container-attributes-for-into_params
 /// [servers]: openapi/server/index.html
 /// [const]: https://doc.rust-lang.org/std/keyword.const.html
 /// [tags_syntax]: #tags-attribute-syntax
@@ -2255,6 +2452,11 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 ///    supplied, then the value is determined by the `parameter_in_provider` in
 ///    [`IntoParams::into_params()`](trait.IntoParams.html#tymethod.into_params).
 /// * `rename_all = ...` Can be provided to alternatively to the serde's `rename_all` attribute. Effectively provides same functionality.
+/// * `component` Reference the parameters from path operations with `$ref` to
+///   `#/components/parameters/{type}.{name}` instead of inlining them. The parameters must be registered
+///   to the OpenAPI document with [`#[openapi(components(parameters(...)))]`][openapi_derive] or
+///   [`ComponentsBuilder::parameters_from`][parameters_from]. Requires _`parameter_in`_ to be
+///   defined since the location of reusable parameter cannot be resolved from the path operation.
 ///
 /// Use `names` to define name for single unnamed argument.
 /// ```rust
@@ -2274,6 +2476,27 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// struct IdAndName(u64, String);
 /// ```
 ///
+/// Use `component` to share pagination parameters between path operations.
+/// ```rust
+/// # use utoipa::{IntoParams, OpenApi};
+/// #
+/// #[derive(IntoParams)]
+/// #[into_params(component, parameter_in = Query)]
+/// struct Pagination {
+///     /// Page to fetch.
+///     page: Option<u64>,
+///     /// Maximum number of items on page.
+///     page_size: Option<u64>,
+/// }
+///
+/// #[utoipa::path(get, path = "/pets", params(Pagination), responses((status = 200)))]
+/// fn list_pets() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(list_pets), components(parameters(Pagination)))]
+/// struct ApiDoc;
+/// ```
+///
 /// # IntoParams Field Attributes for `#[param(...)]`
 ///
 /// The following attributes are available for use in the `#[param(...)]` on struct fields:
@@ -2558,6 +2781,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// [struct]: https://doc.rust-lang.org/std/keyword.struct.html
 /// [style]: openapi/path/enum.ParameterStyle.html
 /// [in_enum]: openapi/path/enum.ParameterIn.html
+/// [openapi_derive]: derive.OpenApi.html
+/// [parameters_from]: openapi/schema/struct.ComponentsBuilder.html#method.parameters_from
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [serde attributes]: https://serde.rs/attributes.html
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
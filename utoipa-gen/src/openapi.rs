@@ -30,6 +30,7 @@ mod info;
 pub struct OpenApiAttr<'o> {
     info: Option<Info<'o>>,
     paths: Punctuated<ExprPath, Comma>,
+    webhooks: Punctuated<ExprPath, Comma>,
     components: Components,
     modifiers: Punctuated<Modifier, Comma>,
     security: Option<Array<'static, SecurityRequirementsAttr>>,
@@ -37,6 +38,8 @@ pub struct OpenApiAttr<'o> {
     external_docs: Option<ExternalDocs>,
     servers: Punctuated<Server, Comma>,
     nested: Vec<NestOpenApi>,
+    openapi_version: Option<OpenApiVersion>,
+    prune_unused_components: bool,
 }
 
 impl<'o> OpenApiAttr<'o> {
@@ -47,12 +50,18 @@ impl<'o> OpenApiAttr<'o> {
         if !other.paths.is_empty() {
             self.paths = other.paths;
         }
+        if !other.webhooks.is_empty() {
+            self.webhooks = other.webhooks;
+        }
         if !other.components.schemas.is_empty() {
             self.components.schemas = other.components.schemas;
         }
         if !other.components.responses.is_empty() {
             self.components.responses = other.components.responses;
         }
+        if !other.components.parameters.is_empty() {
+            self.components.parameters = other.components.parameters;
+        }
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -65,6 +74,12 @@ impl<'o> OpenApiAttr<'o> {
         if !other.servers.is_empty() {
             self.servers = other.servers;
         }
+        if other.openapi_version.is_some() {
+            self.openapi_version = other.openapi_version;
+        }
+        if other.prune_unused_components {
+            self.prune_unused_components = other.prune_unused_components;
+        }
 
         self
     }
@@ -82,7 +97,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Result<Option<OpenApiAttr>, E
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs, servers, nest";
+            "unexpected attribute, expected any of: handlers, webhooks, components, modifiers, security, tags, external_docs, servers, nest, openapi_version, prune_unused_components";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -100,6 +115,10 @@ impl Parse for OpenApiAttr<'_> {
                 "paths" => {
                     openapi.paths = parse_utils::parse_comma_separated_within_parenthesis(input)?;
                 }
+                "webhooks" => {
+                    openapi.webhooks =
+                        parse_utils::parse_comma_separated_within_parenthesis(input)?;
+                }
                 "components" => {
                     openapi.components = input.parse()?;
                 }
@@ -130,6 +149,13 @@ impl Parse for OpenApiAttr<'_> {
                     parenthesized!(nest in input);
                     openapi.nested = parse_utils::parse_groups_collect(&nest)?;
                 }
+                "openapi_version" => {
+                    openapi.openapi_version =
+                        Some(parse_utils::parse_next(input, || input.parse())?);
+                }
+                "prune_unused_components" => {
+                    openapi.prune_unused_components = parse_utils::parse_bool_or_true(input)?;
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -144,6 +170,37 @@ impl Parse for OpenApiAttr<'_> {
     }
 }
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum OpenApiVersion {
+    Version31,
+    Version30,
+}
+
+impl Parse for OpenApiVersion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let version = input.parse::<LitStr>()?;
+
+        match &*version.value() {
+            "3.1" | "3.1.0" => Ok(Self::Version31),
+            "3.0" | "3.0.3" => Ok(Self::Version30),
+            _ => Err(Error::new(
+                version.span(),
+                "unexpected OpenAPI version, expected one of: 3.1, 3.0",
+            )),
+        }
+    }
+}
+
+impl ToTokens for OpenApiVersion {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let version = match self {
+            Self::Version31 => quote! { Version31 },
+            Self::Version30 => quote! { Version30 },
+        };
+        tokens.extend(quote! { utoipa::openapi::OpenApiVersion::#version })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Schema(TypePath);
 
@@ -182,6 +239,15 @@ impl Parse for Response {
     }
 }
 
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Parameters(TypePath);
+
+impl Parse for Parameters {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse().map(Self)
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Modifier {
     and: And,
@@ -495,19 +561,26 @@ impl ToTokensDiagnostics for OpenApi<'_> {
         let Paths(path_items, handlers) =
             impl_paths(attributes.as_ref().map(|attributes| &attributes.paths));
 
-        let handler_schemas = handlers.iter().fold(
-            quote! {
-                    let components = openapi.components.get_or_insert(utoipa::openapi::Components::new());
-                    let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
-            },
-            |mut handler_schemas, (usage, ..)| {
-                handler_schemas.extend(quote! {
-                    <#usage as utoipa::__dev::SchemaReferences>::schemas(&mut schemas);
-                });
+        let (Paths(webhook_impls, webhook_handlers), webhooks) =
+            impl_webhooks(attributes.as_ref().map(|attributes| &attributes.webhooks));
 
-                handler_schemas
-            },
-        );
+        let handler_schema_references = handlers
+            .iter()
+            .chain(webhook_handlers.iter())
+            .map(|(usage, ..)| {
+                quote! {
+                    <#usage as utoipa::__dev::SchemaReferences>::schemas(&mut schemas);
+                }
+            })
+            .collect::<TokenStream>();
+        let handler_schemas = quote! {
+            let components = openapi.components.get_or_insert(utoipa::openapi::Components::new());
+            let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+            #handler_schema_references
+        };
+        let component_schema_references = attributes
+            .as_ref()
+            .map_try(|attributes| attributes.components.schema_references())?;
 
         let securities = attributes
             .as_ref()
@@ -534,6 +607,11 @@ impl ToTokensDiagnostics for OpenApi<'_> {
                 }
             });
 
+        let openapi_version = attributes
+            .as_ref()
+            .and_then(|attributes| attributes.openapi_version.as_ref())
+            .map(|openapi_version| quote! { .openapi(#openapi_version) });
+
         let servers = match attributes.as_ref().map(|attributes| &attributes.servers) {
             Some(servers) if !servers.is_empty() => {
                 let servers = servers.iter().collect::<Array<&Server>>();
@@ -554,6 +632,11 @@ impl ToTokensDiagnostics for OpenApi<'_> {
                 }
             });
 
+        let prune_tokens = attributes
+            .as_ref()
+            .filter(|attributes| attributes.prune_unused_components)
+            .map(|_| quote! { openapi.prune_unused_components(); });
+
         let nested_tokens = self
             .nested_tokens()
             .map(|tokens| quote! {openapi = openapi #tokens;});
@@ -561,11 +644,14 @@ impl ToTokensDiagnostics for OpenApi<'_> {
             impl utoipa::OpenApi for #ident {
                 fn openapi() -> utoipa::openapi::OpenApi {
                     use utoipa::{ToSchema, Path};
+                    #webhook_impls
                     let mut openapi = utoipa::openapi::OpenApiBuilder::new()
+                        #openapi_version
                         .info(#info)
                         .paths({
                             #path_items
                         })
+                        #webhooks
                         #components
                         #securities
                         #tags
@@ -578,8 +664,18 @@ impl ToTokensDiagnostics for OpenApi<'_> {
 
                     #modifiers_tokens
 
+                    #prune_tokens
+
                     openapi
                 }
+
+                fn check_schema_collisions() -> Result<(), Vec<utoipa::openapi::dedupe::SchemaCollision>> {
+                    let mut schemas = Vec::<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>::new();
+                    #component_schema_references
+                    #handler_schema_references
+
+                    utoipa::openapi::dedupe::find_schema_collisions(schemas)
+                }
             }
         });
 
@@ -592,6 +688,7 @@ impl ToTokensDiagnostics for OpenApi<'_> {
 struct Components {
     schemas: Vec<Schema>,
     responses: Vec<Response>,
+    parameters: Vec<Parameters>,
 }
 
 impl Parse for Components {
@@ -599,10 +696,11 @@ impl Parse for Components {
         let content;
         parenthesized!(content in input);
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute. expected one of: schemas, responses";
+            "unexpected attribute. expected one of: schemas, responses, parameters";
 
         let mut schemas: Vec<Schema> = Vec::new();
         let mut responses: Vec<Response> = Vec::new();
+        let mut parameters: Vec<Parameters> = Vec::new();
 
         while !content.is_empty() {
             let ident = content.parse::<Ident>().map_err(|error| {
@@ -621,6 +719,11 @@ impl Parse for Components {
                         .into_iter()
                         .collect(),
                 ),
+                "parameters" => parameters.append(
+                    &mut parse_utils::parse_comma_separated_within_parenthesis(&content)?
+                        .into_iter()
+                        .collect(),
+                ),
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -629,13 +732,37 @@ impl Parse for Components {
             }
         }
 
-        Ok(Self { schemas, responses })
+        Ok(Self {
+            schemas,
+            responses,
+            parameters,
+        })
+    }
+}
+
+impl Components {
+    /// Create tokens pushing every schema of `schemas(...)` with its dependencies to `schemas`
+    /// [`Vec`] with the names they are registered with to the components.
+    fn schema_references(&self) -> Result<TokenStream, Diagnostics> {
+        self.schemas
+            .iter()
+            .map(|schema| {
+                let component_schema = schema.get_component()?;
+                let type_path = &schema.0;
+                let name = &component_schema.name_tokens;
+
+                Ok(quote! {
+                    <#type_path as utoipa::ToSchema>::schemas(&mut schemas);
+                    schemas.push((String::from(#name), (#component_schema).into()));
+                })
+            })
+            .collect()
     }
 }
 
 impl crate::ToTokensDiagnostics for Components {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        if self.schemas.is_empty() && self.responses.is_empty() {
+        if self.schemas.is_empty() && self.responses.is_empty() && self.parameters.is_empty() {
             return Ok(());
         }
 
@@ -677,6 +804,18 @@ impl crate::ToTokensDiagnostics for Components {
                     builder_tokens
                 });
 
+        let builder_tokens =
+            self.parameters
+                .iter()
+                .fold(builder_tokens, |mut builder_tokens, parameters| {
+                    let Parameters(path) = parameters;
+
+                    builder_tokens.extend(quote_spanned! {path.span() =>
+                        .parameters_from::<#path>()
+                    });
+                    builder_tokens
+                });
+
         tokens.extend(quote! { #builder_tokens.build() });
 
         Ok(())
@@ -686,6 +825,46 @@ impl crate::ToTokensDiagnostics for Components {
 struct Paths(TokenStream, Vec<(ExprPath, String, Ident)>);
 
 fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
+    let (handlers_impls, handlers) = impl_handler_configs(handler_paths, "config");
+
+    let tokens = handlers.iter().fold(
+        quote! { #handlers_impls utoipa::openapi::path::PathsBuilder::new() },
+        |mut paths, (_, _, handler_ident_config)| {
+            paths.extend(quote! {
+                .path_from::<#handler_ident_config>()
+            });
+
+            paths
+        },
+    );
+
+    Paths(tokens, handlers)
+}
+
+/// Webhooks share the [`Paths`] representation where the first item is the handler config
+/// implementations and the second are the handlers. Webhooks are appended to the
+/// `OpenApiBuilder` with the _`webhook_from`_ method calls which are returned separately.
+fn impl_webhooks(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> (Paths, TokenStream) {
+    let (handlers_impls, handlers) = impl_handler_configs(handler_paths, "webhook_config");
+
+    let webhooks = handlers
+        .iter()
+        .map(|(_, _, handler_ident_config)| {
+            quote! {
+                .webhook_from::<#handler_ident_config>()
+            }
+        })
+        .collect::<TokenStream>();
+
+    (Paths(handlers_impls, handlers), webhooks)
+}
+
+/// Create `PathConfig` implementations for given handlers. `PathConfig` resolves the tags of the
+/// handler operation from the module path of the handler.
+fn impl_handler_configs(
+    handler_paths: Option<&Punctuated<ExprPath, Comma>>,
+    config_suffix: &str,
+) -> (TokenStream, Vec<(ExprPath, String, Ident)>) {
     let handlers = handler_paths
         .into_iter()
         .flatten()
@@ -698,7 +877,7 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
                 .join("_");
             let handler_fn = &segments.last().unwrap().ident;
             let handler_ident = path::format_path_ident(Cow::Borrowed(handler_fn));
-            let handler_ident_config = format_ident!("{}_config", handler_config_name);
+            let handler_ident_config = format_ident!("{}_{}", handler_config_name, config_suffix);
 
             let tag = segments
                 .iter()
@@ -750,26 +929,7 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
         })
         .collect::<TokenStream>();
 
-    let tokens = handler_paths.into_iter().flatten().fold(
-        quote! { #handlers_impls utoipa::openapi::path::PathsBuilder::new() },
-        |mut paths, handler| {
-            let segments = handler.path.segments.iter().collect::<Vec<_>>();
-            let handler_config_name = segments
-                .iter()
-                .map(|segment| segment.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("_");
-            let handler_ident_config = format_ident!("{}_config", handler_config_name);
-
-            paths.extend(quote! {
-                .path_from::<#handler_ident_config>()
-            });
-
-            paths
-        },
-    );
-
-    Paths(tokens, handlers)
+    (handlers_impls, handlers)
 }
 
 /// (path = "/nest/path", api = NestApi, tags = ["tag1", "tag2"])
@@ -53,6 +53,7 @@ pub struct PathAttr<'p> {
     impl_for: Option<Ident>,
     description: Option<parse_utils::LitStrOrExpr>,
     summary: Option<parse_utils::LitStrOrExpr>,
+    callbacks: Punctuated<Callback, Comma>,
 }
 
 impl<'p> PathAttr<'p> {
@@ -107,19 +108,46 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, description, summary";
+        PathAttr::parse_attributes(input, false)
+    }
+}
+
+impl PathAttr<'_> {
+    /// Parse attributes of `#[utoipa::webhook(...)]` attribute macro. Webhooks share the
+    /// attributes with path operation but the _`path`_ is replaced with _`name`_ of the webhook.
+    pub fn parse_webhook(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        PathAttr::parse_attributes(input, true)
+    }
+
+    fn parse_attributes(input: syn::parse::ParseStream, webhook: bool) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, description, summary, callbacks";
+        const EXPECTED_WEBHOOK_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, name, request_body, responses, params, tag, security, description, summary, callbacks";
+        let expected_attribute_message = if webhook {
+            EXPECTED_WEBHOOK_ATTRIBUTE_MESSAGE
+        } else {
+            EXPECTED_ATTRIBUTE_MESSAGE
+        };
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
                 syn::Error::new(
                     error.span(),
-                    format!("{EXPECTED_ATTRIBUTE_MESSAGE}, {error}"),
+                    format!("{expected_attribute_message}, {error}"),
                 )
             })?;
             let attribute_name = &*ident.to_string();
 
             match attribute_name {
+                "path" | "context_path" | "impl_for" if webhook => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("`{attribute_name}` is not supported for webhooks, {expected_attribute_message}"),
+                    ));
+                }
+                "name" if webhook => {
+                    path_attr.path = Some(parse_utils::parse_next_literal_str_or_expr(input)?);
+                }
                 "method" => {
                     path_attr.methods =
                         parse_utils::parse_parethesized_terminated::<HttpMethod, Comma>(input)?
@@ -182,13 +210,17 @@ impl Parse for PathAttr<'_> {
                 "summary" => {
                     path_attr.summary = Some(parse_utils::parse_next_literal_str_or_expr(input)?)
                 }
+                "callbacks" => {
+                    path_attr.callbacks =
+                        parse_utils::parse_comma_separated_within_parenthesis(input)?;
+                }
                 _ => {
                     if let Some(path_operation) =
                         attribute_name.parse::<HttpMethod>().into_iter().next()
                     {
                         path_attr.methods = vec![path_operation]
                     } else {
-                        return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE));
+                        return Err(syn::Error::new(ident.span(), expected_attribute_message));
                     }
                 }
             }
@@ -202,6 +234,91 @@ impl Parse for PathAttr<'_> {
     }
 }
 
+/// Callback of the path operation referencing other handler annotated with `#[utoipa::path(...)]`.
+///
+/// Callback is either handler path e.g. `payment_notification` in which case the handler function
+/// name is used as name of the callback, or `("name" = handler)` to give the callback a custom name.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Callback {
+    name: Option<LitStr>,
+    handler: syn::ExprPath,
+}
+
+impl Callback {
+    fn name(&self) -> String {
+        self.name
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| self.handler_fn().to_string())
+    }
+
+    fn handler_fn(&self) -> &Ident {
+        &self
+            .handler
+            .path
+            .segments
+            .last()
+            .expect("callback handler must have at least one segment")
+            .ident
+    }
+
+    /// Get path to the `__path_` struct of the callback handler.
+    fn handler_path(&self) -> syn::ExprPath {
+        let mut handler_path = self.handler.clone();
+        if let Some(last) = handler_path.path.segments.last_mut() {
+            last.ident = format_path_ident(Cow::Borrowed(&last.ident)).into_owned();
+        }
+
+        handler_path
+    }
+}
+
+impl Parse for Callback {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Paren) {
+            let callback;
+            parenthesized!(callback in input);
+            let name = callback.parse::<LitStr>()?;
+            callback.parse::<Token![=]>()?;
+
+            Ok(Self {
+                name: Some(name),
+                handler: callback.parse()?,
+            })
+        } else {
+            Ok(Self {
+                name: None,
+                handler: input.parse()?,
+            })
+        }
+    }
+}
+
+/// Callbacks of the path operation grouped by the callback name.
+struct Callbacks<'a>(&'a Punctuated<Callback, Comma>);
+
+impl ToTokens for Callbacks<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let mut callbacks: Vec<(String, Vec<&Callback>)> = Vec::new();
+        for callback in self.0 {
+            let name = callback.name();
+            match callbacks.iter_mut().find(|(existing, _)| *existing == name) {
+                Some((_, handlers)) => handlers.push(callback),
+                None => callbacks.push((name, vec![callback])),
+            }
+        }
+
+        for (name, handlers) in callbacks {
+            let handler_paths = handlers.iter().map(|callback| callback.handler_path());
+            tokens.extend(quote! {
+                .callback(#name, utoipa::openapi::callback::CallbackBuilder::new()
+                    #( .path_from::<#handler_paths>() )*
+                )
+            })
+        }
+    }
+}
+
 /// Path operation HTTP method
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum HttpMethod {
@@ -467,6 +584,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            callbacks: &self.path_attr.callbacks,
         };
         let operation = as_tokens_or_diagnostics!(&operation);
 
@@ -518,6 +636,16 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             .flatten()
             .fold(TokenStream2::new(), to_schema_references);
 
+        let callback_schemas = self
+            .path_attr
+            .callbacks
+            .iter()
+            .map(|callback| {
+                let handler_path = callback.handler_path();
+                quote! { <#handler_path as utoipa::__dev::SchemaReferences>::schemas(schemas); }
+            })
+            .collect::<TokenStream2>();
+
         let mut tags = self.path_attr.tags.clone();
         if let Some(tag) = self.path_attr.tag.as_ref() {
             // if defined tag is the first before the additional tags
@@ -606,6 +734,7 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
                 fn schemas(schemas: &mut Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) {
                     #schemas
                     #response_schemas
+                    #callback_schemas
                 }
             }
 
@@ -625,6 +754,7 @@ struct Operation<'a> {
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<'a, SecurityRequirementsAttr>>,
+    callbacks: &'a Punctuated<Callback, Comma>,
 }
 
 impl ToTokensDiagnostics for Operation<'_> {
@@ -670,6 +800,8 @@ impl ToTokensDiagnostics for Operation<'_> {
             parameter.to_tokens(tokens)?;
         }
 
+        Callbacks(self.callbacks).to_tokens(tokens);
+
         Ok(())
     }
 }
@@ -109,7 +109,7 @@ impl ToTokensDiagnostics for Parameter<'_> {
                     .unwrap_or(default_parameter_in_provider);
                 tokens.extend(quote_spanned! {last_ident.span()=>
                     .parameters(
-                        Some(<#path as utoipa::IntoParams>::into_params(#parameter_in_provider))
+                        Some(<#path as utoipa::IntoParams>::into_params_or_refs(#parameter_in_provider))
                     )
                 })
             }
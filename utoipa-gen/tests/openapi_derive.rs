@@ -711,3 +711,360 @@ fn openapi_resolvle_recursive_references() {
         })
     )
 }
+
+#[test]
+fn derive_openapi_with_openapi_version_30() {
+    #![allow(dead_code)]
+
+    #[derive(ToSchema)]
+    struct Owner {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        nickname: Option<String>,
+        owner: Option<Owner>,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(openapi_version = "3.0", components(schemas(Pet)))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(ApiDoc::openapi()).expect("OpenAPI is serde serializable");
+
+    assert_eq!(value.pointer("/openapi"), Some(&json!("3.0.3")));
+    assert_json_eq!(
+        value.pointer("/components/schemas/Pet"),
+        json!({
+            "properties": {
+                "nickname": {
+                    "type": "string",
+                    "nullable": true
+                },
+                "owner": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "nullable": true
+                }
+            },
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_openapi_with_webhooks() {
+    #![allow(dead_code)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        id: u64,
+        name: String,
+    }
+
+    mod pets {
+        /// Pet was deleted from the store
+        #[utoipa::webhook(
+            post,
+            responses((status = 200, description = "Webhook processed"))
+        )]
+        pub async fn deleted_pet() {}
+    }
+
+    /// New pet was added to the store
+    #[utoipa::webhook(
+        post,
+        name = "newPet",
+        tag = "pet",
+        request_body = Pet,
+        responses((status = 200, description = "Webhook processed")),
+        security(("api_key" = []))
+    )]
+    async fn new_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(webhooks(new_pet, pets::deleted_pet))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(ApiDoc::openapi()).expect("OpenAPI is serde serializable");
+
+    assert_json_eq!(
+        value.get("webhooks"),
+        json!({
+            "newPet": {
+                "post": {
+                    "operationId": "new_pet",
+                    "summary": "New pet was added to the store",
+                    "tags": ["pet"],
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "$ref": "#/components/schemas/Pet"
+                                }
+                            }
+                        },
+                        "required": true
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Webhook processed"
+                        }
+                    },
+                    "security": [
+                        {
+                            "api_key": []
+                        }
+                    ]
+                }
+            },
+            "deleted_pet": {
+                "post": {
+                    "operationId": "deleted_pet",
+                    "summary": "Pet was deleted from the store",
+                    "tags": ["pets"],
+                    "responses": {
+                        "200": {
+                            "description": "Webhook processed"
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert!(
+        value.pointer("/components/schemas/Pet").is_some(),
+        "webhook schemas must be collected"
+    );
+}
+
+#[test]
+fn derive_openapi_with_component_parameters() {
+    #![allow(unused)]
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(component, parameter_in = Query)]
+    struct Pagination {
+        /// Page to fetch.
+        page: Option<u64>,
+        /// Maximum number of items on page.
+        page_size: Option<u64>,
+    }
+
+    #[derive(utoipa::IntoParams)]
+    #[into_params(component, parameter_in = Query)]
+    struct Search {
+        /// Page of search results.
+        page: Option<u64>,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        params(Pagination, Search),
+        responses((status = 200, description = "Pets"))
+    )]
+    fn list_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets), components(parameters(Pagination, Search)))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/paths/~1pets/get/parameters"),
+        json!([
+            { "$ref": "#/components/parameters/Pagination.page" },
+            { "$ref": "#/components/parameters/Pagination.page_size" },
+            { "$ref": "#/components/parameters/Search.page" }
+        ])
+    );
+    assert_json_eq!(
+        value.pointer("/components/parameters"),
+        json!({
+            "Pagination.page": {
+                "name": "page",
+                "in": "query",
+                "description": "Page to fetch.",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int64",
+                    "minimum": 0
+                }
+            },
+            "Pagination.page_size": {
+                "name": "page_size",
+                "in": "query",
+                "description": "Maximum number of items on page.",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int64",
+                    "minimum": 0
+                }
+            },
+            "Search.page": {
+                "name": "page",
+                "in": "query",
+                "description": "Page of search results.",
+                "required": false,
+                "schema": {
+                    "type": ["integer", "null"],
+                    "format": "int64",
+                    "minimum": 0
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_validate_reports_issues() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        id: u64,
+        name: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets/{id}",
+        params(("id" = u64, Path, description = "Pet id")),
+        responses((status = 200, description = "Pet", body = Pet))
+    )]
+    fn get_pet() {}
+
+    #[utoipa::path(
+        delete,
+        path = "/pets/{id}",
+        responses((status = 204, description = "Pet deleted")),
+        security(("api_key" = []))
+    )]
+    fn delete_pet() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ValidApiDoc;
+
+    utoipa::testing::assert_valid(&ValidApiDoc::openapi());
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet, delete_pet))]
+    struct ApiDoc;
+
+    let issues = ApiDoc::openapi()
+        .validate()
+        .into_iter()
+        .map(|issue| issue.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        issues,
+        [
+            "/paths/~1pets~1{id}/delete/security/0: security scheme `api_key` is not declared in components.securitySchemes",
+            "/paths/~1pets~1{id}/delete: path parameter `id` is not defined in operation or path item parameters",
+        ]
+    );
+}
+
+#[test]
+fn derive_openapi_with_prune_unused_components() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    struct Unused {
+        value: String,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses((status = 200, description = "Pets", body = [Pet]))
+    )]
+    fn list_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets), components(schemas(Unused)), prune_unused_components)]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/components/schemas"),
+        json!({
+            "Pet": {
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string"
+                    }
+                },
+                "required": ["name"]
+            }
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_check_schema_collisions() {
+    mod users {
+        #[derive(utoipa::ToSchema)]
+        #[allow(unused)]
+        pub struct ErrorBody {
+            pub message: String,
+        }
+    }
+
+    mod pets {
+        #[derive(utoipa::ToSchema)]
+        #[allow(unused)]
+        pub struct ErrorBody {
+            pub code: i32,
+        }
+
+        #[derive(utoipa::ToSchema)]
+        #[allow(unused)]
+        pub struct Pet {
+            pub name: String,
+        }
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "Pets", body = [pets::Pet]),
+            (status = 500, description = "Error", body = pets::ErrorBody)
+        )
+    )]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets), components(schemas(pets::ErrorBody, pets::Pet)))]
+    struct ApiDoc;
+
+    assert!(ApiDoc::check_schema_collisions().is_ok());
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets), components(schemas(users::ErrorBody)))]
+    struct CollidingApiDoc;
+
+    let collisions = CollidingApiDoc::check_schema_collisions().unwrap_err();
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].name, "ErrorBody");
+    assert_eq!(collisions[0].schemas.len(), 2);
+}
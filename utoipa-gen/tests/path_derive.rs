@@ -3167,3 +3167,111 @@ fn derive_lifetime_generic_request_body_compiles() {
     let _ = serde_json::to_value(__path_test_const_generic::operation())
         .expect("Operation is JSON serializable");
 }
+
+#[test]
+fn derive_path_with_callbacks() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct PaymentStatus {
+        id: i64,
+    }
+
+    mod callbacks {
+        /// Payment refunded
+        #[utoipa::path(
+            post,
+            path = "{$request.body#/refundUrl}",
+            responses((status = 200, description = "Refund received"))
+        )]
+        pub async fn payment_refunded() {}
+    }
+
+    /// Payment status changed
+    #[utoipa::path(
+        post,
+        path = "{$request.body#/callbackUrl}",
+        request_body = PaymentStatus,
+        responses((status = 200, description = "Status received"))
+    )]
+    async fn payment_status_post() {}
+
+    #[utoipa::path(
+        put,
+        path = "{$request.body#/callbackUrl}",
+        responses((status = 204, description = "Status updated"))
+    )]
+    async fn payment_status_put() {}
+
+    #[utoipa::path(
+        post,
+        path = "/payments",
+        responses((status = 201, description = "Payment created")),
+        callbacks(
+            ("paymentStatus" = payment_status_post),
+            ("paymentStatus" = payment_status_put),
+            callbacks::payment_refunded
+        )
+    )]
+    async fn create_payment() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_payment))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1payments/post/callbacks"),
+        json!({
+            "paymentStatus": {
+                "{$request.body#/callbackUrl}": {
+                    "post": {
+                        "operationId": "payment_status_post",
+                        "summary": "Payment status changed",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": {
+                                        "$ref": "#/components/schemas/PaymentStatus"
+                                    }
+                                }
+                            },
+                            "required": true
+                        },
+                        "responses": {
+                            "200": {
+                                "description": "Status received"
+                            }
+                        }
+                    },
+                    "put": {
+                        "operationId": "payment_status_put",
+                        "responses": {
+                            "204": {
+                                "description": "Status updated"
+                            }
+                        }
+                    }
+                }
+            },
+            "payment_refunded": {
+                "{$request.body#/refundUrl}": {
+                    "post": {
+                        "operationId": "payment_refunded",
+                        "summary": "Payment refunded",
+                        "responses": {
+                            "200": {
+                                "description": "Refund received"
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert!(
+        doc.pointer("/components/schemas/PaymentStatus").is_some(),
+        "callback schemas must be collected"
+    );
+}
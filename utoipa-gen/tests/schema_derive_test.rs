@@ -5564,6 +5564,139 @@ fn content_media_type_named_field() {
     )
 }
 
+#[test]
+fn derive_struct_with_const_and_not_named_fields() {
+    let value = api_doc! {
+        struct Pet {
+            #[schema(const = "pet")]
+            kind: String,
+            #[schema(const = 1)]
+            version: i32,
+            #[schema(not = String)]
+            id: serde_json::Value,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "kind": {
+                    "type": "string",
+                    "const": "pet"
+                },
+                "version": {
+                    "type": "integer",
+                    "format": "int32",
+                    "const": 1
+                },
+                "id": {
+                    "not": {
+                        "type": "string"
+                    }
+                }
+            },
+            "required": [
+                "kind",
+                "version",
+                "id"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_const_and_not_array_and_ref_fields() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    let value = api_doc! {
+        struct Owner {
+            #[schema(const = json!(["a"]))]
+            tags: Vec<String>,
+            #[schema(not = String)]
+            pet: Pet,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "tags": {
+                    "allOf": [
+                        {
+                            "type": "array",
+                            "items": {
+                                "type": "string"
+                            }
+                        },
+                        {
+                            "const": ["a"]
+                        }
+                    ]
+                },
+                "pet": {
+                    "allOf": [
+                        {
+                            "$ref": "#/components/schemas/Pet"
+                        },
+                        {
+                            "not": {
+                                "type": "string"
+                            }
+                        }
+                    ]
+                }
+            },
+            "required": [
+                "tags",
+                "pet"
+            ],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn derive_struct_with_pattern_properties() {
+    let value = api_doc! {
+        #[schema(pattern_properties("^x-" = String, "^[0-9]+$" = i64))]
+        struct Headers {
+            name: String,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "name": {
+                    "type": "string"
+                }
+            },
+            "patternProperties": {
+                "^x-": {
+                    "type": "string"
+                },
+                "^[0-9]+$": {
+                    "type": "integer",
+                    "format": "int64"
+                }
+            },
+            "required": [
+                "name"
+            ],
+            "type": "object"
+        })
+    )
+}
+
 #[test]
 fn derive_schema_required_custom_type_required() {
     #[allow(unused)]
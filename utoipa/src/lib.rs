@@ -61,6 +61,11 @@
 //!
 //! * **`macros`** Enable `utoipa-gen` macros. **This is enabled by default.**
 //! * **`yaml`** Enables **serde_yaml** serialization of OpenAPI objects.
+//! * **`instance_validation`** Enables validation of JSON instances against OpenAPI schemas at runtime with
+//!   `OpenApi::validate_instance` and checking declared examples with `OpenApi::validate_examples`.
+//!   Uses [regex](https://crates.io/crates/regex) for `pattern` validation.
+//! * **`content_hash`** Enables `OpenApi::content_hash` for computing SHA-256 hash of the canonical JSON of the
+//!   OpenAPI document e.g. for ETags. Uses [sha2](https://crates.io/crates/sha2) for hashing.
 //! * **`actix_extras`** Enhances [actix-web](https://github.com/actix/actix-web/) integration with being able to
 //!   parse `path`, `path` and `query` parameters from actix web path attribute macros. See [actix extras support][actix_path] or
 //!   [examples](https://github.com/juhaku/utoipa/tree/master/examples) for more details.
@@ -232,7 +237,9 @@
 //! [security]: openapi/security/index.html
 //! [to_schema_derive]: derive.ToSchema.html
 
+pub mod lint;
 pub mod openapi;
+pub mod testing;
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -290,6 +297,47 @@ pub trait OpenApi {
     /// Return the [`openapi::OpenApi`] instance which can be parsed with serde or served via
     /// OpenAPI visualization tool such as Swagger UI.
     fn openapi() -> openapi::OpenApi;
+
+    /// Check that different schemas of this OpenAPI do not collide with the same name.
+    ///
+    /// Schemas are registered to the components by their name thus different types with the
+    /// same [`ToSchema::name`] silently replace each other. Derived implementation collects the
+    /// schemas of _`components(schemas(...))`_ and the schemas referenced by _`paths(...)`_ and
+    /// _`webhooks(...)`_ with the names they are registered with and reports the collisions
+    /// found with [`openapi::dedupe::find_schema_collisions`]. Schemas of _`nest(...)`_ OpenAPIs
+    /// are not included and should be checked separately. The derived [`OpenApi::openapi`] does
+    /// not run the check so it should be called e.g. from a test.
+    ///
+    /// Default implementation does not report any collisions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// mod users {
+    ///     #[derive(utoipa::ToSchema)]
+    ///     pub struct ErrorBody {
+    ///         pub message: String,
+    ///     }
+    /// }
+    ///
+    /// mod pets {
+    ///     #[derive(utoipa::ToSchema)]
+    ///     pub struct ErrorBody {
+    ///         pub code: i32,
+    ///     }
+    /// }
+    ///
+    /// #[derive(OpenApi)]
+    /// #[openapi(components(schemas(users::ErrorBody, pets::ErrorBody)))]
+    /// struct ApiDoc;
+    ///
+    /// let collisions = ApiDoc::check_schema_collisions().unwrap_err();
+    /// assert_eq!(collisions[0].name, "ErrorBody");
+    /// ```
+    fn check_schema_collisions() -> Result<(), Vec<openapi::dedupe::SchemaCollision>> {
+        Ok(())
+    }
 }
 
 /// Trait for implementing OpenAPI Schema object.
@@ -464,6 +512,113 @@ impl<T: ToSchema> From<T> for openapi::RefOr<openapi::schema::Schema> {
     }
 }
 
+/// Create standalone [JSON Schema draft 2020-12][json_schema] document of [`ToSchema`] type.
+///
+/// The schema of the type `T` is used as root of the document and all of its transitive
+/// dependencies returned by [`ToSchema::schemas`] are placed under _`$defs`_. References to
+/// _`#/components/schemas/{name}`_ are rewritten to _`#/$defs/{name}`_ and recursive references
+/// to the root type itself point to the root _`#`_. Own _`$defs`_ of the root schema are kept along
+/// with the dependencies. The resulting document is self-contained and
+/// can be published e.g. for editors to validate configuration files.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// #[derive(utoipa::ToSchema)]
+/// struct Server {
+///     host: String,
+/// }
+///
+/// #[derive(utoipa::ToSchema)]
+/// struct Config {
+///     server: Server,
+/// }
+///
+/// let schema = utoipa::json_schema::<Config>();
+///
+/// assert_eq!(schema["$schema"], "https://json-schema.org/draft/2020-12/schema");
+/// assert_eq!(schema["properties"]["server"], json!({"$ref": "#/$defs/Server"}));
+/// assert_eq!(schema["$defs"]["Server"]["required"], json!(["host"]));
+/// ```
+///
+/// [json_schema]: https://json-schema.org/draft/2020-12/json-schema-core
+pub fn json_schema<T: ToSchema>() -> serde_json::Value {
+    use serde_json::{Map, Value};
+
+    fn rewrite_refs(value: &mut Value, root: &str, defs: &std::collections::HashSet<String>) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    match value {
+                        Value::String(reference) if key == "$ref" => {
+                            if let Some(pointer) = reference.strip_prefix("#/components/schemas/") {
+                                let (name, rest) = pointer
+                                    .find('/')
+                                    .map_or((pointer, ""), |index| pointer.split_at(index));
+                                let unescaped = openapi::validation::unescape_pointer_segment(name);
+                                *reference = if unescaped == root && !defs.contains(&unescaped) {
+                                    format!("#{rest}")
+                                } else {
+                                    format!("#/$defs/{name}{rest}")
+                                };
+                            }
+                        }
+                        value => rewrite_refs(value, root, defs),
+                    }
+                }
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .for_each(|value| rewrite_refs(value, root, defs)),
+            _ => (),
+        }
+    }
+
+    let to_value = |schema: &openapi::RefOr<openapi::schema::Schema>| {
+        serde_json::to_value(schema).expect("schema should be serializable to JSON value")
+    };
+
+    let mut schemas = Vec::new();
+    T::schemas(&mut schemas);
+    let mut defs = Map::new();
+    for (name, schema) in &schemas {
+        if !defs.contains_key(name) {
+            defs.insert(name.clone(), to_value(schema));
+        }
+    }
+    let names = defs.keys().cloned().collect();
+
+    let mut document = Map::new();
+    document.insert(
+        String::from("$schema"),
+        Value::from("https://json-schema.org/draft/2020-12/schema"),
+    );
+    match to_value(&T::schema()) {
+        Value::Object(root) => document.extend(root),
+        root => {
+            document.insert(String::from("allOf"), Value::Array(vec![root]));
+        }
+    }
+    if !defs.is_empty() {
+        match document.get_mut("$defs") {
+            Some(Value::Object(root_defs)) => {
+                for (name, schema) in defs {
+                    root_defs.entry(name).or_insert(schema);
+                }
+            }
+            _ => {
+                document.insert(String::from("$defs"), Value::Object(defs));
+            }
+        }
+    }
+
+    let mut document = Value::Object(document);
+    rewrite_refs(&mut document, &T::name(), &names);
+
+    document
+}
+
 /// Represents _`nullable`_ type. This can be used anywhere where "nothing" needs to be evaluated.
 /// This will serialize to _`null`_ in JSON and [`openapi::schema::empty`] is used to create the
 /// [`openapi::schema::Schema`] for the type.
@@ -1101,6 +1256,45 @@ pub trait IntoParams {
     fn into_params(
         parameter_in_provider: impl Fn() -> Option<openapi::path::ParameterIn>,
     ) -> Vec<openapi::path::Parameter>;
+
+    /// Return name of the type implementing [`IntoParams`]. The name is used to namespace
+    /// reusable parameters registered with
+    /// [`ComponentsBuilder::parameters_from`][parameters_from] as _`{name}.{parameter}`_ so
+    /// that parameters with the same name from different types do not overwrite each other.
+    ///
+    /// By default the name is the type name without module path and generic arguments.
+    ///
+    /// [parameters_from]: openapi/schema/struct.ComponentsBuilder.html#method.parameters_from
+    fn name() -> Cow<'static, str> {
+        let full_type_name = std::any::type_name::<Self>();
+        let type_name_without_generic = full_type_name
+            .split_once("<")
+            .map(|(s1, _)| s1)
+            .unwrap_or(full_type_name);
+        let type_name = type_name_without_generic
+            .rsplit_once("::")
+            .map(|(_, tn)| tn)
+            .unwrap_or(type_name_without_generic);
+        Cow::Borrowed(type_name)
+    }
+
+    /// Provide [`Vec`] of [`openapi::RefOr`]s of [`openapi::path::Parameter`]s to caller. This is
+    /// used by `utoipa-gen` when the parameters are added to a path operation.
+    ///
+    /// By default all parameters from [`IntoParams::into_params`] are inlined. Implementations
+    /// can override this to reference parameters registered as reusable components with
+    /// [`ComponentsBuilder::parameters_from`][parameters_from] instead. This is done by the
+    /// derive with `#[into_params(component)]` attribute.
+    ///
+    /// [parameters_from]: openapi/schema/struct.ComponentsBuilder.html#method.parameters_from
+    fn into_params_or_refs(
+        parameter_in_provider: impl Fn() -> Option<openapi::path::ParameterIn>,
+    ) -> Vec<openapi::RefOr<openapi::path::Parameter>> {
+        Self::into_params(parameter_in_provider)
+            .into_iter()
+            .map(openapi::RefOr::T)
+            .collect()
+    }
 }
 
 /// This trait is implemented to document a type (like an enum) which can represent multiple
@@ -1722,4 +1916,110 @@ mod tests {
             assert_json_eq!(schema, value);
         }
     }
+
+    #[test]
+    fn test_json_schema_rewrites_references() {
+        use openapi::schema::{ArrayBuilder, ObjectBuilder};
+        use openapi::Ref;
+
+        struct Owner;
+        impl ToSchema for Owner {}
+        impl PartialSchema for Owner {
+            fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+                ObjectBuilder::new()
+                    .property("name", String::schema())
+                    .property(
+                        "pets",
+                        ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                    )
+                    .into()
+            }
+        }
+
+        struct Pet;
+        impl ToSchema for Pet {
+            fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+                schemas.push((Owner::name().into(), Owner::schema()));
+            }
+        }
+        impl PartialSchema for Pet {
+            fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+                ObjectBuilder::new()
+                    .property("owner", Ref::from_schema_name("Owner"))
+                    .into()
+            }
+        }
+
+        assert_json_eq!(
+            json_schema::<Pet>(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "owner": {"$ref": "#/$defs/Owner"}
+                },
+                "$defs": {
+                    "Owner": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string"},
+                            "pets": {"type": "array", "items": {"$ref": "#"}}
+                        }
+                    }
+                }
+            })
+        );
+        struct Tree;
+        impl ToSchema for Tree {
+            fn name() -> Cow<'static, str> {
+                Cow::Borrowed("Tree/V1")
+            }
+
+            fn schemas(schemas: &mut Vec<(String, openapi::RefOr<openapi::schema::Schema>)>) {
+                schemas.push((String::from("Leaf"), String::schema()));
+            }
+        }
+        impl PartialSchema for Tree {
+            fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+                ObjectBuilder::new()
+                    .property("label", Ref::new("#/$defs/Label"))
+                    .property("leaf", Ref::from_schema_name("Leaf"))
+                    .property(
+                        "value",
+                        Ref::new("#/components/schemas/Tree~1V1/$defs/Label"),
+                    )
+                    .property(
+                        "children",
+                        ArrayBuilder::new().items(Ref::new("#/components/schemas/Tree~1V1")),
+                    )
+                    .def("Label", String::schema())
+                    .into()
+            }
+        }
+
+        assert_json_eq!(
+            json_schema::<Tree>(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "label": {"$ref": "#/$defs/Label"},
+                    "leaf": {"$ref": "#/$defs/Leaf"},
+                    "value": {"$ref": "#/$defs/Label"},
+                    "children": {"type": "array", "items": {"$ref": "#"}}
+                },
+                "$defs": {
+                    "Label": {"type": "string"},
+                    "Leaf": {"type": "string"}
+                }
+            })
+        );
+        assert_json_eq!(
+            json_schema::<String>(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "string"
+            })
+        );
+    }
 }
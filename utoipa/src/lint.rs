@@ -0,0 +1,709 @@
+//! Implements pluggable style rules for linting [`OpenApi`] documents.
+//!
+//! Unlike [`OpenApi::validate`] which checks the structural validity of the document, lint
+//! rules check the document against API guidelines e.g. that every operation has a summary.
+//! Each [`Rule`] reports found problems as [`Violation`]s which [`Linter`] collects to
+//! [`LintReport`] together with the name and [`Severity`] of the rule. The report can be
+//! formatted as text with [`Display`] or as JSON with [`LintReport::to_json`].
+//!
+//! [`Linter::default`] contains the default ruleset:
+//! * [`OperationSummary`] _`operation-summary`_: every operation has a _`summary`_.
+//! * [`OperationId`] _`operation-id`_: every operation has an _`operationId`_.
+//! * [`DeclaredTags`] _`declared-tags`_: every tag of an operation is declared in top level
+//!   _`tags`_.
+//! * [`CamelCaseProperties`] _`camel-case-properties`_: every property name is in camelCase.
+//! * [`ClientErrorResponse`] _`client-error-response`_: every operation documents at least one
+//!   4xx response.
+//! * [`StringMaxLength`] _`string-max-length`_: every string schema without _`enum`_ or
+//!   _`const`_ has _`maxLength`_.
+//! * [`SchemaExamples`] _`schema-examples`_: every component schema has an example.
+//!
+//! # Examples
+//!
+//! _**Enforce API guidelines in a unit test.**_
+//! ```rust
+//! # use utoipa::lint::{Linter, Severity};
+//! # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+//! # use utoipa::openapi::path::OperationBuilder;
+//! let api = OpenApiBuilder::new()
+//!     .paths(PathsBuilder::new().path(
+//!         "/pets",
+//!         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("list_pets"))),
+//!     ))
+//!     .build();
+//!
+//! let report = Linter::default()
+//!     .severity("operation-summary", Severity::Error)
+//!     .disable("client-error-response")
+//!     .lint(&api);
+//!
+//! assert!(report.has_errors());
+//! assert_eq!(
+//!     report.to_string(),
+//!     "error[operation-summary] /paths/~1pets/get: operation has no summary\n"
+//! );
+//! ```
+//!
+//! [`OpenApi::validate`]: crate::openapi::OpenApi::validate
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::openapi::path::Operation;
+use crate::openapi::schema::{Schema, SchemaType, Type};
+use crate::openapi::visit::{self, Location, Visit};
+use crate::openapi::{OpenApi, RefOr};
+
+/// Severity of the [`LintIssue`] reported by a [`Rule`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Informational hint which does not need to be fixed.
+    Info,
+    /// Problem which should be fixed.
+    Warning,
+    /// Problem which must be fixed. See [`LintReport::has_errors`].
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// Lint rule checking the [`OpenApi`] document against a guideline.
+///
+/// # Examples
+///
+/// _**Implement rule requiring description for every operation.**_
+/// ```rust
+/// # use utoipa::lint::{Linter, Rule, Severity, Violation};
+/// # use utoipa::openapi::OpenApi;
+/// struct OperationDescription;
+///
+/// impl Rule for OperationDescription {
+///     fn name(&self) -> &str {
+///         "operation-description"
+///     }
+///
+///     fn severity(&self) -> Severity {
+///         Severity::Error
+///     }
+///
+///     fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+///         openapi
+///             .paths
+///             .paths
+///             .iter()
+///             .filter(|(_, item)| item.get.as_ref().is_some_and(|get| get.description.is_none()))
+///             .map(|(path, _)| Violation::new(path.as_str(), "GET operation has no description"))
+///             .collect()
+///     }
+/// }
+///
+/// let report = Linter::new().rule(OperationDescription).lint(&OpenApi::default());
+/// assert!(report.is_empty());
+/// ```
+pub trait Rule {
+    /// Unique name of the rule used in [`LintIssue::rule`] and for configuring the rule in
+    /// [`Linter`] e.g. _`operation-summary`_.
+    fn name(&self) -> &str;
+
+    /// Default [`Severity`] of the issues reported by the rule. This can be changed with
+    /// [`Linter::severity`]. By default this is [`Severity::Warning`].
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Check the [`OpenApi`] document and return all found [`Violation`]s.
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation>;
+}
+
+/// Single problem found by a [`Rule`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Violation {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the object within the
+    /// serialized [`OpenApi`] document where the problem was found.
+    pub location: String,
+    /// Human readable description of the problem.
+    pub message: String,
+}
+
+impl Violation {
+    /// Construct a new [`Violation`] with `location` and `message`.
+    pub fn new<L: Into<String>, M: Into<String>>(location: L, message: M) -> Self {
+        Self {
+            location: location.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Single issue of the [`LintReport`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct LintIssue {
+    /// Name of the [`Rule`] which reported the issue.
+    pub rule: String,
+    /// Configured [`Severity`] of the issue.
+    pub severity: Severity,
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the object within the
+    /// serialized [`OpenApi`] document where the issue was found.
+    pub location: String,
+    /// Human readable description of the issue.
+    pub message: String,
+}
+
+impl Display for LintIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.severity, self.rule, self.location, self.message
+        )
+    }
+}
+
+/// Report of [`Linter::lint`] listing all found [`LintIssue`]s.
+///
+/// [`Display`] formats the report as text with one issue per line.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct LintReport {
+    /// Found issues in the order of the rules of the [`Linter`].
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    /// Returns `true` if no issues were found.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns `true` if any of the issues has [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error)
+    }
+
+    /// Serialize the report to pretty formatted JSON String.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the configured [`Rule`]s over [`OpenApi`] documents.
+///
+/// [`Linter::new`] creates a linter without any rules and [`Linter::default`] creates a linter
+/// with the default ruleset listed in the [module documentation][self].
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    severities: BTreeMap<String, Option<Severity>>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+            .rule(OperationSummary)
+            .rule(OperationId)
+            .rule(DeclaredTags)
+            .rule(CamelCaseProperties)
+            .rule(ClientErrorResponse)
+            .rule(StringMaxLength)
+            .rule(SchemaExamples)
+    }
+}
+
+impl Linter {
+    /// Construct a new [`Linter`] without any rules.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            severities: BTreeMap::new(),
+        }
+    }
+
+    /// Add a new [`Rule`] to the linter.
+    pub fn rule<R: Rule + 'static>(mut self, rule: R) -> Self {
+        self.rules.push(Box::new(rule));
+
+        self
+    }
+
+    /// Change [`Severity`] of the issues reported by rule with given `name`.
+    pub fn severity<S: Into<String>>(mut self, name: S, severity: Severity) -> Self {
+        self.severities.insert(name.into(), Some(severity));
+
+        self
+    }
+
+    /// Disable rule with given `name`.
+    pub fn disable<S: Into<String>>(mut self, name: S) -> Self {
+        self.severities.insert(name.into(), None);
+
+        self
+    }
+
+    /// Run all enabled rules over the [`OpenApi`] document and collect found issues to
+    /// [`LintReport`].
+    pub fn lint(&self, openapi: &OpenApi) -> LintReport {
+        let issues = self
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                let severity = self
+                    .severities
+                    .get(rule.name())
+                    .copied()
+                    .unwrap_or(Some(rule.severity()))?;
+                Some((rule, severity))
+            })
+            .flat_map(|(rule, severity)| {
+                rule.check(openapi)
+                    .into_iter()
+                    .map(move |violation| LintIssue {
+                        rule: rule.name().to_string(),
+                        severity,
+                        location: violation.location,
+                        message: violation.message,
+                    })
+            })
+            .collect();
+
+        LintReport { issues }
+    }
+}
+
+impl OpenApi {
+    /// Lint the [`OpenApi`] document with the default ruleset of [`Linter::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// let report = OpenApi::default().lint();
+    /// assert!(report.is_empty());
+    /// ```
+    pub fn lint(&self) -> LintReport {
+        Linter::default().lint(self)
+    }
+}
+
+/// Rule _`operation-summary`_ requiring every [`Operation`] to have a _`summary`_.
+pub struct OperationSummary;
+
+impl Rule for OperationSummary {
+    fn name(&self) -> &str {
+        "operation-summary"
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for_each_operation(openapi, |operation, location| {
+            if operation.summary.as_ref().map_or(true, String::is_empty) {
+                violations.push(Violation::new(
+                    location.pointer(),
+                    "operation has no summary",
+                ));
+            }
+        });
+
+        violations
+    }
+}
+
+/// Rule _`operation-id`_ requiring every [`Operation`] to have an _`operationId`_.
+pub struct OperationId;
+
+impl Rule for OperationId {
+    fn name(&self) -> &str {
+        "operation-id"
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for_each_operation(openapi, |operation, location| {
+            if operation
+                .operation_id
+                .as_ref()
+                .map_or(true, String::is_empty)
+            {
+                violations.push(Violation::new(
+                    location.pointer(),
+                    "operation has no operationId",
+                ));
+            }
+        });
+
+        violations
+    }
+}
+
+/// Rule _`declared-tags`_ requiring every tag of [`Operation`]s to be declared in top level
+/// [`OpenApi::tags`].
+pub struct DeclaredTags;
+
+impl Rule for DeclaredTags {
+    fn name(&self) -> &str {
+        "declared-tags"
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let declared = openapi
+            .tags
+            .iter()
+            .flatten()
+            .map(|tag| tag.name.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let mut violations = Vec::new();
+        for_each_operation(openapi, |operation, location| {
+            for tag in operation.tags.iter().flatten() {
+                if !declared.contains(tag.as_str()) {
+                    violations.push(Violation::new(
+                        location.pointer(),
+                        format!("tag `{tag}` is not declared in top level tags"),
+                    ));
+                }
+            }
+        });
+
+        violations
+    }
+}
+
+/// Rule _`camel-case-properties`_ requiring every property name of object [`Schema`]s to be in
+/// camelCase e.g. _`petName`_.
+pub struct CamelCaseProperties;
+
+impl Rule for CamelCaseProperties {
+    fn name(&self) -> &str {
+        "camel-case-properties"
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let is_camel_case = |name: &str| {
+            name.chars()
+                .next()
+                .is_some_and(|first| first.is_ascii_lowercase())
+                && name.chars().all(|c| c.is_ascii_alphanumeric())
+        };
+
+        let mut violations = Vec::new();
+        for_each_schema(openapi, |schema, location| {
+            let Schema::Object(object) = schema else {
+                return;
+            };
+            location.push("properties");
+            for name in object.properties.keys() {
+                if !is_camel_case(name) {
+                    location.push(name);
+                    violations.push(Violation::new(
+                        location.pointer(),
+                        format!("property name `{name}` is not in camelCase"),
+                    ));
+                    location.pop();
+                }
+            }
+            location.pop();
+        });
+
+        violations
+    }
+}
+
+/// Rule _`client-error-response`_ requiring every [`Operation`] to document at least one 4xx
+/// response e.g. _`400`_ or _`4XX`_.
+pub struct ClientErrorResponse;
+
+impl Rule for ClientErrorResponse {
+    fn name(&self) -> &str {
+        "client-error-response"
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for_each_operation(openapi, |operation, location| {
+            if !operation
+                .responses
+                .responses
+                .keys()
+                .any(|status| status.starts_with('4'))
+            {
+                violations.push(Violation::new(
+                    location.pointer(),
+                    "operation has no 4xx response",
+                ));
+            }
+        });
+
+        violations
+    }
+}
+
+/// Rule _`string-max-length`_ requiring every string [`Schema`] to have _`maxLength`_ unless
+/// the allowed values are restricted with _`enum`_ or _`const`_.
+///
+/// By default this rule has [`Severity::Info`].
+pub struct StringMaxLength;
+
+impl Rule for StringMaxLength {
+    fn name(&self) -> &str {
+        "string-max-length"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for_each_schema(openapi, |schema, location| {
+            let Schema::Object(object) = schema else {
+                return;
+            };
+            let is_string = match &object.schema_type {
+                SchemaType::Type(schema_type) => *schema_type == Type::String,
+                SchemaType::Array(types) => types.contains(&Type::String),
+                SchemaType::AnyValue => false,
+            };
+            if is_string
+                && object.max_length.is_none()
+                && object.enum_values.is_none()
+                && object.const_value.is_none()
+            {
+                violations.push(Violation::new(
+                    location.pointer(),
+                    "string schema has no maxLength",
+                ));
+            }
+        });
+
+        violations
+    }
+}
+
+/// Rule _`schema-examples`_ requiring every [`Schema`] of
+/// [`Components::schemas`][schemas] to have _`example`_ or _`examples`_.
+///
+/// By default this rule has [`Severity::Info`].
+///
+/// [schemas]: crate::openapi::Components::schemas
+pub struct SchemaExamples;
+
+impl Rule for SchemaExamples {
+    fn name(&self) -> &str {
+        "schema-examples"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, openapi: &OpenApi) -> Vec<Violation> {
+        let mut location = Location::new();
+        location.push("components");
+        location.push("schemas");
+
+        let mut violations = Vec::new();
+        for (name, schema) in openapi
+            .components
+            .iter()
+            .flat_map(|components| components.schemas.iter())
+        {
+            let has_examples = match schema {
+                RefOr::T(Schema::Object(object)) => {
+                    object.example.is_some() || !object.examples.is_empty()
+                }
+                RefOr::T(Schema::Array(array)) => {
+                    array.example.is_some() || !array.examples.is_empty()
+                }
+                RefOr::T(Schema::OneOf(one_of)) => {
+                    one_of.example.is_some() || !one_of.examples.is_empty()
+                }
+                RefOr::T(Schema::AllOf(all_of)) => {
+                    all_of.example.is_some() || !all_of.examples.is_empty()
+                }
+                RefOr::T(Schema::AnyOf(any_of)) => {
+                    any_of.example.is_some() || !any_of.examples.is_empty()
+                }
+                _ => true,
+            };
+            if !has_examples {
+                location.push(name);
+                violations.push(Violation::new(location.pointer(), "schema has no example"));
+                location.pop();
+            }
+        }
+
+        violations
+    }
+}
+
+/// Call `f` for every [`Operation`] of paths, webhooks and callbacks of the document.
+fn for_each_operation<'a>(openapi: &'a OpenApi, f: impl FnMut(&'a Operation, &Location)) {
+    struct Operations<F>(F);
+
+    impl<'a, F: FnMut(&'a Operation, &Location)> Visit<'a> for Operations<F> {
+        fn visit_operation(&mut self, operation: &'a Operation, location: &mut Location) {
+            (self.0)(operation, location);
+            visit::walk_operation(self, operation, location);
+        }
+    }
+
+    Operations(f).visit_openapi(openapi, &mut Location::new());
+}
+
+/// Call `f` for every [`Schema`] of the document including nested schemas.
+fn for_each_schema<'a>(openapi: &'a OpenApi, f: impl FnMut(&'a Schema, &mut Location)) {
+    struct Schemas<F>(F);
+
+    impl<'a, F: FnMut(&'a Schema, &mut Location)> Visit<'a> for Schemas<F> {
+        fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+            (self.0)(schema, location);
+            visit::walk_schema(self, schema, location);
+        }
+    }
+
+    Schemas(f).visit_openapi(openapi, &mut Location::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::tag::TagBuilder;
+    use crate::openapi::{
+        ComponentsBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem, PathsBuilder,
+        ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .summary(Some("List pets"))
+                                .operation_id(Some("list_pets"))
+                                .tag("pets")
+                                .response("200", ResponseBuilder::new())
+                                .response("4XX", ResponseBuilder::new()),
+                        ),
+                    )
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Delete,
+                            OperationBuilder::new()
+                                .tag("admin")
+                                .response("204", ResponseBuilder::new()),
+                        ),
+                    ),
+            )
+            .tags(Some([TagBuilder::new().name("pets").build()]))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("pet_name", ObjectBuilder::new().schema_type(Type::String))
+                            .property(
+                                "kind",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .enum_values(Some(["cat", "dog"])),
+                            )
+                            .property(
+                                "nickName",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .max_length(Some(20)),
+                            )
+                            .examples([json!({"pet_name": "Garfield"})]),
+                    )
+                    .schema("Owner", ObjectBuilder::new())
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn lint_with_default_rules() {
+        let report = api().lint();
+
+        assert_eq!(
+            report.to_string(),
+            "warning[operation-summary] /paths/~1pets~1{id}/delete: operation has no summary
+warning[operation-id] /paths/~1pets~1{id}/delete: operation has no operationId
+warning[declared-tags] /paths/~1pets~1{id}/delete: tag `admin` is not declared in top level tags
+warning[camel-case-properties] /components/schemas/Pet/properties/pet_name: property name `pet_name` is not in camelCase
+warning[client-error-response] /paths/~1pets~1{id}/delete: operation has no 4xx response
+info[string-max-length] /components/schemas/Pet/properties/pet_name: string schema has no maxLength
+info[schema-examples] /components/schemas/Owner: schema has no example
+"
+        );
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn lint_with_configured_severities() {
+        let report = Linter::default()
+            .severity("declared-tags", Severity::Error)
+            .disable("operation-summary")
+            .disable("operation-id")
+            .disable("camel-case-properties")
+            .disable("string-max-length")
+            .disable("schema-examples")
+            .lint(&api());
+
+        assert!(report.has_errors());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&report.to_json().unwrap()).unwrap(),
+            json!({
+                "issues": [
+                    {
+                        "rule": "declared-tags",
+                        "severity": "error",
+                        "location": "/paths/~1pets~1{id}/delete",
+                        "message": "tag `admin` is not declared in top level tags"
+                    },
+                    {
+                        "rule": "client-error-response",
+                        "severity": "warning",
+                        "location": "/paths/~1pets~1{id}/delete",
+                        "message": "operation has no 4xx response"
+                    }
+                ]
+            })
+        );
+    }
+}
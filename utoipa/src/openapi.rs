@@ -24,21 +24,42 @@ pub use self::{
     tag::Tag,
 };
 
+pub mod bundle;
+pub mod callback;
+mod canonical;
 pub mod content;
+pub mod dedupe;
+pub mod diff;
+mod downgrade;
 pub mod encoding;
 pub mod example;
 pub mod extensions;
 pub mod external_docs;
+mod filter;
+mod generate;
 pub mod header;
 pub mod info;
+#[cfg(feature = "instance_validation")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "instance_validation")))]
+pub mod instance_validation;
 pub mod link;
+pub mod merge;
+mod normalize;
+pub mod overlay;
 pub mod path;
+mod postman;
+mod prune;
+mod render;
 pub mod request_body;
+mod resolve;
 pub mod response;
 pub mod schema;
 pub mod security;
 pub mod server;
 pub mod tag;
+mod typescript;
+pub mod validation;
+pub mod visit;
 pub mod xml;
 
 builder! {
@@ -65,12 +86,18 @@ builder! {
     /// construct a new [`OpenApi`] object.
     ///
     /// See more details at <https://spec.openapis.org/oas/latest.html#openapi-object>.
+    ///
+    /// [`OpenApi`] is always modeled with OpenAPI 3.1 constructs. When [`OpenApi::openapi`] is
+    /// set to [`OpenApiVersion::Version30`] the document will be rewritten to OpenAPI 3.0.3
+    /// compatible form when it is serialized. See [`OpenApiVersion::Version30`] for more details.
     #[non_exhaustive]
     #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    #[serde(rename_all = "camelCase")]
+    #[serde(rename_all = "camelCase", remote = "Self")]
     pub struct OpenApi {
         /// OpenAPI document version.
+        ///
+        /// This also defines the output format of the serialized OpenAPI document.
         pub openapi: OpenApiVersion,
 
         /// Provides metadata about the API.
@@ -91,6 +118,14 @@ builder! {
         /// See more details at <https://spec.openapis.org/oas/latest.html#paths-object>.
         pub paths: Paths,
 
+        /// Incoming webhooks that may be received as part of this API and that the API consumer
+        /// may choose to implement. The key is unique name of the webhook and the value is
+        /// [`PathItem`] describing the request that may be initiated by the API provider.
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#oasWebhooks>.
+        #[serde(skip_serializing_if = "PathsMap::is_empty", default)]
+        pub webhooks: PathsMap<String, PathItem>,
+
         /// Holds various reusable schemas for the OpenAPI document.
         ///
         /// Few of these elements are security schemas and object schemas.
@@ -181,18 +216,23 @@ impl OpenApi {
 
     /// Merge `other` [`OpenApi`] consuming it and resuming it's content.
     ///
-    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `schemas`, `responses`,
-    /// `security_schemes`, `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
+    /// Merge function will take all `self` nonexistent _`servers`, `paths`, `components`,
+    /// `security_requirements` and `tags`_ from _`other`_ [`OpenApi`].
     ///
-    /// This function performs a shallow comparison for `paths`, `schemas`, `responses` and
-    /// `security schemes` which means that only _`name`_ and _`path`_ is used for comparison. When
+    /// This function performs a shallow comparison for `paths` and all the reusable items of the
+    /// `components` e.g. `schemas`, `responses`, `parameters` and `security schemes` which means
+    /// that only _`name`_ and _`path`_ is used for comparison. When
     /// match occurs the whole item will be ignored from merged results. Only items not
     /// found will be appended to `self`.
     ///
+    /// For _`webhooks`_ the operations of a webhook found with same name will be merged to the
+    /// existing webhook in the same way as operations of the _`paths`_.
+    ///
     /// For _`servers`_, _`tags`_ and _`security_requirements`_ the whole item will be used for
     /// comparison. Items not found from `self` will be appended to `self`.
     ///
-    /// **Note!** `info`, `openapi`, `external_docs` and `schema` will not be merged.
+    /// **Note!** `info`, `openapi`, `external_docs` and `schema` will not be merged. Use
+    /// [`OpenApi::merge_with`] for merging those and for reporting conflicting definitions.
     pub fn merge(&mut self, mut other: OpenApi) {
         if let Some(other_servers) = &mut other.servers {
             let servers = self.servers.get_or_insert(Vec::new());
@@ -204,6 +244,14 @@ impl OpenApi {
             self.paths.merge(other.paths);
         };
 
+        for (name, that) in other.webhooks {
+            if let Some(this) = self.webhooks.get_mut(&name) {
+                this.merge_operations(that);
+            } else {
+                self.webhooks.insert(name, that);
+            }
+        }
+
         if let Some(other_components) = &mut other.components {
             let components = self.components.get_or_insert(Components::default());
 
@@ -223,6 +271,17 @@ impl OpenApi {
             components
                 .security_schemes
                 .append(&mut other_components.security_schemes);
+
+            merge_components_map(&mut components.parameters, &mut other_components.parameters);
+            merge_components_map(&mut components.examples, &mut other_components.examples);
+            merge_components_map(
+                &mut components.request_bodies,
+                &mut other_components.request_bodies,
+            );
+            merge_components_map(&mut components.headers, &mut other_components.headers);
+            merge_components_map(&mut components.links, &mut other_components.links);
+            merge_components_map(&mut components.callbacks, &mut other_components.callbacks);
+            merge_components_map(&mut components.path_items, &mut other_components.path_items);
         }
 
         if let Some(other_security) = &mut other.security {
@@ -243,6 +302,7 @@ impl OpenApi {
     /// Nesting performs custom [`OpenApi::merge`] where `other` [`OpenApi`] paths are prepended with given
     /// `path` and then appended to _`paths`_ of this [`OpenApi`] instance. Rest of the  `other`
     /// [`OpenApi`] instance is merged to this [`OpenApi`] with [`OpenApi::merge_from`] method.
+    /// Webhooks are not prefixed with the `path` since they are identified by name.
     ///
     /// **If multiple** APIs are being nested with same `path` only the **last** one will be retained.
     ///
@@ -301,7 +361,64 @@ impl OpenApi {
     }
 }
 
+/// Append all items of _`other`_ not found by name from _`this`_ reusable components map.
+fn merge_components_map<T>(
+    this: &mut std::collections::BTreeMap<String, T>,
+    other: &mut std::collections::BTreeMap<String, T>,
+) {
+    other.retain(|name, _| !this.contains_key(name));
+    this.append(other);
+}
+
+impl Serialize for OpenApi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.openapi {
+            OpenApiVersion::Version31 => OpenApi::serialize(self, serializer),
+            OpenApiVersion::Version30 => {
+                // serialize to JSON first since `serde_json::Value` does not keep the order of
+                // the keys unless `serde_json/preserve_order` is enabled
+                let mut json = Vec::new();
+                OpenApi::serialize(self, &mut serde_json::Serializer::new(&mut json))
+                    .map_err(serde::ser::Error::custom)?;
+                let mut document = serde_json::from_slice::<downgrade::Value>(&json)
+                    .map_err(serde::ser::Error::custom)?;
+                downgrade::downgrade_document(&mut document);
+
+                document.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenApi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        OpenApi::deserialize(deserializer)
+    }
+}
+
 impl OpenApiBuilder {
+    /// Add [`OpenApiVersion`] of the OpenAPI document. This defines the format of the serialized
+    /// OpenAPI document.
+    ///
+    /// # Examples
+    ///
+    /// _**Output OpenAPI 3.0.3 document.**_
+    /// ```rust
+    /// # use utoipa::openapi::{OpenApiBuilder, OpenApiVersion};
+    /// let openapi = OpenApiBuilder::new()
+    ///     .openapi(OpenApiVersion::Version30)
+    ///     .build();
+    /// ```
+    pub fn openapi(mut self, openapi: OpenApiVersion) -> Self {
+        set_value!(self openapi openapi)
+    }
+
     /// Add [`Info`] metadata of the API.
     pub fn info<I: Into<Info>>(mut self, info: I) -> Self {
         set_value!(self info info.into())
@@ -317,6 +434,61 @@ impl OpenApiBuilder {
         set_value!(self paths paths.into())
     }
 
+    /// Add map of webhooks with unique webhook name and [`PathItem`] describing the webhook
+    /// request.
+    pub fn webhooks<I: IntoIterator<Item = (N, PathItem)>, N: Into<String>>(
+        mut self,
+        webhooks: I,
+    ) -> Self {
+        set_value!(self webhooks webhooks.into_iter().map(|(name, item)| (name.into(), item)).collect())
+    }
+
+    /// Append webhook with unique _`name`_ and [`PathItem`] to the webhooks. If webhook already
+    /// exists the [`Operation`][operation]s of the [`PathItem`] will be merged with the existing
+    /// webhook.
+    ///
+    /// # Examples
+    ///
+    /// _**Add `newPet` webhook.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, Response};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let openapi = OpenApiBuilder::new()
+    ///     .webhook(
+    ///         "newPet",
+    ///         PathItem::new(
+    ///             HttpMethod::Post,
+    ///             OperationBuilder::new().response("200", Response::new("Pet received")),
+    ///         ),
+    ///     )
+    ///     .build();
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    pub fn webhook<N: Into<String>>(mut self, name: N, item: PathItem) -> Self {
+        let name = name.into();
+        if let Some(existing_item) = self.webhooks.get_mut(&name) {
+            existing_item.merge_operations(item);
+        } else {
+            self.webhooks.insert(name, item);
+        }
+
+        self
+    }
+
+    /// Append webhook from type implementing [`trait@crate::Path`] trait. The
+    /// [`Path::path`][path] is used as the name of the webhook.
+    ///
+    /// This is used by [`#[derive(OpenApi)]`][derive] to register handlers annotated with
+    /// [`#[utoipa::webhook(...)]`][webhook] attribute macro.
+    ///
+    /// [path]: ../trait.Path.html#tymethod.path
+    /// [derive]: ../derive.OpenApi.html
+    /// [webhook]: ../attr.webhook.html
+    pub fn webhook_from<P: crate::Path>(self) -> Self {
+        self.webhook(P::path(), PathItem::from_path::<P>())
+    }
+
     /// Add [`Components`] to configure reusable schemas.
     pub fn components(mut self, components: Option<Components>) -> Self {
         set_value!(self components components)
@@ -366,6 +538,27 @@ pub enum OpenApiVersion {
     #[serde(rename = "3.1.0")]
     #[default]
     Version31,
+
+    /// Will serialize to `3.0.3`, the latest OpenAPI 3.0 version.
+    ///
+    /// When [`OpenApi`] is serialized with this version the OpenAPI 3.1 constructs without
+    /// direct OpenAPI 3.0 counterpart are rewritten as follows:
+    /// * `type: [T, "null"]` becomes `type: T` with `nullable: true` and `oneOf` / `anyOf`
+    ///   containing `{"type": "null"}` becomes `nullable: true`.
+    /// * `examples` array of schema becomes single `example` with the first example.
+    /// * `const` becomes single value `enum` and boolean schemas `true` and `false` become `{}`
+    ///   and `{"not": {}}`.
+    /// * `prefixItems` becomes `items` with `anyOf` of the prefix items.
+    /// * `contentEncoding: base64` becomes `format: byte` and `contentMediaType` becomes
+    ///   `format: binary`.
+    /// * Numeric `exclusiveMinimum` and `exclusiveMaximum` become boolean flags accompanied with
+    ///   `minimum` and `maximum`.
+    /// * OpenAPI 3.1 only fields such as `webhooks`, `pathItems` of components, `identifier`
+    ///   of license and JSON Schema keywords like `propertyNames`, `patternProperties`,
+    ///   `dependentRequired`, `dependentSchemas`, `unevaluatedProperties`, `if` / `then` /
+    ///   `else`, `contains`, `$defs`, `$id` and `$anchor` are omitted.
+    #[serde(rename = "3.0.3")]
+    Version30,
 }
 
 impl<'de> Deserialize<'de> for OpenApiVersion {
@@ -379,7 +572,7 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
             type Value = OpenApiVersion;
 
             fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-                formatter.write_str("a version string in 3.1.x format")
+                formatter.write_str("a version string in 3.1.x or 3.0.x format")
             }
 
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -398,14 +591,16 @@ impl<'de> Deserialize<'de> for OpenApiVersion {
                     .flat_map(|digit| digit.parse::<i8>())
                     .collect::<Vec<_>>();
 
-                if version.len() == 3 && version.first() == Some(&3) && version.get(1) == Some(&1) {
-                    Ok(OpenApiVersion::Version31)
-                } else {
-                    let expected: &dyn Expected = &"3.1.0";
-                    Err(Error::invalid_value(
-                        serde::de::Unexpected::Str(&v),
-                        expected,
-                    ))
+                match version.as_slice() {
+                    [3, 1, _] => Ok(OpenApiVersion::Version31),
+                    [3, 0, _] => Ok(OpenApiVersion::Version30),
+                    _ => {
+                        let expected: &dyn Expected = &"3.1.0 or 3.0.3";
+                        Err(Error::invalid_value(
+                            serde::de::Unexpected::Str(&v),
+                            expected,
+                        ))
+                    }
                 }
             }
         }
@@ -642,7 +837,7 @@ mod tests {
 
     use crate::openapi::{
         info::InfoBuilder,
-        path::{OperationBuilder, PathsBuilder},
+        path::{OperationBuilder, ParameterBuilder, ParameterIn, PathsBuilder},
     };
 
     use super::{response::Response, *};
@@ -650,6 +845,16 @@ mod tests {
     #[test]
     fn serialize_deserialize_openapi_version_success() -> Result<(), serde_json::Error> {
         assert_eq!(serde_json::to_value(&OpenApiVersion::Version31)?, "3.1.0");
+        assert_eq!(serde_json::to_value(&OpenApiVersion::Version30)?, "3.0.3");
+        assert_eq!(
+            serde_json::from_value::<OpenApiVersion>(json!("3.1.1"))?,
+            OpenApiVersion::Version31
+        );
+        assert_eq!(
+            serde_json::from_value::<OpenApiVersion>(json!("3.0.1"))?,
+            OpenApiVersion::Version30
+        );
+        assert!(serde_json::from_value::<OpenApiVersion>(json!("2.0.0")).is_err());
         Ok(())
     }
 
@@ -1056,4 +1261,546 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn serialize_openapi_version_30_keeps_stricter_limit() {
+        use crate::openapi::schema::ObjectBuilder;
+
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Limits",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .minimum(Some(5))
+                            .exclusive_minimum(Some(0))
+                            .maximum(Some(10))
+                            .exclusive_maximum(Some(10)),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let api_json = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+
+        assert_json_eq!(
+            api_json["components"]["schemas"]["Limits"],
+            json!({
+                "type": "integer",
+                "minimum": 5,
+                "maximum": 10,
+                "exclusiveMaximum": true
+            })
+        );
+    }
+
+    #[cfg(all(feature = "preserve_order", feature = "preserve_path_order"))]
+    #[test]
+    fn serialize_openapi_version_30_preserves_order() {
+        use crate::openapi::schema::ObjectBuilder;
+
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/zebra",
+                        PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+                    )
+                    .path(
+                        "/apple",
+                        PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("zz", ObjectBuilder::new().schema_type(Type::String))
+                            .property("aa", ObjectBuilder::new().schema_type(Type::String)),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let json = api.to_json().expect("OpenApi must serialize to JSON");
+
+        assert!(json.find("/zebra") < json.find("/apple"));
+        assert!(json.find("\"zz\"") < json.find("\"aa\""));
+        assert!(json.find("\"openapi\"") < json.find("\"paths\""));
+    }
+
+    #[test]
+    fn serialize_openapi_version_30_keeps_order_without_serde_json_preserve_order() {
+        use crate::openapi::schema::ObjectBuilder;
+
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Object)
+                            .description(Some("Pet"))
+                            .const_value(Some(json!({"name": "Lassie"}))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let json = api.to_json().expect("OpenApi must serialize to JSON");
+
+        // serialized field order of the 3.1 output is kept instead of alphabetical order
+        assert!(json.find("\"openapi\"") < json.find("\"info\""));
+        assert!(json.find("\"type\"") < json.find("\"description\""));
+        assert!(json.find("\"description\"") < json.find("\"enum\""));
+    }
+
+    #[test]
+    fn serialize_openapi_version_30_keeps_data_fields() {
+        use crate::openapi::{example::ExampleBuilder, link::LinkBuilder};
+
+        let data = json!({"schema": {"type": ["string", "null"], "const": 1}});
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new()
+                                .content(
+                                    "application/json",
+                                    ContentBuilder::new()
+                                        .example(Some(data.clone()))
+                                        .examples_from_iter([(
+                                            "pet",
+                                            ExampleBuilder::new().value(Some(data.clone())),
+                                        )])
+                                        .build(),
+                                )
+                                .link(
+                                    "owner",
+                                    LinkBuilder::new().parameter("filter", data.clone()),
+                                ),
+                        ),
+                    ),
+                ),
+            )
+            .build();
+
+        let api_json = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+        let response = &api_json["paths"]["/pets"]["get"]["responses"]["200"];
+
+        assert_json_eq!(response["content"]["application/json"]["example"], data);
+        assert_json_eq!(
+            response["content"]["application/json"]["examples"]["pet"]["value"],
+            data
+        );
+        assert_json_eq!(response["links"]["owner"]["parameters"]["filter"], data);
+    }
+
+    #[test]
+    fn serialize_openapi_version_30_downgrades_document() {
+        use crate::openapi::{
+            path::{ParameterBuilder, ParameterIn},
+            schema::{ArrayBuilder, ArrayItems, ObjectBuilder, SchemaType},
+            RefOr,
+        };
+
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .info(
+                InfoBuilder::new()
+                    .title("title")
+                    .version("1.0.0")
+                    .license(Some(
+                        LicenseBuilder::new()
+                            .name("MIT")
+                            .identifier(Some("MIT"))
+                            .build(),
+                    )),
+            )
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().parameter(
+                            ParameterBuilder::new()
+                                .name("id")
+                                .parameter_in(ParameterIn::Path)
+                                .schema(Some(
+                                    ObjectBuilder::new()
+                                        .schema_type(SchemaType::from_iter([
+                                            Type::Integer,
+                                            Type::Null,
+                                        ]))
+                                        .exclusive_minimum(Some(0)),
+                                )),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "name",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .examples(["Doggo", "Catto"]),
+                            )
+                            .property(
+                                "owner",
+                                OneOfBuilder::new()
+                                    .item(ObjectBuilder::new().schema_type(Type::Null))
+                                    .item(Ref::from_schema_name("Owner")),
+                            )
+                            .property(
+                                "photo",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .content_media_type("image/png"),
+                            )
+                            .property(
+                                "position",
+                                ArrayBuilder::new().items(ArrayItems::False).prefix_items([
+                                    ObjectBuilder::new().schema_type(Type::Number),
+                                    ObjectBuilder::new().schema_type(Type::Number),
+                                ]),
+                            ),
+                    )
+                    .schema(
+                        "Owner",
+                        RefOr::T(
+                            ObjectBuilder::new()
+                                .schema_type(Type::Object)
+                                .property_names(Some(
+                                    ObjectBuilder::new().schema_type(Type::String),
+                                ))
+                                .into(),
+                        ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let api_json = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+
+        assert_json_eq!(
+            api_json,
+            json!({
+                "openapi": "3.0.3",
+                "info": {
+                    "title": "title",
+                    "version": "1.0.0",
+                    "license": {
+                        "name": "MIT"
+                    }
+                },
+                "paths": {
+                    "/pets/{id}": {
+                        "get": {
+                            "parameters": [
+                                {
+                                    "name": "id",
+                                    "in": "path",
+                                    "required": false,
+                                    "schema": {
+                                        "type": "integer",
+                                        "nullable": true,
+                                        "minimum": 0,
+                                        "exclusiveMinimum": true
+                                    }
+                                }
+                            ],
+                            "responses": {}
+                        }
+                    }
+                },
+                "components": {
+                    "schemas": {
+                        "Owner": {
+                            "type": "object"
+                        },
+                        "Pet": {
+                            "type": "object",
+                            "properties": {
+                                "name": {
+                                    "type": "string",
+                                    "example": "Doggo"
+                                },
+                                "owner": {
+                                    "allOf": [
+                                        {
+                                            "$ref": "#/components/schemas/Owner"
+                                        }
+                                    ],
+                                    "nullable": true
+                                },
+                                "photo": {
+                                    "type": "string",
+                                    "format": "binary"
+                                },
+                                "position": {
+                                    "type": "array",
+                                    "items": {
+                                        "anyOf": [
+                                            { "type": "number" },
+                                            { "type": "number" }
+                                        ]
+                                    },
+                                    "minItems": 2,
+                                    "maxItems": 2
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+
+        let api: OpenApi = serde_json::from_value(json!({
+            "openapi": "3.0.3",
+            "info": { "title": "title", "version": "1.0.0" },
+            "paths": {}
+        }))
+        .expect("OpenApi must deserialize");
+        assert_eq!(api.openapi, OpenApiVersion::Version30);
+    }
+
+    #[test]
+    fn serialize_openapi_version_30_downgrades_json_schema_keywords() {
+        use crate::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema};
+
+        let api = OpenApiBuilder::new()
+            .openapi(OpenApiVersion::Version30)
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .id(Some("https://example.com/pet.json"))
+                            .def("Name", ObjectBuilder::new().schema_type(Type::String))
+                            .property(
+                                "kind",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .const_value(Some("pet")),
+                            )
+                            .property("any", Schema::Bool(true))
+                            .property("none", Schema::Bool(false))
+                            .property(
+                                "tags",
+                                ArrayBuilder::new()
+                                    .items(ObjectBuilder::new().schema_type(Type::String))
+                                    .contains(Some(ObjectBuilder::new().const_value(Some("cute")))),
+                            )
+                            .pattern_property("^x-", ObjectBuilder::new().schema_type(Type::String))
+                            .dependent_required("name", ["kind"])
+                            .unevaluated_properties(Some(false))
+                            .not(Some(
+                                ObjectBuilder::new()
+                                    .schema_type(Type::Integer)
+                                    .const_value(Some(1)),
+                            ))
+                            .if_schema(Some(ObjectBuilder::new().required("kind")))
+                            .then_schema(Some(ObjectBuilder::new().required("any"))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let api_json = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+
+        assert_json_eq!(
+            api_json.pointer("/components/schemas/Pet"),
+            json!({
+                "type": "object",
+                "properties": {
+                    "kind": {
+                        "type": "string",
+                        "enum": ["pet"]
+                    },
+                    "any": {},
+                    "none": {
+                        "not": {}
+                    },
+                    "tags": {
+                        "type": "array",
+                        "items": {
+                            "type": "string"
+                        }
+                    }
+                },
+                "not": {
+                    "type": "integer",
+                    "enum": [1]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn merge_openapi_with_reusable_components() {
+        let mut api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .parameter(
+                        "page",
+                        ParameterBuilder::new()
+                            .name("page")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .header(
+                        "X-Rate-Limit",
+                        Header::new(Object::with_type(schema::Type::Integer)),
+                    )
+                    .build(),
+            ))
+            .build();
+        let other = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .parameter(
+                        "page",
+                        ParameterBuilder::new()
+                            .name("page")
+                            .parameter_in(ParameterIn::Header),
+                    )
+                    .parameter(
+                        "page_size",
+                        ParameterBuilder::new()
+                            .name("page_size")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .callback("onEvent", Ref::from_callback_name("onOtherEvent"))
+                    .path_item("Pets", Ref::from_path_item_name("OtherPets"))
+                    .build(),
+            ))
+            .build();
+
+        api.merge(other);
+
+        let value = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+        assert_json_eq!(
+            value.get("components"),
+            json!({
+                "parameters": {
+                    "page": {
+                        "name": "page",
+                        "in": "query",
+                        "required": false
+                    },
+                    "page_size": {
+                        "name": "page_size",
+                        "in": "query",
+                        "required": false
+                    }
+                },
+                "headers": {
+                    "X-Rate-Limit": {
+                        "schema": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "callbacks": {
+                    "onEvent": {
+                        "$ref": "#/components/callbacks/onOtherEvent"
+                    }
+                },
+                "pathItems": {
+                    "Pets": {
+                        "$ref": "#/components/pathItems/OtherPets"
+                    }
+                }
+            })
+        );
+
+        api.openapi = OpenApiVersion::Version30;
+        let value = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+        assert!(
+            value.pointer("/components/pathItems").is_none(),
+            "path items must be omitted from OpenAPI 3.0"
+        );
+    }
+
+    #[test]
+    fn merge_openapi_with_webhooks() {
+        let mut api = OpenApiBuilder::new()
+            .webhook(
+                "newPet",
+                PathItem::new(
+                    HttpMethod::Post,
+                    OperationBuilder::new().response("200", Response::new("Pet received")),
+                ),
+            )
+            .build();
+        let other = OpenApiBuilder::new()
+            .webhook(
+                "newPet",
+                PathItem::new(
+                    HttpMethod::Put,
+                    OperationBuilder::new().response("200", Response::new("Pet updated")),
+                ),
+            )
+            .webhook(
+                "deletedPet",
+                PathItem::new(
+                    HttpMethod::Post,
+                    OperationBuilder::new().response("200", Response::new("Pet deleted")),
+                ),
+            )
+            .build();
+
+        api.merge(other);
+
+        let value = serde_json::to_value(&api).expect("OpenApi must serialize to JSON");
+        assert_json_eq!(
+            value.get("webhooks"),
+            json!({
+                "deletedPet": {
+                    "post": {
+                        "responses": {
+                            "200": {
+                                "description": "Pet deleted"
+                            }
+                        }
+                    }
+                },
+                "newPet": {
+                    "post": {
+                        "responses": {
+                            "200": {
+                                "description": "Pet received"
+                            }
+                        }
+                    },
+                    "put": {
+                        "responses": {
+                            "200": {
+                                "description": "Pet updated"
+                            }
+                        }
+                    }
+                }
+            })
+        );
+
+        let nested = OpenApi::new(Info::new("api", "1.0.0"), Paths::new()).nest("/api", api);
+        assert!(
+            nested.webhooks.contains_key("newPet") && nested.webhooks.contains_key("deletedPet"),
+            "nested webhooks must not be prefixed"
+        );
+    }
 }
@@ -0,0 +1,1010 @@
+//! Implements loading multi-file [`OpenApi`] documents and splitting documents to multiple files.
+//!
+//! [`OpenApi::from_file`] reads a root document from disk and bundles every external file
+//! reference e.g. _`./common.yaml#/components/schemas/Error`_ into the [`OpenApi::components`]
+//! of the loaded document. [`OpenApi::write_split`] does the inverse and writes every reusable
+//! component to a separate file referenced with relative references from the root document.
+//!
+//! JSON files are always supported and YAML files with _`.yaml`_ or _`.yml`_ extension when the
+//! _`yaml`_ feature is enabled. Remote references e.g. _`https://example.com/schemas.json`_ are
+//! left untouched.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use super::validation::{escape_pointer_segment, unescape_pointer_segment};
+use super::OpenApi;
+
+/// Kinds of components which can be referenced with _`$ref`_ and therefore split to separate
+/// files.
+const COMPONENT_KINDS: [&str; 9] = [
+    "schemas",
+    "responses",
+    "parameters",
+    "examples",
+    "requestBodies",
+    "headers",
+    "links",
+    "callbacks",
+    "pathItems",
+];
+
+/// File format used by [`OpenApi::write_split`].
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum FileFormat {
+    /// Write files as pretty printed JSON with _`.json`_ extension.
+    #[default]
+    Json,
+    /// Write files as YAML with _`.yaml`_ extension.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    Yaml,
+}
+
+impl FileFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            #[cfg(feature = "yaml")]
+            Self::Yaml => "yaml",
+        }
+    }
+
+    fn serialize(self, path: &Path, value: &Value) -> Result<String, BundleError> {
+        let invalid_document = |message: String| BundleError::Parse {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        match self {
+            Self::Json => serde_json::to_string_pretty(value)
+                .map_err(|error| invalid_document(error.to_string())),
+            #[cfg(feature = "yaml")]
+            Self::Yaml => {
+                serde_yaml::to_string(value).map_err(|error| invalid_document(error.to_string()))
+            }
+        }
+    }
+}
+
+/// Error returned from [`OpenApi::from_file`] and [`OpenApi::write_split`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// Reading or writing file failed.
+    Io {
+        /// Path of the file.
+        path: PathBuf,
+        /// Description of the error.
+        message: String,
+    },
+    /// File could not be parsed or serialized.
+    Parse {
+        /// Path of the file.
+        path: PathBuf,
+        /// Description of the error.
+        message: String,
+    },
+    /// File is YAML file but _`yaml`_ feature is not enabled.
+    UnsupportedFormat {
+        /// Path of the file.
+        path: PathBuf,
+    },
+    /// Reference points to a value that does not exist in the referenced file.
+    UnresolvedReference {
+        /// Path of the file the reference is defined in.
+        path: PathBuf,
+        /// The unresolved reference.
+        reference: String,
+    },
+    /// Bundled document is not a valid [`OpenApi`] document.
+    InvalidDocument {
+        /// Description of the error.
+        message: String,
+    },
+}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, message } => write!(f, "{}: {message}", path.display()),
+            Self::Parse { path, message } => {
+                write!(f, "{}: invalid document: {message}", path.display())
+            }
+            Self::UnsupportedFormat { path } => write!(
+                f,
+                "{}: YAML files are supported only with `yaml` feature",
+                path.display()
+            ),
+            Self::UnresolvedReference { path, reference } => write!(
+                f,
+                "{}: reference `{reference}` does not resolve to any value",
+                path.display()
+            ),
+            Self::InvalidDocument { message } => {
+                write!(f, "bundled document is invalid: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+impl OpenApi {
+    /// Load [`OpenApi`] document from the file at `path` bundling all external file references
+    /// into [`OpenApi::components`].
+    ///
+    /// External reference e.g. _`./common.yaml#/components/schemas/Error`_ is resolved relative
+    /// to the file it is defined in. Referenced value is added to the components of the same
+    /// kind and name as in the referenced file and the reference is replaced with local
+    /// reference e.g. _`#/components/schemas/Error`_. References to whole files e.g.
+    /// _`./schemas/Pet.json`_ are named after the file and added to the components of kind
+    /// named by the parent directory, defaulting to _`schemas`_. If the name is already taken by
+    /// another component a number is appended to it. References to values nested in components
+    /// or files e.g. _`./schemas/Pet.json#/properties/name`_ bundle the containing component and
+    /// refer to the value within it.
+    ///
+    /// References within referenced files are bundled recursively.
+    ///
+    /// # Examples
+    ///
+    /// _**Load document and print it as single JSON document.**_
+    /// ```rust,no_run
+    /// # use utoipa::openapi::OpenApi;
+    /// let api = OpenApi::from_file("api/openapi.yaml").expect("api must load");
+    /// println!("{}", api.to_pretty_json().unwrap());
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, BundleError> {
+        let path = canonicalize(path.as_ref())?;
+        let mut root = read_file(&path)?;
+
+        let mut bundler = Bundler {
+            root: path.clone(),
+            files: HashMap::new(),
+            bundled: HashMap::new(),
+            components: root
+                .get_mut("components")
+                .and_then(Value::as_object_mut)
+                .map(std::mem::take)
+                .unwrap_or_default(),
+        };
+
+        // Components of the root document which only reference external files e.g. ones written
+        // by `write_split` keep their names. Register them before bundling anything else so
+        // that other references to the same files resolve to them.
+        let mut root_components = Vec::new();
+        for (kind, values) in &bundler.components {
+            for (name, value) in values.as_object().into_iter().flatten() {
+                let external = match value
+                    .as_object()
+                    .map(|value| (value.len(), value.get("$ref")))
+                {
+                    Some((1, Some(Value::String(reference)))) => bundler
+                        .resolve(reference, &path)?
+                        .filter(|(target, _)| *target != path)
+                        .map(|(target, fragment)| (reference.clone(), target, fragment)),
+                    _ => None,
+                };
+                if let Some((_, target, fragment)) = &external {
+                    bundler.bundled.insert(
+                        (target.clone(), fragment.clone()),
+                        local_reference(kind, name),
+                    );
+                }
+                root_components.push((kind.clone(), name.clone(), value.clone(), external));
+            }
+        }
+        for (kind, name, mut value, external) in root_components {
+            let mut file = path.clone();
+            if let Some((reference, target, fragment)) = external {
+                value = bundler.read_value(&target, &fragment, &reference, &path)?;
+                file = target;
+            }
+            bundler.bundle_value(&mut value, &file)?;
+            if let Some(Value::Object(components)) = bundler.components.get_mut(&kind) {
+                components.insert(name, value);
+            }
+        }
+        bundler.bundle_value(&mut root, &path)?;
+
+        if !bundler.components.is_empty() {
+            if let Value::Object(root) = &mut root {
+                root.insert("components".to_string(), Value::Object(bundler.components));
+            }
+        }
+
+        serde_json::from_value(root).map_err(|error| BundleError::InvalidDocument {
+            message: error.to_string(),
+        })
+    }
+
+    /// Write this [`OpenApi`] to directory `dir` splitting every reusable component to a separate
+    /// file and return path of the written root document.
+    ///
+    /// Root document is written to _`openapi.json`_ or _`openapi.yaml`_ depending on given
+    /// [`FileFormat`]. Components are written to _`components/{kind}/{name}.{extension}`_ e.g.
+    /// _`components/schemas/Pet.json`_ and local references to them are replaced with relative
+    /// file references. Characters of the component name other than ASCII alphanumeric
+    /// characters, _`-`_ and _`_`_ are replaced with _`_`_ in the file name and a number is
+    /// appended to names which would still clash. Components of the root document reference the
+    /// written files in order to keep their names. Security schemes are kept in the root document
+    /// as is since they are not referenced with _`$ref`_.
+    ///
+    /// Written files can be loaded back with [`OpenApi::from_file`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use utoipa::openapi::OpenApiBuilder;
+    /// # use utoipa::openapi::bundle::FileFormat;
+    /// let api = OpenApiBuilder::new().build();
+    /// let root = api.write_split("target/api", FileFormat::Json).expect("api must write");
+    /// ```
+    pub fn write_split<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        format: FileFormat,
+    ) -> Result<PathBuf, BundleError> {
+        let dir = dir.as_ref();
+        let extension = format.extension();
+        let mut root =
+            serde_json::to_value(self).map_err(|error| BundleError::InvalidDocument {
+                message: error.to_string(),
+            })?;
+
+        let file_stems = file_stems(root.get("components"));
+        let mut files = BTreeMap::new();
+        if let Some(components) = root.get_mut("components").and_then(Value::as_object_mut) {
+            for kind in COMPONENT_KINDS {
+                let Some(Value::Object(values)) = components.remove(kind) else {
+                    continue;
+                };
+                let mut references = Map::new();
+                for (name, mut value) in values {
+                    rewrite_references(&mut value, &|reference| {
+                        split_reference(reference, Some(kind), &file_stems, extension)
+                    });
+                    let stem = &file_stems[&(kind.to_string(), name.clone())];
+                    let file = Path::new("components")
+                        .join(kind)
+                        .join(format!("{stem}.{extension}"));
+                    references.insert(
+                        name,
+                        json!({"$ref": format!("./components/{kind}/{stem}.{extension}")}),
+                    );
+                    files.insert(file, value);
+                }
+                components.insert(kind.to_string(), Value::Object(references));
+            }
+        }
+        rewrite_references(&mut root, &|reference| {
+            split_reference(reference, None, &file_stems, extension)
+        });
+
+        let root_path = dir.join(format!("openapi.{extension}"));
+        files.insert(PathBuf::from(format!("openapi.{extension}")), root);
+        for (path, value) in files {
+            let path = dir.join(path);
+            let contents = format.serialize(&path, &value)?;
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).map_err(|error| io_error(parent, error))?;
+            }
+            std::fs::write(&path, contents).map_err(|error| io_error(&path, error))?;
+        }
+
+        Ok(root_path)
+    }
+}
+
+struct Bundler {
+    /// Canonical path of the root document.
+    root: PathBuf,
+    /// Already read files by their canonical path.
+    files: HashMap<PathBuf, Value>,
+    /// Local references of already bundled values by their canonical file path and fragment.
+    bundled: HashMap<(PathBuf, String), String>,
+    /// Components of the bundled document by their kind.
+    components: Map<String, Value>,
+}
+
+impl Bundler {
+    /// Bundle all references of the `value` defined in the file at `path`.
+    fn bundle_value(&mut self, value: &mut Value, path: &Path) -> Result<(), BundleError> {
+        match value {
+            Value::Object(object) => {
+                if let Some(Value::String(reference)) = object.get("$ref") {
+                    if let Some(local) = self.bundle_reference(reference, path)? {
+                        object.insert("$ref".to_string(), Value::String(local));
+                    }
+                }
+                for (key, value) in object.iter_mut() {
+                    if key != "$ref" {
+                        self.bundle_value(value, path)?;
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.bundle_value(value, path)?;
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    /// Resolve canonical path of the referenced file and fragment within it. Returns `None` for
+    /// references which are left as is.
+    fn resolve(
+        &self,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<(PathBuf, String)>, BundleError> {
+        let (file, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+        if file.contains("://") || (file.is_empty() && path == self.root) {
+            return Ok(None);
+        }
+        let (file, fragment) = (percent_decode(file), percent_decode(fragment));
+
+        let target = if file.is_empty() {
+            path.to_path_buf()
+        } else {
+            canonicalize(&path.parent().unwrap_or(Path::new("")).join(file))?
+        };
+
+        Ok(Some((target, fragment)))
+    }
+
+    /// Read value at `fragment` of the file at `target` referenced by `reference` in file at
+    /// `path`.
+    fn read_value(
+        &mut self,
+        target: &Path,
+        fragment: &str,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Value, BundleError> {
+        if !self.files.contains_key(target) {
+            let value = read_file(target)?;
+            self.files.insert(target.to_path_buf(), value);
+        }
+
+        self.files[target]
+            .pointer(fragment)
+            .cloned()
+            .ok_or_else(|| BundleError::UnresolvedReference {
+                path: path.to_path_buf(),
+                reference: reference.to_string(),
+            })
+    }
+
+    /// Bundle referenced value and return local reference to it. Returns `None` for references
+    /// which are left as is.
+    fn bundle_reference(
+        &mut self,
+        reference: &str,
+        path: &Path,
+    ) -> Result<Option<String>, BundleError> {
+        let Some((target, fragment)) = self.resolve(reference, path)? else {
+            return Ok(None);
+        };
+        if target == self.root {
+            return Ok(Some(format!("#{fragment}")));
+        }
+
+        let (component, rest) = split_fragment(&fragment);
+        if rest.is_empty() {
+            return self
+                .bundle_component(target, fragment, reference, path)
+                .map(Some);
+        }
+
+        // value nested in a component is referenced within the bundled component
+        self.read_value(&target, &fragment, reference, path)?;
+        let component = component.to_string();
+        let local = self.bundle_component(target, component, reference, path)?;
+
+        Ok(Some(format!("{local}{rest}")))
+    }
+
+    /// Bundle component at `fragment` of the file at `target` and return local reference to it.
+    /// Empty `fragment` bundles the whole file as a component.
+    fn bundle_component(
+        &mut self,
+        target: PathBuf,
+        fragment: String,
+        reference: &str,
+        path: &Path,
+    ) -> Result<String, BundleError> {
+        let key = (target.clone(), fragment.clone());
+        if let Some(local) = self.bundled.get(&key) {
+            return Ok(local.clone());
+        }
+
+        let mut value = self.read_value(&target, &fragment, reference, path)?;
+        let (kind, name) = component_name(&target, &fragment);
+        let components = self
+            .components
+            .entry(kind.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        let Value::Object(components) = components else {
+            return Err(BundleError::InvalidDocument {
+                message: format!("components `{kind}` is not an object"),
+            });
+        };
+        let name = (1..)
+            .map(|index| match index {
+                1 => name.clone(),
+                index => format!("{name}{index}"),
+            })
+            .find(|name| !components.contains_key(name))
+            .unwrap_or(name);
+        // reserve the name before bundling the value to support recursive references
+        components.insert(name.clone(), Value::Null);
+
+        let local = local_reference(&kind, &name);
+        self.bundled.insert(key, local.clone());
+
+        self.bundle_value(&mut value, &target)?;
+        if let Some(Value::Object(components)) = self.components.get_mut(&kind) {
+            components.insert(name, value);
+        }
+
+        Ok(local)
+    }
+}
+
+fn local_reference(kind: &str, name: &str) -> String {
+    format!(
+        "#/components/{}/{}",
+        escape_pointer_segment(kind),
+        escape_pointer_segment(name)
+    )
+}
+
+/// Split `fragment` to the fragment of the component containing the value and the rest of the
+/// fragment within the component. Component is the value at _`/components/{kind}/{name}`_ or
+/// otherwise the whole file.
+fn split_fragment(fragment: &str) -> (&str, &str) {
+    if !fragment.starts_with("/components/") {
+        return ("", fragment);
+    }
+
+    match fragment.match_indices('/').nth(3) {
+        Some((index, _)) => fragment.split_at(index),
+        None if fragment.match_indices('/').count() == 3 => (fragment, ""),
+        None => ("", fragment),
+    }
+}
+
+/// Resolve kind and name of the component for value at component `fragment` of file at `path`.
+fn component_name(path: &Path, fragment: &str) -> (String, String) {
+    let segments = fragment
+        .split('/')
+        .skip(1)
+        .map(unescape_pointer_segment)
+        .collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [components, kind, name] if components == "components" => (kind.clone(), name.clone()),
+        _ => {
+            let kind = path
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(|kind| kind.to_str())
+                .filter(|kind| COMPONENT_KINDS.contains(kind))
+                .unwrap_or("schemas");
+            let name = path
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .unwrap_or("Component");
+
+            (kind.to_string(), name.to_string())
+        }
+    }
+}
+
+/// Resolve file stem for every splittable component in `components` by kind and name.
+///
+/// Component names are arbitrary strings so they are sanitized to contain only ASCII
+/// alphanumeric characters, _`-`_ and _`_`_. Names which would result in the same file on a case
+/// insensitive file system get a number appended to them.
+fn file_stems(components: Option<&Value>) -> HashMap<(String, String), String> {
+    let mut stems = HashMap::new();
+    for kind in COMPONENT_KINDS {
+        let Some(Value::Object(values)) = components.and_then(|components| components.get(kind))
+        else {
+            continue;
+        };
+        let mut taken = HashSet::new();
+        for name in values.keys() {
+            let sanitized = name
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+                    _ => '_',
+                })
+                .collect::<String>();
+            let sanitized = if sanitized.is_empty() {
+                "Component".to_string()
+            } else {
+                sanitized
+            };
+            let stem = (1..)
+                .map(|index| match index {
+                    1 => sanitized.clone(),
+                    index => format!("{sanitized}{index}"),
+                })
+                .find(|stem| taken.insert(stem.to_ascii_lowercase()))
+                .unwrap_or(sanitized);
+            stems.insert((kind.to_string(), name.clone()), stem);
+        }
+    }
+
+    stems
+}
+
+/// Convert local `reference` to a relative file reference from the file of component of
+/// `from_kind`, or from root document when `None`.
+fn split_reference(
+    reference: &str,
+    from_kind: Option<&str>,
+    file_stems: &HashMap<(String, String), String>,
+    extension: &str,
+) -> Option<String> {
+    let pointer = reference.strip_prefix("#/components/")?;
+    let mut segments = pointer.splitn(3, '/');
+    let kind = segments.next()?;
+    let name = unescape_pointer_segment(segments.next()?);
+    let stem = file_stems.get(&(kind.to_string(), name))?;
+
+    let file = match from_kind {
+        Some(from_kind) if from_kind == kind => format!("./{stem}.{extension}"),
+        Some(_) => format!("../{kind}/{stem}.{extension}"),
+        None => format!("./components/{kind}/{stem}.{extension}"),
+    };
+    match segments.next() {
+        Some(rest) => Some(format!("{file}#/{}", percent_encode(rest))),
+        None => Some(file),
+    }
+}
+
+/// Percent encode every character of JSON pointer `pointer` which is not allowed in URI fragment.
+fn percent_encode(pointer: &str) -> String {
+    let mut encoded = String::with_capacity(pointer.len());
+    for byte in pointer.bytes() {
+        match byte {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~'
+            | b'/'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@' => encoded.push(byte as char),
+            byte => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    encoded
+}
+
+/// Decode percent encoded `value`. Invalid escapes are left as is.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| value.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn rewrite_references(value: &mut Value, rewrite: &dyn Fn(&str) -> Option<String>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get_mut("$ref") {
+                if let Some(rewritten) = rewrite(reference) {
+                    *reference = rewritten;
+                }
+            }
+            for value in object.values_mut() {
+                rewrite_references(value, rewrite);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                rewrite_references(value, rewrite);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn read_file(path: &Path) -> Result<Value, BundleError> {
+    let contents = std::fs::read_to_string(path).map_err(|error| io_error(path, error))?;
+    let parse_error = |message: String| BundleError::Parse {
+        path: path.to_path_buf(),
+        message,
+    };
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => {
+            serde_yaml::from_str(&contents).map_err(|error| parse_error(error.to_string()))
+        }
+        #[cfg(not(feature = "yaml"))]
+        Some("yaml" | "yml") => Err(BundleError::UnsupportedFormat {
+            path: path.to_path_buf(),
+        }),
+        _ => serde_json::from_str(&contents).map_err(|error| parse_error(error.to_string())),
+    }
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, BundleError> {
+    path.canonicalize().map_err(|error| io_error(path, error))
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> BundleError {
+    BundleError::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    /// Temporary directory removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("utoipa-bundle-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).expect("temp dir must be created");
+            Self(dir)
+        }
+
+        fn write(&self, path: &str, value: Value) {
+            let path = self.0.join(path);
+            std::fs::create_dir_all(path.parent().expect("path must have parent"))
+                .expect("dir must be created");
+            std::fs::write(path, value.to_string()).expect("file must be written");
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn from_file_bundles_external_references() {
+        let dir = TempDir::new("load");
+        dir.write(
+            "api/openapi.json",
+            json!({
+                "openapi": "3.1.0",
+                "info": {"title": "Pets", "version": "1.0.0"},
+                "paths": {
+                    "/pets": {
+                        "get": {
+                            "responses": {
+                                "200": {"$ref": "../shared/common.json#/components/responses/Pets"},
+                                "default": {"$ref": "#/components/responses/Error"}
+                            }
+                        }
+                    }
+                },
+                "components": {
+                    "responses": {
+                        "Error": {
+                            "description": "Error",
+                            "content": {"application/json": {"schema": {"$ref": "../shared/common.json#/components/schemas/Error"}}}
+                        }
+                    },
+                    "schemas": {"Pet": {"type": "string"}}
+                }
+            }),
+        );
+        dir.write(
+            "shared/common.json",
+            json!({
+                "components": {
+                    "responses": {
+                        "Pets": {
+                            "description": "Pets",
+                            "content": {"application/json": {"schema": {"type": "array", "items": {"$ref": "./schemas/Pet.json"}}}}
+                        }
+                    },
+                    "schemas": {
+                        "Error": {"type": "object", "properties": {
+                            "cause": {"$ref": "#/components/schemas/Error"},
+                            "code": {"$ref": "#/components/schemas/Code/properties/value"},
+                            "pet": {"$ref": "./schemas/Pet.json#/properties/name"}
+                        }},
+                        "Code": {"type": "object", "properties": {"value": {"type": "integer"}}}
+                    }
+                }
+            }),
+        );
+        dir.write(
+            "shared/schemas/Pet.json",
+            json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+        );
+
+        let api = OpenApi::from_file(dir.0.join("api/openapi.json")).expect("api must load");
+
+        let api = serde_json::to_value(api).expect("api must serialize");
+        assert_eq!(
+            api["paths"]["/pets"]["get"]["responses"],
+            json!({
+                "200": {"$ref": "#/components/responses/Pets"},
+                "default": {"$ref": "#/components/responses/Error"},
+            })
+        );
+        assert_eq!(
+            api["components"]["responses"]["Pets"]["content"]["application/json"]["schema"]
+                ["items"],
+            json!({"$ref": "#/components/schemas/Pet2"})
+        );
+        assert_eq!(
+            api["components"]["schemas"],
+            json!({
+                "Code": {"type": "object", "properties": {"value": {"type": "integer"}}},
+                "Error": {"type": "object", "properties": {
+                    "cause": {"$ref": "#/components/schemas/Error"},
+                    "code": {"$ref": "#/components/schemas/Code/properties/value"},
+                    "pet": {"$ref": "#/components/schemas/Pet2/properties/name"},
+                }},
+                "Pet": {"type": "string"},
+                "Pet2": {"type": "object", "properties": {"name": {"type": "string"}}},
+            })
+        );
+    }
+
+    #[test]
+    fn from_file_reports_unresolved_references() {
+        let dir = TempDir::new("unresolved");
+        dir.write(
+            "openapi.json",
+            json!({
+                "openapi": "3.1.0",
+                "info": {"title": "Pets", "version": "1.0.0"},
+                "paths": {},
+                "components": {"schemas": {"Pet": {"$ref": "./common.json#/components/schemas/Pet"}}}
+            }),
+        );
+        dir.write("common.json", json!({}));
+
+        assert!(matches!(
+            OpenApi::from_file(dir.0.join("openapi.json")),
+            Err(BundleError::UnresolvedReference { reference, .. }) if reference == "./common.json#/components/schemas/Pet"
+        ));
+        assert!(matches!(
+            OpenApi::from_file(dir.0.join("missing.json")),
+            Err(BundleError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn write_split_round_trips_with_from_file() {
+        let dir = TempDir::new("split");
+        let api = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new().response("200", Ref::from_response_name("Pets")),
+                ),
+            ))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new().property("owner", Ref::from_schema_name("Owner")),
+                    )
+                    .schema("Owner", ObjectBuilder::new())
+                    .response(
+                        "Pets",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            ContentBuilder::new()
+                                .schema(Some(Ref::from_schema_name("Pet")))
+                                .build(),
+                        ),
+                    )
+                    .security_scheme(
+                        "api_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("api_key"))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let root = api
+            .write_split(&dir.0, FileFormat::Json)
+            .expect("api must be written");
+
+        let read = |path: &str| -> Value {
+            serde_json::from_str(
+                &std::fs::read_to_string(dir.0.join(path)).expect("file must exist"),
+            )
+            .expect("file must be JSON")
+        };
+        assert_eq!(
+            read("openapi.json")["paths"]["/pets"]["get"]["responses"]["200"],
+            json!({"$ref": "./components/responses/Pets.json"})
+        );
+        assert_eq!(
+            read("openapi.json")["components"]["schemas"],
+            json!({
+                "Owner": {"$ref": "./components/schemas/Owner.json"},
+                "Pet": {"$ref": "./components/schemas/Pet.json"},
+            })
+        );
+        assert_eq!(
+            read("openapi.json")["components"]["securitySchemes"],
+            json!({"api_key": {"type": "apiKey", "name": "api_key", "in": "header"}})
+        );
+        assert_eq!(
+            read("components/schemas/Pet.json")["properties"]["owner"],
+            json!({"$ref": "./Owner.json"})
+        );
+        assert_eq!(
+            read("components/responses/Pets.json")["content"]["application/json"]["schema"],
+            json!({"$ref": "../schemas/Pet.json"})
+        );
+
+        let loaded = OpenApi::from_file(root).expect("api must load");
+        assert_eq!(
+            serde_json::to_value(loaded).expect("api must serialize"),
+            serde_json::to_value(api).expect("api must serialize")
+        );
+    }
+
+    #[test]
+    fn write_split_sanitizes_file_names() {
+        let dir = TempDir::new("split-names");
+        let api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "../Pet",
+                        ObjectBuilder::new()
+                            .property("a b", Ref::from_schema_name("a.b"))
+                            .property("b", Ref::from_schema_name("A_b")),
+                    )
+                    .schema("a.b", ObjectBuilder::new())
+                    .schema("A_b", ObjectBuilder::new())
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new().property(
+                            "pet",
+                            Ref::new("#/components/schemas/..~1Pet/properties/a b"),
+                        ),
+                    )
+                    .path_item(
+                        "Pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .response("200", ResponseBuilder::new().description("Pets")),
+                        ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let root = api
+            .write_split(&dir.0, FileFormat::Json)
+            .expect("api must be written");
+
+        let read = |path: &str| -> Value {
+            serde_json::from_str(
+                &std::fs::read_to_string(dir.0.join(path)).expect("file must exist"),
+            )
+            .expect("file must be JSON")
+        };
+        assert!(!dir.0.join("Pet.json").exists());
+        assert_eq!(
+            read("openapi.json")["components"],
+            json!({
+                "schemas": {
+                    "../Pet": {"$ref": "./components/schemas/___Pet.json"},
+                    "A_b": {"$ref": "./components/schemas/A_b.json"},
+                    "Owner": {"$ref": "./components/schemas/Owner.json"},
+                    "a.b": {"$ref": "./components/schemas/a_b2.json"},
+                },
+                "pathItems": {"Pets": {"$ref": "./components/pathItems/Pets.json"}},
+            })
+        );
+        assert_eq!(
+            read("components/schemas/___Pet.json")["properties"],
+            json!({"a b": {"$ref": "./a_b2.json"}, "b": {"$ref": "./A_b.json"}})
+        );
+        assert_eq!(
+            read("components/schemas/Owner.json")["properties"]["pet"],
+            json!({"$ref": "./___Pet.json#/properties/a%20b"})
+        );
+        assert!(dir.0.join("components/pathItems/Pets.json").exists());
+
+        let loaded = OpenApi::from_file(root).expect("api must load");
+        let loaded = serde_json::to_value(loaded).expect("api must serialize");
+        let api = serde_json::to_value(api).expect("api must serialize");
+        assert_eq!(loaded["components"], api["components"]);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn write_split_yaml_round_trips_with_from_file() {
+        let dir = TempDir::new("split-yaml");
+        let api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new().property("owner", Ref::from_schema_name("Owner")),
+                    )
+                    .schema("Owner", ObjectBuilder::new())
+                    .build(),
+            ))
+            .build();
+
+        let root = api
+            .write_split(&dir.0, FileFormat::Yaml)
+            .expect("api must be written");
+
+        assert!(dir.0.join("components/schemas/Owner.yaml").exists());
+        let loaded = OpenApi::from_file(root).expect("api must load");
+        assert_eq!(
+            serde_json::to_value(loaded).expect("api must serialize"),
+            serde_json::to_value(api).expect("api must serialize")
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! Implements [OpenAPI Callback Object][callback] types.
+//!
+//! [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+use serde::{Deserialize, Serialize};
+
+use crate::Path;
+
+use super::{
+    builder,
+    extensions::Extensions,
+    path::{PathItem, PathsMap},
+    set_value, Ref, RefOr,
+};
+
+builder! {
+    CallbackBuilder;
+
+    /// Implements [OpenAPI Callback Object][callback].
+    ///
+    /// Callback is a map of possible out-of band requests related to the parent [`Operation`][operation].
+    /// Each key is a [runtime expression][expression] e.g. `{$request.body#/callbackUrl}` which is
+    /// evaluated at runtime to resolve the URL used for the callback request and the value is
+    /// [`PathItem`] describing the requests that can be made to the URL.
+    ///
+    /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+    /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
+    /// [operation]: ../path/struct.Operation.html
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Callback {
+        /// Map of runtime expressions with [`PathItem`]s describing the callback requests.
+        #[serde(flatten)]
+        pub paths: PathsMap<String, PathItem>,
+
+        /// Optional extensions "x-something".
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl Callback {
+    /// Construct a new empty [`Callback`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl CallbackBuilder {
+    /// Append [`PathItem`] with runtime _`expression`_ to the [`Callback`]. If expression already
+    /// exists the [`Operation`][operation]s of the [`PathItem`] are merged with the existing path item.
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    pub fn path<E: Into<String>>(mut self, expression: E, item: PathItem) -> Self {
+        let expression = expression.into();
+        if let Some(existing_item) = self.paths.get_mut(&expression) {
+            existing_item.merge_operations(item);
+        } else {
+            self.paths.insert(expression, item);
+        }
+
+        self
+    }
+
+    /// Append a [`trait@Path`] to the [`Callback`]. The [`trait@Path::path`] is used as the runtime
+    /// expression of the callback request.
+    ///
+    /// # Examples
+    ///
+    /// _**Append `MyCallback` request to the callback.**_
+    /// ```rust
+    /// # struct MyCallback;
+    /// # impl utoipa::Path for MyCallback {
+    /// #   fn methods() -> Vec<utoipa::openapi::path::HttpMethod> { vec![] }
+    /// #   fn path() -> String { String::from("{$request.body#/callbackUrl}") }
+    /// #   fn operation() -> utoipa::openapi::path::Operation {
+    /// #        utoipa::openapi::path::Operation::new()
+    /// #   }
+    /// # }
+    /// let callback = utoipa::openapi::callback::CallbackBuilder::new();
+    /// let _ = callback.path_from::<MyCallback>();
+    /// ```
+    pub fn path_from<P: Path>(self) -> Self {
+        self.path(P::path(), PathItem::from_path::<P>())
+    }
+
+    /// Add openapi extensions (x-something) of the [`Callback`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+impl From<CallbackBuilder> for RefOr<Callback> {
+    fn from(value: CallbackBuilder) -> Self {
+        Self::T(value.build())
+    }
+}
+
+impl From<Ref> for RefOr<Callback> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use crate::openapi::{
+        path::{OperationBuilder, ParameterBuilder, ParameterIn},
+        HttpMethod, Ref, RefOr, Response,
+    };
+
+    use super::*;
+
+    #[test]
+    fn callback_with_multiple_expressions() {
+        let callback = CallbackBuilder::new()
+            .path(
+                "{$request.body#/callbackUrl}",
+                PathItem::new(
+                    HttpMethod::Post,
+                    OperationBuilder::new().response("200", Response::new("Event received")),
+                ),
+            )
+            .path(
+                "{$request.body#/callbackUrl}",
+                PathItem::new(
+                    HttpMethod::Put,
+                    OperationBuilder::new().response("204", Response::new("Event updated")),
+                ),
+            )
+            .path(
+                "{$request.query.statusUrl}/{id}",
+                PathItem::new(
+                    HttpMethod::Get,
+                    OperationBuilder::new()
+                        .parameter(
+                            ParameterBuilder::new()
+                                .name("id")
+                                .parameter_in(ParameterIn::Path),
+                        )
+                        .response("200", Response::new("Status")),
+                ),
+            )
+            .build();
+
+        assert_json_eq!(
+            callback,
+            json!({
+                "{$request.body#/callbackUrl}": {
+                    "post": {
+                        "responses": {
+                            "200": {
+                                "description": "Event received"
+                            }
+                        }
+                    },
+                    "put": {
+                        "responses": {
+                            "204": {
+                                "description": "Event updated"
+                            }
+                        }
+                    }
+                },
+                "{$request.query.statusUrl}/{id}": {
+                    "get": {
+                        "parameters": [
+                            {
+                                "name": "id",
+                                "in": "path",
+                                "required": false
+                            }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "Status"
+                            }
+                        }
+                    }
+                }
+            })
+        )
+    }
+
+    #[test]
+    fn operation_with_callbacks() {
+        let operation = OperationBuilder::new()
+            .callback(
+                "onEvent",
+                CallbackBuilder::new().path(
+                    "{$request.body#/callbackUrl}",
+                    PathItem::new(HttpMethod::Post, OperationBuilder::new()),
+                ),
+            )
+            .callback(
+                "onStatus",
+                RefOr::Ref(Ref::new("#/components/callbacks/onStatus")),
+            )
+            .build();
+
+        assert_json_eq!(
+            operation,
+            json!({
+                "responses": {},
+                "callbacks": {
+                    "onEvent": {
+                        "{$request.body#/callbackUrl}": {
+                            "post": {
+                                "responses": {}
+                            }
+                        }
+                    },
+                    "onStatus": {
+                        "$ref": "#/components/callbacks/onStatus"
+                    }
+                }
+            })
+        );
+
+        let value = serde_json::to_value(&operation).expect("operation must serialize");
+        let deserialized = serde_json::from_value::<crate::openapi::path::Operation>(value.clone())
+            .expect("operation must deserialize");
+        assert_json_eq!(deserialized, value);
+    }
+}
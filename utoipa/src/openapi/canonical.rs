@@ -0,0 +1,187 @@
+//! Implements deterministic canonical serialization and content hashing of [`OpenApi`] documents.
+use std::fmt::Write;
+
+use serde_json::{Number, Value};
+#[cfg(feature = "content_hash")]
+use sha2::{Digest, Sha256};
+
+use super::OpenApi;
+
+impl OpenApi {
+    /// Converts this [`OpenApi`] to canonical JSON String.
+    ///
+    /// Canonical JSON follows [JSON Canonicalization Scheme][jcs]: object keys are sorted,
+    /// insignificant whitespace is omitted and numbers are normalized e.g. _`1.0`_ is serialized
+    /// as _`1`_. Thus two semantically identical documents are serialized byte identically
+    /// regardless of enabled features e.g. _`preserve_order`_ or insertion order of the maps.
+    ///
+    /// This is useful for snapshot tests and for comparing documents. See also
+    /// _`OpenApi::content_hash`_ available with _`content_hash`_ feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{InfoBuilder, OpenApiBuilder};
+    /// let api = OpenApiBuilder::new()
+    ///     .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     api.to_canonical_json().unwrap(),
+    ///     r#"{"info":{"title":"Pets","version":"1.0.0"},"openapi":"3.1.0","paths":{}}"#
+    /// );
+    /// ```
+    ///
+    /// [jcs]: https://www.rfc-editor.org/rfc/rfc8785
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_value(self).map(|value| canonical_json(&value))
+    }
+
+    /// Compute stable content hash of this [`OpenApi`].
+    ///
+    /// Hash is lowercase hex encoded SHA-256 digest of [`OpenApi::to_canonical_json`] thus it
+    /// only changes when the content of the document changes. It can be used e.g. as an _`ETag`_
+    /// when serving the document over HTTP.
+    ///
+    /// This requires the _`content_hash`_ feature.
+    ///
+    /// # Examples
+    ///
+    /// _**Use content hash as ETag.**_
+    /// ```rust
+    /// # use utoipa::openapi::OpenApiBuilder;
+    /// let api = OpenApiBuilder::new().build();
+    /// let etag = format!("\"{}\"", api.content_hash().unwrap());
+    ///
+    /// assert_eq!(etag.len(), 66);
+    /// assert_eq!(api.content_hash().unwrap(), api.clone().content_hash().unwrap());
+    /// ```
+    #[cfg(feature = "content_hash")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "content_hash")))]
+    pub fn content_hash(&self) -> Result<String, serde_json::Error> {
+        let json = self.to_canonical_json()?;
+
+        Ok(Sha256::digest(json.as_bytes()).iter().fold(
+            String::with_capacity(64),
+            |mut hash, byte| {
+                let _ = write!(hash, "{byte:02x}");
+                hash
+            },
+        ))
+    }
+}
+
+/// Serialize the JSON `value` to canonical JSON String.
+pub(super) fn canonical_json(value: &Value) -> String {
+    let mut json = String::new();
+    write_canonical(&mut json, value);
+
+    json
+}
+
+fn write_canonical(json: &mut String, value: &Value) {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => json.push_str(&value.to_string()),
+        Value::Number(number) => write_number(json, number),
+        Value::Array(values) => {
+            json.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                write_canonical(json, value);
+            }
+            json.push(']');
+        }
+        Value::Object(object) => {
+            // keys are sorted by their UTF-16 code units as defined by the JCS
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by(|(left, _), (right, _)| left.encode_utf16().cmp(right.encode_utf16()));
+
+            json.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    json.push(',');
+                }
+                json.push_str(&Value::String(key.clone()).to_string());
+                json.push(':');
+                write_canonical(json, value);
+            }
+            json.push('}');
+        }
+    }
+}
+
+fn write_number(json: &mut String, number: &Number) {
+    match number.as_f64() {
+        Some(float) if number.is_f64() && float.fract() == 0.0 && float.abs() < 1e21 => {
+            // integral floats are serialized without fraction, also `-0.0` becomes `0`
+            let _ = write!(json, "{}", float as i128);
+        }
+        _ => {
+            let _ = write!(json, "{number}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::{ComponentsBuilder, InfoBuilder, ObjectBuilder, OpenApiBuilder, Type};
+
+    use super::*;
+
+    #[test]
+    fn canonical_json_sorts_keys_and_normalizes_numbers() {
+        assert_eq!(
+            canonical_json(&json!({
+                "b": [1.0, -0.0, 1.5, 10000000000000000000000.0, "\u{1F600}\n"],
+                "a": {"\u{E000}": null, "\u{1F600}": true, "A": 1},
+            })),
+            "{\"a\":{\"A\":1,\"\u{1F600}\":true,\"\u{E000}\":null},\"b\":[1,0,1.5,1e+22,\"\u{1F600}\\n\"]}"
+        );
+    }
+
+    #[test]
+    fn content_hash_is_independent_of_insertion_order() {
+        let api = |properties: &[(&str, Type)]| {
+            let schema =
+                properties
+                    .iter()
+                    .fold(ObjectBuilder::new(), |schema, (name, schema_type)| {
+                        schema
+                            .property(*name, ObjectBuilder::new().schema_type(schema_type.clone()))
+                    });
+            OpenApiBuilder::new()
+                .components(Some(ComponentsBuilder::new().schema("Pet", schema).build()))
+                .build()
+        };
+
+        let first = api(&[("name", Type::String), ("age", Type::Integer)]);
+        let second = api(&[("age", Type::Integer), ("name", Type::String)]);
+        let third = api(&[("age", Type::Number), ("name", Type::String)]);
+
+        assert_eq!(
+            first.to_canonical_json().unwrap(),
+            second.to_canonical_json().unwrap()
+        );
+        assert_eq!(
+            first.content_hash().unwrap(),
+            second.content_hash().unwrap()
+        );
+        assert_ne!(first.content_hash().unwrap(), third.content_hash().unwrap());
+    }
+
+    #[test]
+    fn content_hash_is_sha256_of_canonical_json() {
+        let api = OpenApiBuilder::new()
+            .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+            .build();
+
+        assert_eq!(
+            api.content_hash().unwrap(),
+            "9510116adb467ae02bf23327857b52a77a80c836eeb60bebc457d5a378bd1b9a"
+        );
+    }
+}
@@ -0,0 +1,541 @@
+//! Implements detection and deduplication of structurally equal [`Schema`]s of [`Components`]
+//! and detection of different [`Schema`]s colliding with the same name.
+//!
+//! [`OpenApi::find_duplicate_schemas`] reports structurally equal component schemas and
+//! [`OpenApi::dedupe_schemas`] collapses them into one canonical schema. [`find_schema_collisions`]
+//! reports different schemas registered with the same name which would otherwise silently
+//! replace each other in [`Components::schemas`].
+//!
+//! [`Components`]: super::Components
+//! [`Components::schemas`]: super::Components::schemas
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::canonical::canonical_json;
+use super::schema::{AllOf, AnyOf, OneOf, Schema};
+use super::visit::{self, Location, VisitMut};
+use super::{OpenApi, Ref, RefOr};
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Group of structurally equal component schemas found with
+/// [`OpenApi::find_duplicate_schemas`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DuplicateSchemas {
+    /// Canonical name of the schema the duplicates are collapsed to in
+    /// [`OpenApi::dedupe_schemas`]. This is the first name of the group in alphabetical order.
+    pub name: String,
+    /// Names of the schemas structurally equal to the schema with canonical name.
+    pub duplicates: Vec<String>,
+}
+
+/// Different [`Schema`]s registered with the same name found with [`find_schema_collisions`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+pub struct SchemaCollision {
+    /// Name shared by the colliding schemas.
+    pub name: String,
+    /// All different schemas registered with the name in registration order.
+    pub schemas: Vec<RefOr<Schema>>,
+}
+
+impl Display for SchemaCollision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "schema name `{}` is used by {} different schemas",
+            self.name,
+            self.schemas.len()
+        )
+    }
+}
+
+// Schemas are formatted as JSON since [`Schema`] implements `Debug` only with `debug` feature.
+impl std::fmt::Debug for SchemaCollision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchemaCollision")
+            .field("name", &self.name)
+            .field(
+                "schemas",
+                &self
+                    .schemas
+                    .iter()
+                    .map(|schema| serde_json::to_value(schema).unwrap_or(Value::Null))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl std::error::Error for SchemaCollision {}
+
+/// Find different [`Schema`]s registered with the same name.
+///
+/// Schemas are registered in [`Components::schemas`][schemas] by their name thus different
+/// types with same [`ToSchema::name`][name] replace each other silently. This function reports
+/// every name having more than one different schema as [`SchemaCollision`]. Registering the
+/// same schema multiple times with the same name is not considered a collision.
+///
+/// This is used by [`OpenApi::check_schema_collisions`][check] derived with
+/// `#[derive(OpenApi)]` but can be used with any collected schemas e.g. from
+/// [`ToSchema::schemas`][schemas_fn].
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::openapi::{ObjectBuilder, RefOr, Type};
+/// # use utoipa::openapi::dedupe::find_schema_collisions;
+/// let schemas: [(&str, RefOr<_>); 3] = [
+///     ("Id", ObjectBuilder::new().schema_type(Type::Integer).into()),
+///     ("Id", ObjectBuilder::new().schema_type(Type::Integer).into()),
+///     ("Id", ObjectBuilder::new().schema_type(Type::String).into()),
+/// ];
+///
+/// let collisions = find_schema_collisions(schemas).unwrap_err();
+/// assert_eq!(collisions[0].name, "Id");
+/// assert_eq!(collisions[0].schemas.len(), 2);
+/// ```
+///
+/// [schemas]: super::Components::schemas
+/// [name]: ../../trait.ToSchema.html#method.name
+/// [schemas_fn]: ../../trait.ToSchema.html#method.schemas
+/// [check]: ../../trait.OpenApi.html#method.check_schema_collisions
+pub fn find_schema_collisions<I: IntoIterator<Item = (S, RefOr<Schema>)>, S: Into<String>>(
+    schemas: I,
+) -> Result<(), Vec<SchemaCollision>> {
+    let mut registered = BTreeMap::<String, Vec<RefOr<Schema>>>::new();
+    for (name, schema) in schemas {
+        let schemas = registered.entry(name.into()).or_default();
+        if !schemas.contains(&schema) {
+            schemas.push(schema);
+        }
+    }
+
+    let collisions = registered
+        .into_iter()
+        .filter(|(_, schemas)| schemas.len() > 1)
+        .map(|(name, schemas)| SchemaCollision { name, schemas })
+        .collect::<Vec<_>>();
+
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(collisions)
+    }
+}
+
+impl OpenApi {
+    /// Find structurally equal schemas of [`Components::schemas`][schemas].
+    ///
+    /// Schemas are structurally equal when they are equal ignoring _`title`_ and _`description`_
+    /// of the schemas and nested schemas. References to schemas which are structurally equal
+    /// are considered equal as well, thus schemas only referencing duplicates are reported as
+    /// duplicates too. Component schemas which are references themselves are not compared.
+    ///
+    /// Each group of structurally equal schemas is reported as [`DuplicateSchemas`] ordered by
+    /// the canonical name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Type};
+    /// let error = || {
+    ///     ObjectBuilder::new()
+    ///         .property("message", ObjectBuilder::new().schema_type(Type::String))
+    ///         .required("message")
+    /// };
+    /// let api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("ErrorBody", error().description(Some("Error of users API")))
+    ///             .schema("ApiError", error())
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let duplicates = api.find_duplicate_schemas();
+    /// assert_eq!(duplicates[0].name, "ApiError");
+    /// assert_eq!(duplicates[0].duplicates, ["ErrorBody"]);
+    /// ```
+    ///
+    /// [schemas]: super::Components::schemas
+    pub fn find_duplicate_schemas(&self) -> Vec<DuplicateSchemas> {
+        let Some(components) = &self.components else {
+            return Vec::new();
+        };
+
+        let mut shapes = components
+            .schemas
+            .iter()
+            .filter_map(|(name, schema)| match schema {
+                RefOr::T(schema) => Some((name.as_str(), shape(schema))),
+                RefOr::Ref(_) => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+        let mut renames = BTreeMap::<String, String>::new();
+
+        loop {
+            let mut groups = BTreeMap::<String, Vec<&str>>::new();
+            for (name, shape) in &shapes {
+                if !renames.contains_key(*name) {
+                    groups.entry(canonical_json(shape)).or_default().push(name);
+                }
+            }
+
+            let mut found = false;
+            for names in groups.values() {
+                if let [name, duplicates @ ..] = names.as_slice() {
+                    for duplicate in duplicates {
+                        renames.insert(duplicate.to_string(), name.to_string());
+                        found = true;
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+
+            let renames = resolve_renames(&renames);
+            shapes
+                .values_mut()
+                .for_each(|shape| rename_value_refs(shape, &renames));
+        }
+
+        resolve_renames(&renames)
+            .into_iter()
+            .fold(
+                BTreeMap::<String, Vec<String>>::new(),
+                |mut groups, (duplicate, name)| {
+                    groups.entry(name).or_default().push(duplicate);
+                    groups
+                },
+            )
+            .into_iter()
+            .map(|(name, duplicates)| DuplicateSchemas { name, duplicates })
+            .collect()
+    }
+
+    /// Collapse structurally equal schemas of [`Components::schemas`][schemas] found with
+    /// [`OpenApi::find_duplicate_schemas`] into one canonical schema.
+    ///
+    /// Duplicate schemas are removed from the components and all references to them are
+    /// rewritten to reference the canonical schema, including the _`mapping`_ of
+    /// [`Discriminator`][discriminator]s. Title and description of the canonical schema are
+    /// kept. Returns the collapsed [`DuplicateSchemas`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref, Type};
+    /// let error = || ObjectBuilder::new().property("message", ObjectBuilder::new().schema_type(Type::String));
+    /// let mut api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("ErrorBody", error())
+    ///             .schema("ApiError", error())
+    ///             .schema("Failure", ObjectBuilder::new().property("error", Ref::from_schema_name("ErrorBody")))
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// api.dedupe_schemas();
+    ///
+    /// let schemas = serde_json::to_value(&api.components.unwrap().schemas).unwrap();
+    /// assert!(schemas.get("ErrorBody").is_none());
+    /// assert_eq!(schemas["Failure"]["properties"]["error"]["$ref"], "#/components/schemas/ApiError");
+    /// ```
+    ///
+    /// [schemas]: super::Components::schemas
+    /// [discriminator]: super::schema::Discriminator
+    pub fn dedupe_schemas(&mut self) -> Vec<DuplicateSchemas> {
+        let duplicates = self.find_duplicate_schemas();
+        let Some(components) = self.components.as_mut() else {
+            return duplicates;
+        };
+
+        let mut renames = BTreeMap::new();
+        for group in &duplicates {
+            for duplicate in &group.duplicates {
+                components.schemas.remove(duplicate);
+                renames.insert(duplicate.clone(), group.name.clone());
+            }
+        }
+        if !renames.is_empty() {
+            RenameReferences(&renames).visit_openapi_mut(self, &mut Location::new());
+        }
+
+        duplicates
+    }
+}
+
+/// Create comparable JSON shape of the [`Schema`] without titles and descriptions.
+fn shape(schema: &Schema) -> Value {
+    let mut schema = schema.clone();
+    StripAnnotations.visit_schema_mut(&mut schema, &mut Location::new());
+
+    serde_json::to_value(schema).unwrap_or_default()
+}
+
+/// Resolve chained renames so that every name maps directly to its final canonical name.
+fn resolve_renames(renames: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    renames
+        .keys()
+        .map(|duplicate| {
+            let mut name = duplicate;
+            while let Some(next) = renames.get(name) {
+                name = next;
+            }
+            (duplicate.clone(), name.clone())
+        })
+        .collect()
+}
+
+fn rename(reference: &str, renames: &BTreeMap<String, String>) -> Option<String> {
+    reference
+        .strip_prefix(SCHEMA_REF_PREFIX)
+        .and_then(|name| renames.get(name))
+        .map(|name| format!("{SCHEMA_REF_PREFIX}{name}"))
+}
+
+fn rename_value_refs(value: &mut Value, renames: &BTreeMap<String, String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        if let Some(renamed) = rename(reference, renames) {
+                            *reference = renamed;
+                        }
+                    }
+                    Value::Object(discriminator) if key == "discriminator" => {
+                        if let Some(Value::Object(mapping)) = discriminator.get_mut("mapping") {
+                            for reference in mapping.values_mut() {
+                                if let Some(renamed) = reference
+                                    .as_str()
+                                    .and_then(|reference| rename(reference, renames))
+                                {
+                                    *reference = Value::String(renamed);
+                                }
+                            }
+                        }
+                    }
+                    value => rename_value_refs(value, renames),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| rename_value_refs(value, renames)),
+        _ => (),
+    }
+}
+
+/// Removes titles and descriptions of all nested [`Schema`]s and [`Ref`]s.
+struct StripAnnotations;
+
+impl VisitMut for StripAnnotations {
+    fn visit_schema_mut(&mut self, schema: &mut Schema, location: &mut Location) {
+        match schema {
+            Schema::Object(object) => {
+                object.title = None;
+                object.description = None;
+            }
+            Schema::Array(array) => {
+                array.title = None;
+                array.description = None;
+            }
+            Schema::OneOf(one_of) => {
+                one_of.title = None;
+                one_of.description = None;
+            }
+            Schema::AllOf(all_of) => {
+                all_of.title = None;
+                all_of.description = None;
+            }
+            Schema::AnyOf(any_of) => any_of.description = None,
+            _ => (),
+        }
+        visit::walk_schema_mut(self, schema, location);
+    }
+
+    fn visit_ref_mut(&mut self, reference: &mut Ref, _location: &mut Location) {
+        reference.description.clear();
+        reference.summary.clear();
+    }
+}
+
+/// Rewrites references to renamed schemas.
+struct RenameReferences<'r>(&'r BTreeMap<String, String>);
+
+impl VisitMut for RenameReferences<'_> {
+    fn visit_schema_mut(&mut self, schema: &mut Schema, location: &mut Location) {
+        if let Schema::OneOf(OneOf { discriminator, .. })
+        | Schema::AllOf(AllOf { discriminator, .. })
+        | Schema::AnyOf(AnyOf { discriminator, .. }) = schema
+        {
+            for reference in discriminator
+                .iter_mut()
+                .flat_map(|d| d.mapping.values_mut())
+            {
+                if let Some(renamed) = rename(reference, self.0) {
+                    *reference = renamed;
+                }
+            }
+        }
+        visit::walk_schema_mut(self, schema, location);
+    }
+
+    fn visit_ref_mut(&mut self, reference: &mut Ref, _location: &mut Location) {
+        if let Some(renamed) = rename(&reference.ref_location, self.0) {
+            reference.ref_location = renamed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::OperationBuilder;
+    use crate::openapi::schema::{Discriminator, OneOfBuilder};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        let error = |description: &str| {
+            ObjectBuilder::new()
+                .description(Some(description))
+                .property(
+                    "message",
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .description(Some(description)),
+                )
+                .required("message")
+        };
+        let response = |error: &str| {
+            ObjectBuilder::new()
+                .property("error", Ref::from_schema_name(error))
+                .property("code", ObjectBuilder::new().schema_type(Type::Integer))
+        };
+
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "500",
+                            ResponseBuilder::new().content(
+                                "application/json",
+                                ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name("ErrorBody")))
+                                    .build(),
+                            ),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("ErrorBody", error("Error of pets"))
+                    .schema("ApiError", error("Error of users"))
+                    .schema("UsersResponse", response("ApiError"))
+                    .schema("PetsResponse", response("ErrorBody"))
+                    .schema("Other", ObjectBuilder::new().schema_type(Type::String))
+                    .schema(
+                        "Response",
+                        OneOfBuilder::new()
+                            .item(Ref::from_schema_name("UsersResponse"))
+                            .discriminator(Some(Discriminator::with_mapping(
+                                "kind",
+                                [("users", "#/components/schemas/UsersResponse")],
+                            ))),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn find_duplicate_schemas_through_references() {
+        let duplicates = api().find_duplicate_schemas();
+
+        assert_eq!(
+            serde_json::to_value(duplicates).unwrap(),
+            json!([
+                {"name": "ApiError", "duplicates": ["ErrorBody"]},
+                {"name": "PetsResponse", "duplicates": ["UsersResponse"]},
+            ])
+        );
+    }
+
+    #[test]
+    fn dedupe_schemas_rewrites_references() {
+        let mut api = api();
+
+        let duplicates = api.dedupe_schemas();
+        assert_eq!(duplicates.len(), 2);
+        assert!(api.find_duplicate_schemas().is_empty());
+
+        let api = serde_json::to_value(api).unwrap();
+        let schemas = &api["components"]["schemas"];
+        assert_eq!(
+            schemas.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["ApiError", "Other", "PetsResponse", "Response"]
+        );
+        assert_eq!(schemas["ApiError"]["description"], "Error of users");
+        assert_eq!(
+            schemas["PetsResponse"]["properties"]["error"],
+            json!({"$ref": "#/components/schemas/ApiError"})
+        );
+        assert_eq!(
+            api["paths"]["/pets"]["get"]["responses"]["500"]["content"]["application/json"]
+                ["schema"],
+            json!({"$ref": "#/components/schemas/ApiError"})
+        );
+        assert_eq!(
+            schemas["Response"],
+            json!({
+                "oneOf": [{"$ref": "#/components/schemas/PetsResponse"}],
+                "discriminator": {
+                    "propertyName": "kind",
+                    "mapping": {"users": "#/components/schemas/PetsResponse"}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn find_schema_collisions_reports_different_schemas() {
+        let integer = || RefOr::from(ObjectBuilder::new().schema_type(Type::Integer));
+        let string = || RefOr::from(ObjectBuilder::new().schema_type(Type::String));
+
+        assert!(find_schema_collisions([("Id", integer()), ("Id", integer())]).is_ok());
+
+        let collisions = find_schema_collisions([
+            ("Id", integer()),
+            ("Name", string()),
+            ("Id", string()),
+            ("Id", integer()),
+        ])
+        .unwrap_err();
+        assert_eq!(collisions.len(), 1);
+        assert_eq!(collisions[0].name, "Id");
+        assert!(collisions[0].schemas == [integer(), string()]);
+        assert_eq!(
+            collisions[0].to_string(),
+            "schema name `Id` is used by 2 different schemas"
+        );
+    }
+}
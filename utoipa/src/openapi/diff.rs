@@ -0,0 +1,1169 @@
+//! Implements semantic diff between two [`OpenApi`] documents.
+//!
+//! Diff is created with [`OpenApi::diff`] and it reports added, removed and changed operations,
+//! parameters, request bodies, responses and schema properties as list of [`Change`]s. Each
+//! change is classified either breaking or non-breaking from the API client's point of view.
+//! [`Diff`] can be serialized to JSON e.g. to gate breaking changes in CI.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::content::Content;
+use super::path::{Operation, Parameter, ParameterIn, PathItem};
+use super::request_body::RequestBody;
+use super::response::Response;
+use super::schema::{Array, ArrayItems, Object, Schema, SchemaType};
+use super::validation::escape_pointer_segment;
+use super::visit::{Location, Visit};
+use super::{Components, OpenApi, RefOr, Required};
+
+/// Result of [`OpenApi::diff`] listing all found [`Change`]s.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Diff {
+    /// Changes between the documents in order they were found.
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    /// Returns `true` if there are no changes between the documents.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if any of the [`Change`]s is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// Iterate over breaking [`Change`]s only.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.breaking)
+    }
+
+    /// Converts this [`Diff`] to JSON String. This method essentially calls [`serde_json::to_string`] method.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Converts this [`Diff`] to pretty JSON String. This method essentially calls [`serde_json::to_string_pretty`] method.
+    pub fn to_pretty_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Single change between two [`OpenApi`] documents.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Change {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the changed object. Added
+    /// objects are located in the new document and removed objects are located in the old
+    /// document.
+    pub location: String,
+
+    /// Whether the change breaks existing API clients.
+    pub breaking: bool,
+
+    /// Kind of the change.
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+/// Kind of the [`Change`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// New [`Operation`] was added. Non-breaking.
+    OperationAdded,
+    /// [`Operation`] was removed. Breaking.
+    OperationRemoved,
+    /// New [`Parameter`] was added. Breaking if the parameter is required.
+    ParameterAdded {
+        /// Name of the parameter.
+        name: String,
+        /// Location of the parameter.
+        #[serde(rename = "in")]
+        parameter_in: ParameterIn,
+        /// Whether the parameter is required.
+        required: bool,
+    },
+    /// [`Parameter`] was removed. Breaking.
+    ParameterRemoved {
+        /// Name of the parameter.
+        name: String,
+        /// Location of the parameter.
+        #[serde(rename = "in")]
+        parameter_in: ParameterIn,
+    },
+    /// Required status of [`Parameter`] changed. Breaking if the parameter became required.
+    ParameterRequiredChanged {
+        /// Name of the parameter.
+        name: String,
+        /// Location of the parameter.
+        #[serde(rename = "in")]
+        parameter_in: ParameterIn,
+        /// Whether the parameter is required in the new document.
+        required: bool,
+    },
+    /// [`RequestBody`] was added. Breaking if the request body is required.
+    RequestBodyAdded {
+        /// Whether the request body is required.
+        required: bool,
+    },
+    /// [`RequestBody`] was removed. Breaking.
+    RequestBodyRemoved,
+    /// Required status of [`RequestBody`] changed. Breaking if request body became required.
+    RequestBodyRequiredChanged {
+        /// Whether the request body is required in the new document.
+        required: bool,
+    },
+    /// New media type was added to request body or response content. Non-breaking.
+    MediaTypeAdded {
+        /// The added media type e.g. _`application/json`_.
+        media_type: String,
+    },
+    /// Media type was removed from request body or response content. Breaking.
+    MediaTypeRemoved {
+        /// The removed media type e.g. _`application/json`_.
+        media_type: String,
+    },
+    /// New [`Response`] status was added. Non-breaking.
+    ResponseAdded {
+        /// Status code of the response e.g. _`404`_.
+        status: String,
+    },
+    /// [`Response`] status was removed. Breaking.
+    ResponseRemoved {
+        /// Status code of the response e.g. _`404`_.
+        status: String,
+    },
+    /// New property was added to object schema. Breaking if property is required and the
+    /// schema is used in a request.
+    PropertyAdded {
+        /// Name of the property.
+        name: String,
+        /// Whether the property is required.
+        required: bool,
+    },
+    /// Property was removed from object schema. Breaking unless the schema is only used in a
+    /// request.
+    PropertyRemoved {
+        /// Name of the property.
+        name: String,
+    },
+    /// Required status of property changed. Breaking if property became required in a request
+    /// or optional in a response. Change of component schema property is always breaking.
+    PropertyRequiredChanged {
+        /// Name of the property.
+        name: String,
+        /// Whether the property is required in the new document.
+        required: bool,
+    },
+    /// Type of the schema changed. Breaking.
+    TypeChanged {
+        /// Type in the old document.
+        old: SchemaType,
+        /// Type in the new document.
+        new: SchemaType,
+    },
+    /// Schema changed in a way that cannot be compared in more detail e.g. from
+    /// [`Schema::Object`] to [`Schema::OneOf`]. Breaking.
+    SchemaChanged,
+    /// Schema reference was changed to point to another schema. Breaking.
+    SchemaReferenceChanged {
+        /// Reference location in the old document.
+        old: String,
+        /// Reference location in the new document.
+        new: String,
+    },
+    /// New schema was added to components. Non-breaking.
+    SchemaAdded {
+        /// Name of the schema.
+        name: String,
+    },
+    /// Schema was removed from components. Breaking.
+    SchemaRemoved {
+        /// Name of the schema.
+        name: String,
+    },
+}
+
+impl ChangeKind {
+    fn is_breaking(&self, direction: Direction) -> bool {
+        match self {
+            Self::OperationAdded
+            | Self::MediaTypeAdded { .. }
+            | Self::ResponseAdded { .. }
+            | Self::SchemaAdded { .. } => false,
+            Self::OperationRemoved
+            | Self::ParameterRemoved { .. }
+            | Self::RequestBodyRemoved
+            | Self::MediaTypeRemoved { .. }
+            | Self::ResponseRemoved { .. }
+            | Self::TypeChanged { .. }
+            | Self::SchemaChanged
+            | Self::SchemaReferenceChanged { .. }
+            | Self::SchemaRemoved { .. } => true,
+            Self::ParameterAdded { required, .. }
+            | Self::ParameterRequiredChanged { required, .. }
+            | Self::RequestBodyAdded { required }
+            | Self::RequestBodyRequiredChanged { required } => *required,
+            Self::PropertyAdded { required, .. } => direction != Direction::Response && *required,
+            Self::PropertyRemoved { .. } => direction != Direction::Request,
+            Self::PropertyRequiredChanged { required, .. } => match direction {
+                Direction::Request => *required,
+                Direction::Response => !*required,
+                Direction::Component => true,
+            },
+        }
+    }
+}
+
+/// Defines in which direction the compared schema is sent. Component schemas can be used in
+/// both directions thus changes are classified by the strictest rules.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Request,
+    Response,
+    Component,
+}
+
+impl OpenApi {
+    /// Compare this [`OpenApi`] document to a `new` version of it and return [`Diff`] of the
+    /// changes.
+    ///
+    /// Operations are matched by path and [`HttpMethod`][method], parameters by name and
+    /// location and responses by status code. Schemas referenced from both documents with the
+    /// same name are compared once under _`components.schemas`_.
+    ///
+    /// # Examples
+    ///
+    /// _**Removing an operation is a breaking change.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// # use utoipa::openapi::diff::ChangeKind;
+    /// let old = OpenApiBuilder::new()
+    ///     .paths(PathsBuilder::new().path("/pets", PathItem::new(HttpMethod::Get, OperationBuilder::new())))
+    ///     .build();
+    /// let new = OpenApiBuilder::new().build();
+    ///
+    /// let diff = old.diff(&new);
+    /// assert!(diff.is_breaking());
+    /// assert_eq!(diff.changes[0].location, "/paths/~1pets/get");
+    /// assert_eq!(diff.changes[0].kind, ChangeKind::OperationRemoved);
+    /// ```
+    ///
+    /// [method]: super::path::HttpMethod
+    pub fn diff(&self, new: &OpenApi) -> Diff {
+        let mut differ = Differ {
+            old: self,
+            new,
+            location: Vec::new(),
+            changes: Vec::new(),
+        };
+
+        differ.with("paths", |this| this.diff_paths());
+        differ.with("components", |this| {
+            this.with("schemas", |this| this.diff_component_schemas())
+        });
+
+        Diff {
+            changes: differ.changes,
+        }
+    }
+}
+
+struct Differ<'a> {
+    old: &'a OpenApi,
+    new: &'a OpenApi,
+    location: Vec<String>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn diff_paths(&mut self) {
+        let old_operations = PathOperations::collect(self.old);
+        let new_operations = PathOperations::collect(self.new);
+
+        for (segments, (old_path_item, old_operation)) in &old_operations.0 {
+            let (http_method, path) = segments.split_last().expect("operation must have location");
+            self.with_all(path, |this| match new_operations.get(segments) {
+                Some((new_path_item, new_operation)) => this.diff_operation(
+                    http_method,
+                    (old_path_item, old_operation),
+                    (new_path_item, new_operation),
+                ),
+                None => this.with(http_method, |this| {
+                    this.change(ChangeKind::OperationRemoved, Direction::Component)
+                }),
+            });
+        }
+        for (segments, _) in &new_operations.0 {
+            if old_operations.get(segments).is_none() {
+                self.with_all(segments, |this| {
+                    this.change(ChangeKind::OperationAdded, Direction::Component)
+                });
+            }
+        }
+    }
+
+    /// Diff operations. Location must point to the path item of the new operation when called.
+    fn diff_operation(
+        &mut self,
+        http_method: &str,
+        (old_path_item, old_operation): (&'a PathItem, &'a Operation),
+        (new_path_item, new_operation): (&'a PathItem, &'a Operation),
+    ) {
+        let old_parameters =
+            effective_parameters(self.old, http_method, old_path_item, old_operation);
+        let new_parameters =
+            effective_parameters(self.new, http_method, new_path_item, new_operation);
+
+        for ((name, parameter_in), (_, old_parameter)) in &old_parameters {
+            match new_parameters.get(&(name.clone(), parameter_in.clone())) {
+                Some((segments, new_parameter)) => {
+                    let (old_required, new_required) = (
+                        old_parameter.required == Required::True,
+                        new_parameter.required == Required::True,
+                    );
+                    if old_required != new_required {
+                        self.with(http_method, |this| {
+                            this.change(
+                                ChangeKind::ParameterRequiredChanged {
+                                    name: name.clone(),
+                                    parameter_in: new_parameter.parameter_in.clone(),
+                                    required: new_required,
+                                },
+                                Direction::Request,
+                            )
+                        });
+                    }
+                    if let (Some(old_schema), Some(new_schema)) =
+                        (&old_parameter.schema, &new_parameter.schema)
+                    {
+                        self.with_all(segments, |this| {
+                            this.with("schema", |this| {
+                                this.diff_ref_or_schema(old_schema, new_schema, Direction::Request)
+                            })
+                        });
+                    }
+                }
+                None => self.with(http_method, |this| {
+                    this.change(
+                        ChangeKind::ParameterRemoved {
+                            name: name.clone(),
+                            parameter_in: old_parameter.parameter_in.clone(),
+                        },
+                        Direction::Request,
+                    )
+                }),
+            }
+        }
+        for (key, (_, new_parameter)) in &new_parameters {
+            if !old_parameters.contains_key(key) {
+                self.with(http_method, |this| {
+                    this.change(
+                        ChangeKind::ParameterAdded {
+                            name: new_parameter.name.clone(),
+                            parameter_in: new_parameter.parameter_in.clone(),
+                            required: new_parameter.required == Required::True,
+                        },
+                        Direction::Request,
+                    )
+                });
+            }
+        }
+
+        self.with(http_method, |this| {
+            this.diff_request_body(&old_operation.request_body, &new_operation.request_body);
+            this.with("responses", |this| {
+                this.diff_responses(old_operation, new_operation)
+            });
+        });
+    }
+
+    fn diff_request_body(
+        &mut self,
+        old_request_body: &'a Option<RequestBody>,
+        new_request_body: &'a Option<RequestBody>,
+    ) {
+        let is_required =
+            |request_body: &RequestBody| matches!(request_body.required, Some(Required::True));
+
+        match (old_request_body, new_request_body) {
+            (Some(old_request_body), Some(new_request_body)) => {
+                self.with("requestBody", |this| {
+                    let new_required = is_required(new_request_body);
+                    if is_required(old_request_body) != new_required {
+                        this.change(
+                            ChangeKind::RequestBodyRequiredChanged {
+                                required: new_required,
+                            },
+                            Direction::Request,
+                        );
+                    }
+                    this.with("content", |this| {
+                        this.diff_content(
+                            old_request_body.content.iter().collect(),
+                            new_request_body.content.iter().collect(),
+                            Direction::Request,
+                        )
+                    });
+                });
+            }
+            (Some(_), None) => self.with("requestBody", |this| {
+                this.change(ChangeKind::RequestBodyRemoved, Direction::Request)
+            }),
+            (None, Some(new_request_body)) => self.with("requestBody", |this| {
+                this.change(
+                    ChangeKind::RequestBodyAdded {
+                        required: is_required(new_request_body),
+                    },
+                    Direction::Request,
+                )
+            }),
+            (None, None) => (),
+        }
+    }
+
+    fn diff_responses(&mut self, old_operation: &'a Operation, new_operation: &'a Operation) {
+        let (old_responses, new_responses) = (
+            &old_operation.responses.responses,
+            &new_operation.responses.responses,
+        );
+
+        for (status, old_response) in old_responses {
+            match new_responses.get(status) {
+                Some(new_response) => {
+                    let old_response = resolve_response(self.old, old_response);
+                    let new_response = resolve_response(self.new, new_response);
+                    if let (Some(old_response), Some(new_response)) = (old_response, new_response) {
+                        self.with(status, |this| {
+                            this.with("content", |this| {
+                                this.diff_content(
+                                    old_response.content.iter().collect(),
+                                    new_response.content.iter().collect(),
+                                    Direction::Response,
+                                )
+                            })
+                        });
+                    }
+                }
+                None => self.with(status, |this| {
+                    this.change(
+                        ChangeKind::ResponseRemoved {
+                            status: status.clone(),
+                        },
+                        Direction::Response,
+                    )
+                }),
+            }
+        }
+        for status in new_responses.keys() {
+            if !old_responses.contains_key(status) {
+                self.with(status, |this| {
+                    this.change(
+                        ChangeKind::ResponseAdded {
+                            status: status.clone(),
+                        },
+                        Direction::Response,
+                    )
+                });
+            }
+        }
+    }
+
+    fn diff_content(
+        &mut self,
+        old_content: BTreeMap<&'a String, &'a Content>,
+        new_content: BTreeMap<&'a String, &'a Content>,
+        direction: Direction,
+    ) {
+        for (media_type, old_content) in &old_content {
+            match new_content.get(media_type) {
+                Some(new_content) => {
+                    if let (Some(old_schema), Some(new_schema)) =
+                        (&old_content.schema, &new_content.schema)
+                    {
+                        self.with(media_type, |this| {
+                            this.with("schema", |this| {
+                                this.diff_ref_or_schema(old_schema, new_schema, direction)
+                            })
+                        });
+                    }
+                }
+                None => self.with(media_type, |this| {
+                    this.change(
+                        ChangeKind::MediaTypeRemoved {
+                            media_type: media_type.to_string(),
+                        },
+                        direction,
+                    )
+                }),
+            }
+        }
+        for media_type in new_content.keys() {
+            if !old_content.contains_key(media_type) {
+                self.with(media_type, |this| {
+                    this.change(
+                        ChangeKind::MediaTypeAdded {
+                            media_type: media_type.to_string(),
+                        },
+                        direction,
+                    )
+                });
+            }
+        }
+    }
+
+    fn diff_component_schemas(&mut self) {
+        let schemas = |api: &'a OpenApi| {
+            api.components
+                .as_ref()
+                .map(|components| &components.schemas)
+        };
+        let old_schemas = schemas(self.old);
+        let new_schemas = schemas(self.new);
+
+        for (name, old_schema) in old_schemas.into_iter().flatten() {
+            self.with(name, |this| {
+                match new_schemas.and_then(|schemas| schemas.get(name)) {
+                    Some(new_schema) => {
+                        this.diff_ref_or_schema(old_schema, new_schema, Direction::Component)
+                    }
+                    None => this.change(
+                        ChangeKind::SchemaRemoved { name: name.clone() },
+                        Direction::Component,
+                    ),
+                }
+            });
+        }
+        for name in new_schemas.into_iter().flat_map(|schemas| schemas.keys()) {
+            if !old_schemas.is_some_and(|schemas| schemas.contains_key(name)) {
+                self.with(name, |this| {
+                    this.change(
+                        ChangeKind::SchemaAdded { name: name.clone() },
+                        Direction::Component,
+                    )
+                });
+            }
+        }
+    }
+
+    fn diff_ref_or_schema(
+        &mut self,
+        old_schema: &'a RefOr<Schema>,
+        new_schema: &'a RefOr<Schema>,
+        direction: Direction,
+    ) {
+        match (old_schema, new_schema) {
+            // same named schemas are compared in components
+            (RefOr::Ref(old_ref), RefOr::Ref(new_ref)) => {
+                if old_ref.ref_location != new_ref.ref_location {
+                    self.change(
+                        ChangeKind::SchemaReferenceChanged {
+                            old: old_ref.ref_location.clone(),
+                            new: new_ref.ref_location.clone(),
+                        },
+                        direction,
+                    );
+                }
+            }
+            (RefOr::T(old_schema), RefOr::T(new_schema)) => {
+                self.diff_schema(old_schema, new_schema, direction)
+            }
+            (RefOr::Ref(old_ref), RefOr::T(new_schema)) => match self.old.resolve_schema(old_ref) {
+                Some(old_schema) => self.diff_schema(old_schema, new_schema, direction),
+                None => self.change(ChangeKind::SchemaChanged, direction),
+            },
+            (RefOr::T(old_schema), RefOr::Ref(new_ref)) => match self.new.resolve_schema(new_ref) {
+                Some(new_schema) => self.diff_schema(old_schema, new_schema, direction),
+                None => self.change(ChangeKind::SchemaChanged, direction),
+            },
+        }
+    }
+
+    fn diff_schema(
+        &mut self,
+        old_schema: &'a Schema,
+        new_schema: &'a Schema,
+        direction: Direction,
+    ) {
+        match (old_schema, new_schema) {
+            (Schema::Object(old_object), Schema::Object(new_object)) => {
+                self.diff_object(old_object, new_object, direction)
+            }
+            (Schema::Array(old_array), Schema::Array(new_array)) => {
+                self.diff_array(old_array, new_array, direction)
+            }
+            (old_schema, new_schema) => {
+                if old_schema != new_schema {
+                    self.change(ChangeKind::SchemaChanged, direction)
+                }
+            }
+        }
+    }
+
+    fn diff_object(
+        &mut self,
+        old_object: &'a Object,
+        new_object: &'a Object,
+        direction: Direction,
+    ) {
+        if old_object.schema_type != new_object.schema_type {
+            self.change(
+                ChangeKind::TypeChanged {
+                    old: old_object.schema_type.clone(),
+                    new: new_object.schema_type.clone(),
+                },
+                direction,
+            );
+            return;
+        }
+
+        self.with("properties", |this| {
+            for (name, old_property) in &old_object.properties {
+                this.with(name, |this| match new_object.properties.get(name) {
+                    Some(new_property) => {
+                        let old_required = old_object.required.contains(name);
+                        let new_required = new_object.required.contains(name);
+                        if old_required != new_required {
+                            this.change(
+                                ChangeKind::PropertyRequiredChanged {
+                                    name: name.clone(),
+                                    required: new_required,
+                                },
+                                direction,
+                            );
+                        }
+                        this.diff_ref_or_schema(old_property, new_property, direction);
+                    }
+                    None => this.change(
+                        ChangeKind::PropertyRemoved { name: name.clone() },
+                        direction,
+                    ),
+                });
+            }
+            for name in new_object.properties.keys() {
+                if !old_object.properties.contains_key(name) {
+                    this.with(name, |this| {
+                        this.change(
+                            ChangeKind::PropertyAdded {
+                                name: name.clone(),
+                                required: new_object.required.contains(name),
+                            },
+                            direction,
+                        )
+                    });
+                }
+            }
+        });
+    }
+
+    fn diff_array(&mut self, old_array: &'a Array, new_array: &'a Array, direction: Direction) {
+        if old_array.schema_type != new_array.schema_type {
+            self.change(
+                ChangeKind::TypeChanged {
+                    old: old_array.schema_type.clone(),
+                    new: new_array.schema_type.clone(),
+                },
+                direction,
+            );
+            return;
+        }
+
+        match (&old_array.items, &new_array.items) {
+            (ArrayItems::RefOrSchema(old_items), ArrayItems::RefOrSchema(new_items)) => self
+                .with("items", |this| {
+                    this.diff_ref_or_schema(old_items, new_items, direction)
+                }),
+            (ArrayItems::False, ArrayItems::False) => (),
+            _ => self.with("items", |this| {
+                this.change(ChangeKind::SchemaChanged, direction)
+            }),
+        }
+    }
+
+    fn with<S: ToString>(&mut self, segment: S, f: impl FnOnce(&mut Self)) {
+        self.location.push(segment.to_string());
+        f(self);
+        self.location.pop();
+    }
+
+    fn with_all(&mut self, segments: &[String], f: impl FnOnce(&mut Self)) {
+        let len = self.location.len();
+        self.location.extend(segments.iter().cloned());
+        f(self);
+        self.location.truncate(len);
+    }
+
+    fn change(&mut self, kind: ChangeKind, direction: Direction) {
+        self.changes.push(Change {
+            location: self
+                .location
+                .iter()
+                .map(|segment| format!("/{}", escape_pointer_segment(segment)))
+                .collect(),
+            breaking: kind.is_breaking(direction),
+            kind,
+        });
+    }
+}
+
+/// Operations of [`OpenApi::paths`] with their path items in order of the document. Operations
+/// are identified by location segments relative to _`paths`_ e.g. `["/pets", "get"]`.
+#[derive(Default)]
+struct PathOperations<'a>(Vec<(Vec<String>, (&'a PathItem, &'a Operation))>);
+
+impl<'a> PathOperations<'a> {
+    fn collect(api: &'a OpenApi) -> Self {
+        let mut operations = PathOperations::default();
+        operations.visit_openapi(api, &mut Location::new());
+
+        operations
+    }
+
+    fn get(&self, segments: &[String]) -> Option<(&'a PathItem, &'a Operation)> {
+        self.0
+            .iter()
+            .find(|(operation_segments, _)| operation_segments == segments)
+            .map(|(_, operation)| *operation)
+    }
+}
+
+impl<'a> Visit<'a> for PathOperations<'a> {
+    fn visit_path_item(&mut self, path_item: &'a PathItem, location: &mut Location) {
+        // only operations of paths are compared, webhooks and callbacks are not
+        if !matches!(location.segments(), [paths, _] if paths == "paths") {
+            return;
+        }
+
+        for (http_method, operation) in path_item.operations() {
+            location.push(http_method.as_str());
+            self.0
+                .push((location.segments()[1..].to_vec(), (path_item, operation)));
+            location.pop();
+        }
+    }
+
+    fn visit_components(&mut self, _: &'a Components, _: &mut Location) {}
+}
+
+/// Get parameters of the operation merged with parameters of the path item keyed by name and
+/// location. Values are tuples of location segments relative to path item and the parameter.
+fn effective_parameters<'a>(
+    api: &'a OpenApi,
+    http_method: &str,
+    path_item: &'a PathItem,
+    operation: &'a Operation,
+) -> BTreeMap<(String, ParameterInKey), (Vec<String>, &'a Parameter)> {
+    let path_item_parameters = path_item
+        .parameters
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, parameter)| (vec!["parameters".to_string(), index.to_string()], parameter));
+    let operation_parameters =
+        operation
+            .parameters
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, parameter)| {
+                (
+                    vec![
+                        http_method.to_string(),
+                        "parameters".to_string(),
+                        index.to_string(),
+                    ],
+                    parameter,
+                )
+            });
+
+    path_item_parameters
+        .chain(operation_parameters)
+        .filter_map(|(segments, parameter)| {
+            resolve_parameter(api, parameter).map(|parameter| {
+                (
+                    (
+                        parameter.name.clone(),
+                        ParameterInKey(parameter.parameter_in.clone()),
+                    ),
+                    (segments, parameter),
+                )
+            })
+        })
+        .collect()
+}
+
+/// [`ParameterIn`] wrapper usable as ordered map key.
+#[derive(Clone, PartialEq, Eq)]
+struct ParameterInKey(ParameterIn);
+
+impl ParameterInKey {
+    fn order(&self) -> u8 {
+        match self.0 {
+            ParameterIn::Query => 0,
+            ParameterIn::Path => 1,
+            ParameterIn::Header => 2,
+            ParameterIn::Cookie => 3,
+        }
+    }
+}
+
+impl PartialOrd for ParameterInKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ParameterInKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.order().cmp(&other.order())
+    }
+}
+
+fn resolve_parameter<'a>(
+    api: &'a OpenApi,
+    parameter: &'a RefOr<Parameter>,
+) -> Option<&'a Parameter> {
+    match parameter {
+        RefOr::T(parameter) => Some(parameter),
+        RefOr::Ref(reference) => api.resolve_parameter(reference),
+    }
+}
+
+fn resolve_response<'a>(api: &'a OpenApi, response: &'a RefOr<Response>) -> Option<&'a Response> {
+    match response {
+        RefOr::T(response) => Some(response),
+        RefOr::Ref(reference) => api.resolve_response(reference),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, PathItemBuilder};
+    use crate::openapi::request_body::RequestBodyBuilder;
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathsBuilder,
+        ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    /// Get changes sorted by location so that the order does not depend on `preserve_order`
+    /// features.
+    fn changes(diff: &Diff) -> Vec<(&str, &ChangeKind, bool)> {
+        let mut changes = diff
+            .changes
+            .iter()
+            .map(|change| (change.location.as_str(), &change.kind, change.breaking))
+            .collect::<Vec<_>>();
+        changes.sort_by_key(|(location, _, _)| *location);
+
+        changes
+    }
+
+    fn pet_schema() -> ObjectBuilder {
+        ObjectBuilder::new()
+            .property("name", ObjectBuilder::new().schema_type(Type::String))
+            .required("name")
+            .property("age", ObjectBuilder::new().schema_type(Type::Integer))
+    }
+
+    #[test]
+    fn diff_identical_documents_is_empty() {
+        let api = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+            ))
+            .components(Some(
+                ComponentsBuilder::new().schema("Pet", pet_schema()).build(),
+            ))
+            .build();
+
+        assert!(api.diff(&api).is_empty());
+    }
+
+    #[test]
+    fn diff_operations_and_parameters() {
+        let old = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("limit")
+                                        .parameter_in(ParameterIn::Query),
+                                )
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("offset")
+                                        .parameter_in(ParameterIn::Query),
+                                )
+                                .response("200", ResponseBuilder::new())
+                                .response("404", ResponseBuilder::new()),
+                        ),
+                    )
+                    .path(
+                        "/owners",
+                        PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+                    ),
+            )
+            .build();
+        let new = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItemBuilder::new()
+                        .operation(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("limit")
+                                        .parameter_in(ParameterIn::Query)
+                                        .required(Required::True),
+                                )
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("filter")
+                                        .parameter_in(ParameterIn::Query),
+                                )
+                                .response("200", ResponseBuilder::new())
+                                .response("400", ResponseBuilder::new()),
+                        )
+                        .operation(HttpMethod::Post, OperationBuilder::new())
+                        .build(),
+                ),
+            )
+            .build();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            changes(&diff),
+            vec![
+                ("/paths/~1owners/get", &ChangeKind::OperationRemoved, true),
+                (
+                    "/paths/~1pets/get",
+                    &ChangeKind::ParameterRequiredChanged {
+                        name: "limit".to_string(),
+                        parameter_in: ParameterIn::Query,
+                        required: true
+                    },
+                    true
+                ),
+                (
+                    "/paths/~1pets/get",
+                    &ChangeKind::ParameterRemoved {
+                        name: "offset".to_string(),
+                        parameter_in: ParameterIn::Query,
+                    },
+                    true
+                ),
+                (
+                    "/paths/~1pets/get",
+                    &ChangeKind::ParameterAdded {
+                        name: "filter".to_string(),
+                        parameter_in: ParameterIn::Query,
+                        required: false
+                    },
+                    false
+                ),
+                (
+                    "/paths/~1pets/get/responses/400",
+                    &ChangeKind::ResponseAdded {
+                        status: "400".to_string()
+                    },
+                    false
+                ),
+                (
+                    "/paths/~1pets/get/responses/404",
+                    &ChangeKind::ResponseRemoved {
+                        status: "404".to_string()
+                    },
+                    true
+                ),
+                ("/paths/~1pets/post", &ChangeKind::OperationAdded, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_request_bodies() {
+        let api = |put: Option<RequestBody>, post: Option<RequestBody>| {
+            OpenApiBuilder::new()
+                .paths(
+                    PathsBuilder::new().path(
+                        "/pets",
+                        PathItemBuilder::new()
+                            .operation(HttpMethod::Put, OperationBuilder::new().request_body(put))
+                            .operation(HttpMethod::Post, OperationBuilder::new().request_body(post))
+                            .build(),
+                    ),
+                )
+                .build()
+        };
+        let request_body = || Some(RequestBodyBuilder::new().build());
+
+        let diff = api(request_body(), None).diff(&api(None, request_body()));
+
+        assert_eq!(
+            changes(&diff),
+            vec![
+                (
+                    "/paths/~1pets/post/requestBody",
+                    &ChangeKind::RequestBodyAdded { required: false },
+                    false
+                ),
+                (
+                    "/paths/~1pets/put/requestBody",
+                    &ChangeKind::RequestBodyRemoved,
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_schema_properties_by_direction() {
+        let api = |request: ObjectBuilder, response: ObjectBuilder| {
+            OpenApiBuilder::new()
+                .paths(
+                    PathsBuilder::new().path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Post,
+                            OperationBuilder::new()
+                                .request_body(Some(
+                                    RequestBodyBuilder::new()
+                                        .content(
+                                            "application/json",
+                                            ContentBuilder::new().schema(Some(request)).build(),
+                                        )
+                                        .build(),
+                                ))
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new().content(
+                                        "application/json",
+                                        ContentBuilder::new().schema(Some(response)).build(),
+                                    ),
+                                ),
+                        ),
+                    ),
+                )
+                .build()
+        };
+        let old = api(pet_schema(), pet_schema());
+        let new = api(
+            pet_schema()
+                .property("owner", ObjectBuilder::new().schema_type(Type::String))
+                .required("owner"),
+            ObjectBuilder::new()
+                .property("name", ObjectBuilder::new().schema_type(Type::String))
+                .property("owner", ObjectBuilder::new().schema_type(Type::String))
+                .required("owner"),
+        );
+
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            changes(&diff),
+            vec![
+                (
+                    "/paths/~1pets/post/requestBody/content/application~1json/schema/properties/owner",
+                    &ChangeKind::PropertyAdded {
+                        name: "owner".to_string(),
+                        required: true
+                    },
+                    true
+                ),
+                (
+                    "/paths/~1pets/post/responses/200/content/application~1json/schema/properties/age",
+                    &ChangeKind::PropertyRemoved {
+                        name: "age".to_string()
+                    },
+                    true
+                ),
+                (
+                    "/paths/~1pets/post/responses/200/content/application~1json/schema/properties/name",
+                    &ChangeKind::PropertyRequiredChanged {
+                        name: "name".to_string(),
+                        required: false
+                    },
+                    true
+                ),
+                (
+                    "/paths/~1pets/post/responses/200/content/application~1json/schema/properties/owner",
+                    &ChangeKind::PropertyAdded {
+                        name: "owner".to_string(),
+                        required: true
+                    },
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_component_schemas_serializes_to_json() {
+        let old = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", pet_schema())
+                    .schema("Owner", ObjectBuilder::new())
+                    .build(),
+            ))
+            .build();
+        let new = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        pet_schema()
+                            .property("age", ObjectBuilder::new().schema_type(Type::String)),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let diff = old.diff(&new);
+
+        assert!(diff.is_breaking());
+        assert_json_eq!(
+            serde_json::to_value(&diff).expect("diff must serialize"),
+            json!({
+                "changes": [
+                    {
+                        "location": "/components/schemas/Owner",
+                        "breaking": true,
+                        "kind": "schemaRemoved",
+                        "name": "Owner"
+                    },
+                    {
+                        "location": "/components/schemas/Pet/properties/age",
+                        "breaking": true,
+                        "kind": "typeChanged",
+                        "old": "integer",
+                        "new": "string"
+                    }
+                ]
+            })
+        );
+    }
+}
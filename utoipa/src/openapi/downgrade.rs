@@ -0,0 +1,415 @@
+//! Rewrites a serialized OpenAPI 3.1 document to its OpenAPI 3.0 counterpart.
+//!
+//! The rewriting is done on [`Value`] level after the [`OpenApi`][openapi] has been serialized
+//! with its 3.1 representation. Only the parts of the document that have no direct 3.0
+//! counterpart are changed. [`Value`] keeps the keys of the objects in the serialized order thus
+//! the order of e.g. paths and properties is the same as in the 3.1 output.
+//!
+//! [openapi]: super::OpenApi
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+type Map = IndexMap<String, Value>;
+
+/// JSON value keeping the order of object keys regardless of enabled `serde_json` features.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub(super) enum Value {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Value>),
+    Object(Map),
+}
+
+impl Value {
+    fn as_object_mut(&mut self) -> Option<&mut Map> {
+        match self {
+            Self::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    fn as_array_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn as_object(&self) -> Option<&Map> {
+        match self {
+            Self::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => number.as_f64(),
+            _ => None,
+        }
+    }
+
+    fn is_object(&self) -> bool {
+        matches!(self, Self::Object(_))
+    }
+}
+
+/// Keys of the objects which map names chosen by the user to OpenAPI objects.
+const NAMED_MAPS: [&str; 4] = ["responses", "content", "encoding", "headers"];
+
+/// Fields holding user data e.g. example payloads or link parameters, which are left as is.
+const DATA_FIELDS: [&str; 5] = ["example", "examples", "default", "links", "value"];
+
+/// Downgrade serialized OpenAPI 3.1 _`document`_ to OpenAPI 3.0.
+pub(super) fn downgrade_document(document: &mut Value) {
+    let Some(document) = document.as_object_mut() else {
+        return;
+    };
+
+    // webhooks are not supported in OpenAPI 3.0
+    document.shift_remove("webhooks");
+    if let Some(components) = document
+        .get_mut("components")
+        .and_then(Value::as_object_mut)
+    {
+        components.shift_remove("pathItems");
+    }
+
+    // 3.1 only fields of the Info object
+    if let Some(info) = document.get_mut("info").and_then(Value::as_object_mut) {
+        info.shift_remove("summary");
+        if let Some(license) = info.get_mut("license").and_then(Value::as_object_mut) {
+            license.shift_remove("identifier");
+        }
+    }
+
+    if let Some(paths) = document.get_mut("paths") {
+        downgrade_named_objects(paths);
+    }
+    if let Some(components) = document
+        .get_mut("components")
+        .and_then(Value::as_object_mut)
+    {
+        for (kind, values) in components.iter_mut() {
+            match kind.as_str() {
+                "schemas" => values
+                    .as_object_mut()
+                    .into_iter()
+                    .flat_map(Map::values_mut)
+                    .for_each(downgrade_schema),
+                "callbacks" => downgrade_callbacks(values),
+                "examples" | "links" => (),
+                kind if is_extension(kind) => (),
+                _ => downgrade_named_objects(values),
+            }
+        }
+    }
+}
+
+fn is_extension(name: &str) -> bool {
+    name.starts_with("x-")
+}
+
+/// Downgrade the _`schema`_ fields of every object of the _`value`_ mapping names to objects.
+fn downgrade_named_objects(value: &mut Value) {
+    if let Some(object) = value.as_object_mut() {
+        object.values_mut().for_each(downgrade_nested_schemas);
+    }
+}
+
+/// Downgrade every callback of the _`value`_ mapping names to callbacks.
+fn downgrade_callbacks(value: &mut Value) {
+    if let Some(callbacks) = value.as_object_mut() {
+        callbacks.values_mut().for_each(downgrade_named_objects);
+    }
+}
+
+/// Find all _`schema`_ fields e.g. in parameters, contents and headers from the given non schema
+/// _`value`_ and downgrade them. User data e.g. examples is left as is.
+fn downgrade_nested_schemas(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (name, value) in object.iter_mut() {
+                match name.as_str() {
+                    "schema" => downgrade_schema(value),
+                    "callbacks" => downgrade_callbacks(value),
+                    name if NAMED_MAPS.contains(&name) => downgrade_named_objects(value),
+                    name if DATA_FIELDS.contains(&name) || is_extension(name) => (),
+                    _ => downgrade_nested_schemas(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(downgrade_nested_schemas),
+        _ => (),
+    }
+}
+
+/// Downgrade a single JSON Schema 2020-12 _`schema`_ and all of its sub schemas to the OpenAPI 3.0
+/// Schema Object.
+fn downgrade_schema(schema: &mut Value) {
+    if let Value::Bool(value) = schema {
+        *schema = downgrade_boolean_schema(*value);
+        return;
+    }
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    if let Some(properties) = object.get_mut("properties").and_then(Value::as_object_mut) {
+        properties.values_mut().for_each(downgrade_schema);
+    }
+    for keyword in ["allOf", "oneOf", "anyOf", "prefixItems"] {
+        if let Some(items) = object.get_mut(keyword).and_then(Value::as_array_mut) {
+            items.iter_mut().for_each(downgrade_schema);
+        }
+    }
+    // boolean values are valid as is or handled with the prefix items
+    for keyword in ["items", "additionalProperties"] {
+        if let Some(value) = object.get_mut(keyword).filter(|value| value.is_object()) {
+            downgrade_schema(value);
+        }
+    }
+    if let Some(not) = object.get_mut("not") {
+        downgrade_schema(not);
+    }
+
+    downgrade_type(object);
+    downgrade_composite_null(object);
+    downgrade_examples(object);
+    downgrade_const(object);
+    downgrade_prefix_items(object);
+    downgrade_content(object);
+    downgrade_exclusive_limit(object, "exclusiveMinimum", "minimum");
+    downgrade_exclusive_limit(object, "exclusiveMaximum", "maximum");
+
+    // no counterpart in OpenAPI 3.0
+    for keyword in [
+        "propertyNames",
+        "patternProperties",
+        "dependentRequired",
+        "dependentSchemas",
+        "unevaluatedProperties",
+        "if",
+        "then",
+        "else",
+        "contains",
+        "minContains",
+        "maxContains",
+        "$defs",
+        "$id",
+        "$anchor",
+    ] {
+        object.shift_remove(keyword);
+    }
+}
+
+/// Boolean schema `true` becomes empty schema `{}` and `false` becomes `{"not": {}}`.
+fn downgrade_boolean_schema(value: bool) -> Value {
+    if value {
+        Value::Object(Map::new())
+    } else {
+        Value::Object(Map::from_iter([(
+            "not".to_string(),
+            Value::Object(Map::new()),
+        )]))
+    }
+}
+
+fn is_null_type(value: &Value) -> bool {
+    value.as_str() == Some("null")
+}
+
+/// `type: [T, "null"]` becomes `type: T` with `nullable: true`. Multiple non null types are
+/// expressed with `anyOf` since OpenAPI 3.0 only allows single type.
+fn downgrade_type(object: &mut Map) {
+    let types = match object.get("type") {
+        Some(Value::Array(types)) => types.clone(),
+        Some(value) if is_null_type(value) => vec![value.clone()],
+        _ => return,
+    };
+
+    let nullable = types.iter().any(is_null_type);
+    let mut types = types
+        .into_iter()
+        .filter(|value| !is_null_type(value))
+        .collect::<Vec<_>>();
+
+    if types.len() == 1 {
+        object.insert("type".to_string(), types.remove(0));
+    } else if types.len() > 1 && !object.contains_key("anyOf") {
+        let any_of = types
+            .into_iter()
+            .map(|value| Value::Object(Map::from_iter([("type".to_string(), value)])))
+            .collect();
+        replace_key(object, "type", "anyOf", Value::Array(any_of));
+    } else {
+        object.shift_remove("type");
+    }
+
+    if nullable {
+        object.insert("nullable".to_string(), Value::Bool(true));
+    }
+}
+
+fn is_nullable_only(value: &Value) -> bool {
+    value.as_object().is_some_and(|object| {
+        object.get("nullable") == Some(&Value::Bool(true)) && !object.contains_key("type")
+    })
+}
+
+/// `oneOf: [{type: "null"}, T]` becomes `allOf: [T]` with `nullable: true`, which is the OpenAPI
+/// 3.0 way of declaring nullable references.
+fn downgrade_composite_null(object: &mut Map) {
+    for keyword in ["oneOf", "anyOf"] {
+        let Some(items) = object.get_mut(keyword).and_then(Value::as_array_mut) else {
+            continue;
+        };
+        let len = items.len();
+        items.retain(|item| !is_nullable_only(item));
+        if items.len() == len {
+            continue;
+        }
+
+        if items.len() == 1 && !object.contains_key("allOf") {
+            let items = object
+                .get(keyword)
+                .cloned()
+                .expect("composite keyword must exist");
+            replace_key(object, keyword, "allOf", items);
+        }
+        object.insert("nullable".to_string(), Value::Bool(true));
+    }
+}
+
+/// `examples: [..]` becomes `example` with the first example value.
+fn downgrade_examples(object: &mut Map) {
+    let Some(Value::Array(examples)) = object.get("examples") else {
+        return;
+    };
+    match examples.first().cloned() {
+        Some(example) if !object.contains_key("example") => {
+            replace_key(object, "examples", "example", example)
+        }
+        _ => {
+            object.shift_remove("examples");
+        }
+    }
+}
+
+/// `const: value` becomes `enum: [value]`.
+fn downgrade_const(object: &mut Map) {
+    let Some(value) = object.get("const").cloned() else {
+        return;
+    };
+    if object.contains_key("enum") {
+        object.shift_remove("const");
+    } else {
+        replace_key(object, "const", "enum", Value::Array(vec![value]));
+    }
+}
+
+/// Tuple `prefixItems` become `items` with `anyOf` of the prefix items. When no additional items
+/// are allowed the length of the array is fixed with `minItems` and `maxItems`.
+fn downgrade_prefix_items(object: &mut Map) {
+    let prefix_items = match object.shift_remove("prefixItems") {
+        Some(Value::Array(prefix_items)) => prefix_items,
+        Some(_) | None => Vec::new(),
+    };
+    let items_false = object.get("items") == Some(&Value::Bool(false));
+
+    if prefix_items.is_empty() {
+        if items_false {
+            object.insert("items".to_string(), Value::Object(Map::new()));
+            object.insert("maxItems".to_string(), Value::Number(0.into()));
+        }
+        return;
+    }
+
+    let len = prefix_items.len();
+    let mut any_of = prefix_items;
+    match object.shift_remove("items") {
+        Some(Value::Bool(false)) | None => {
+            object
+                .entry("minItems".to_string())
+                .or_insert_with(|| Value::Number(len.into()));
+            object
+                .entry("maxItems".to_string())
+                .or_insert_with(|| Value::Number(len.into()));
+        }
+        Some(items) => any_of.push(items),
+    };
+
+    let items = if any_of.len() == 1 {
+        any_of.remove(0)
+    } else {
+        Value::Object(Map::from_iter([(
+            "anyOf".to_string(),
+            Value::Array(any_of),
+        )]))
+    };
+    object.insert("items".to_string(), items);
+}
+
+/// `contentEncoding: base64` becomes `format: byte` and `contentMediaType` becomes
+/// `format: binary`.
+fn downgrade_content(object: &mut Map) {
+    let content_encoding = object.shift_remove("contentEncoding");
+    let content_media_type = object.shift_remove("contentMediaType");
+
+    let format = match (content_encoding, content_media_type) {
+        (Some(Value::String(encoding)), _) if encoding.eq_ignore_ascii_case("base64") => "byte",
+        (_, Some(_)) => "binary",
+        _ => return,
+    };
+    object
+        .entry("format".to_string())
+        .or_insert_with(|| Value::String(format.to_string()));
+}
+
+/// Numeric `exclusiveMinimum: n` becomes `minimum: n` with `exclusiveMinimum: true`. Same applies to
+/// `exclusiveMaximum`. If the schema already has stricter inclusive limit, the exclusive limit is
+/// omitted instead.
+fn downgrade_exclusive_limit(object: &mut Map, exclusive: &str, inclusive: &str) {
+    let Some(limit) = object.get(exclusive).and_then(Value::as_f64) else {
+        return;
+    };
+    let is_inclusive_stricter =
+        object
+            .get(inclusive)
+            .and_then(Value::as_f64)
+            .is_some_and(|inclusive_limit| {
+                if exclusive == "exclusiveMinimum" {
+                    inclusive_limit > limit
+                } else {
+                    inclusive_limit < limit
+                }
+            });
+
+    if is_inclusive_stricter {
+        object.shift_remove(exclusive);
+    } else if let Some(limit) = object.insert(exclusive.to_string(), Value::Bool(true)) {
+        object.insert(inclusive.to_string(), limit);
+    }
+}
+
+/// Replace the `from` key of the `object` with the `to` key and `value` keeping the position of
+/// the key. The `to` key must not exist in the `object`.
+fn replace_key(object: &mut Map, from: &str, to: &str, value: Value) {
+    let Some(index) = object.get_index_of(from) else {
+        return;
+    };
+    object.shift_remove_index(index);
+    let (inserted, _) = object.insert_full(to.to_string(), value);
+    object.move_index(inserted, index);
+}
@@ -0,0 +1,252 @@
+//! Implements filtering of [`OpenApi`] documents to subset of its operations.
+use super::path::{HttpMethod, Operation};
+use super::OpenApi;
+
+impl OpenApi {
+    /// Retain only the [`Operation`]s of [`OpenApi::paths`] for which `predicate` returns
+    /// `true`.
+    ///
+    /// The `predicate` is called with path, [`HttpMethod`] and [`Operation`] of every operation.
+    /// Path items left without any operations are removed. Afterwards components that were
+    /// reachable from the removed operations only are removed. Components that were not
+    /// reachable before filtering are kept, use [`OpenApi::prune_unused_components`] to remove
+    /// them as well.
+    ///
+    /// Webhooks are not filtered since they are not identified by path.
+    ///
+    /// # Examples
+    ///
+    /// _**Remove all delete operations.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::{OperationBuilder, PathItemBuilder};
+    /// let mut api = OpenApiBuilder::new()
+    ///     .paths(
+    ///         PathsBuilder::new().path(
+    ///             "/pets",
+    ///             PathItemBuilder::new()
+    ///                 .operation(HttpMethod::Get, OperationBuilder::new())
+    ///                 .operation(HttpMethod::Delete, OperationBuilder::new())
+    ///                 .build(),
+    ///         ),
+    ///     )
+    ///     .build();
+    ///
+    /// api.filter(|_, method, _| method != HttpMethod::Delete);
+    ///
+    /// let pets = api.paths.get_path_item("/pets").unwrap();
+    /// assert!(pets.get.is_some());
+    /// assert!(pets.delete.is_none());
+    /// ```
+    pub fn filter<F: FnMut(&str, HttpMethod, &Operation) -> bool>(&mut self, mut predicate: F) {
+        let reachable = self.reachable_components();
+
+        self.paths.paths.retain(|path, path_item| {
+            let removed = path_item
+                .operations()
+                .filter(|(http_method, operation)| !predicate(path, http_method.clone(), operation))
+                .map(|(http_method, _)| http_method)
+                .collect::<Vec<_>>();
+            if removed.is_empty() {
+                return true;
+            }
+
+            for http_method in removed {
+                path_item.remove_operation(http_method);
+            }
+            path_item.operations().next().is_some()
+        });
+
+        // remove only components that became unreachable by filtering, components that were
+        // not reachable in the first place are left as is
+        let still_reachable = self.reachable_components();
+        self.retain_components(|kind, name| {
+            let component = (kind.to_string(), name.clone());
+            !reachable.contains(&component) || still_reachable.contains(&component)
+        });
+    }
+
+    /// Retain only the [`Operation`]s tagged with at least one of the given `tags`.
+    ///
+    /// Top level [`OpenApi::tags`] not in given `tags` are removed as well. See
+    /// [`OpenApi::filter`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// _**Publish only the public part of the API.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut api = OpenApiBuilder::new()
+    ///     .paths(
+    ///         PathsBuilder::new()
+    ///             .path("/pets", PathItem::new(HttpMethod::Get, OperationBuilder::new().tag("public")))
+    ///             .path("/metrics", PathItem::new(HttpMethod::Get, OperationBuilder::new().tag("internal"))),
+    ///     )
+    ///     .build();
+    ///
+    /// api.retain_tags(["public"]);
+    ///
+    /// assert!(api.paths.get_path_item("/pets").is_some());
+    /// assert!(api.paths.get_path_item("/metrics").is_none());
+    /// ```
+    pub fn retain_tags<I: IntoIterator<Item = S>, S: AsRef<str>>(&mut self, tags: I) {
+        let tags = tags
+            .into_iter()
+            .map(|tag| tag.as_ref().to_string())
+            .collect::<Vec<_>>();
+
+        if let Some(openapi_tags) = self.tags.as_mut() {
+            openapi_tags.retain(|tag| tags.contains(&tag.name));
+        }
+
+        self.filter(|_, _, operation| {
+            operation
+                .tags
+                .iter()
+                .flatten()
+                .any(|operation_tag| tags.contains(operation_tag))
+        });
+    }
+
+    /// Remove all paths starting with given `prefix` e.g. _`/admin`_.
+    ///
+    /// Prefix is matched against the path string as is thus _`/admin`_ will also match
+    /// _`/administrators`_. See [`OpenApi::filter`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut api = OpenApiBuilder::new()
+    ///     .paths(
+    ///         PathsBuilder::new()
+    ///             .path("/pets", PathItem::new(HttpMethod::Get, OperationBuilder::new()))
+    ///             .path("/admin/users", PathItem::new(HttpMethod::Get, OperationBuilder::new())),
+    ///     )
+    ///     .build();
+    ///
+    /// api.exclude_paths_with_prefix("/admin");
+    ///
+    /// assert!(api.paths.get_path_item("/pets").is_some());
+    /// assert!(api.paths.get_path_item("/admin/users").is_none());
+    /// ```
+    pub fn exclude_paths_with_prefix<P: AsRef<str>>(&mut self, prefix: P) {
+        let prefix = prefix.as_ref();
+        self.filter(|path, _, _| !path.starts_with(prefix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, PathItemBuilder};
+    use crate::openapi::tag::Tag;
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, ObjectBuilder, OpenApiBuilder, PathItem, PathsBuilder,
+        Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn operation(tag: &str, schema: &str) -> OperationBuilder {
+        OperationBuilder::new().tag(tag).response(
+            "200",
+            ResponseBuilder::new().content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name(schema)))
+                    .build(),
+            ),
+        )
+    }
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItemBuilder::new()
+                            .operation(HttpMethod::Get, operation("public", "Pet"))
+                            .operation(HttpMethod::Delete, operation("internal", "Pet"))
+                            .build(),
+                    )
+                    .path(
+                        "/admin/users",
+                        PathItem::new(HttpMethod::Get, operation("internal", "User")),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new())
+                    .schema("User", ObjectBuilder::new())
+                    .build(),
+            ))
+            .tags(Some([Tag::new("public"), Tag::new("internal")]))
+            .build()
+    }
+
+    fn schema_names(api: &OpenApi) -> Vec<&str> {
+        api.components
+            .iter()
+            .flat_map(|components| components.schemas.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn retain_tags_removes_operations_and_prunes_components() {
+        let mut api = api();
+
+        api.retain_tags(["public"]);
+
+        let pets = api.paths.get_path_item("/pets").expect("/pets must exist");
+        assert!(pets.get.is_some());
+        assert!(pets.delete.is_none());
+        assert!(api.paths.get_path_item("/admin/users").is_none());
+        assert_eq!(schema_names(&api), ["Pet"]);
+        assert_eq!(api.tags, Some(vec![Tag::new("public")]));
+    }
+
+    #[test]
+    fn exclude_paths_with_prefix_removes_paths_and_prunes_components() {
+        let mut api = api();
+
+        api.exclude_paths_with_prefix("/admin");
+
+        let pets = api.paths.get_path_item("/pets").expect("/pets must exist");
+        assert!(pets.get.is_some());
+        assert!(pets.delete.is_some());
+        assert!(api.paths.get_path_item("/admin/users").is_none());
+        assert_eq!(schema_names(&api), ["Pet"]);
+    }
+
+    #[test]
+    fn filter_keeps_components_unreachable_before_filtering() {
+        let mut api = api();
+        if let Some(components) = api.components.as_mut() {
+            components
+                .schemas
+                .insert("Unused".to_string(), ObjectBuilder::new().into());
+        }
+
+        api.exclude_paths_with_prefix("/admin");
+
+        assert_eq!(schema_names(&api), ["Pet", "Unused"]);
+    }
+
+    #[test]
+    fn filter_keeps_path_items_without_operations() {
+        let mut api = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItemBuilder::new().summary(Some("Pets")).build(),
+            ))
+            .build();
+
+        api.filter(|_, _, _| false);
+
+        assert!(api.paths.get_path_item("/pets").is_some());
+    }
+}
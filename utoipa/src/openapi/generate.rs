@@ -0,0 +1,639 @@
+//! Implements generation of example values from [`Schema`]s.
+use serde_json::{Map, Value};
+
+use super::schema::{
+    AdditionalProperties, Array, ArrayItems, KnownFormat, Object, Schema, SchemaFormat, SchemaType,
+    Type,
+};
+use super::{OpenApi, Ref, RefOr};
+use crate::Number;
+
+impl Schema {
+    /// Generate example value of this [`Schema`] resolving local schema references from given
+    /// `openapi`.
+    ///
+    /// Explicitly defined values are preferred in order of _`examples`_, _`example`_,
+    /// _`default`_, _`const`_ and the first _`enum`_ value. Otherwise value is synthesized from
+    /// the schema type honoring _`format`_, _`minimum`_, _`maximum`_, _`multipleOf`_, length
+    /// bounds and simple _`pattern`_s. Objects are generated with all of their properties, arrays
+    /// with _`minItems`_ items but at least one, and _`oneOf`_ and _`anyOf`_ from their first
+    /// item. Items of _`allOf`_ are merged together.
+    ///
+    /// Recursive references are generated only once. Recursive properties are left out unless
+    /// required in which case they are generated as `null`, and recursive array items are left
+    /// out entirely.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_json::json;
+    /// # use utoipa::openapi::{KnownFormat, ObjectBuilder, OpenApi, Schema, SchemaFormat, Type};
+    /// let schema: Schema = ObjectBuilder::new()
+    ///     .property("id", ObjectBuilder::new().schema_type(Type::Integer).minimum(Some(1)))
+    ///     .property(
+    ///         "created",
+    ///         ObjectBuilder::new()
+    ///             .schema_type(Type::String)
+    ///             .format(Some(SchemaFormat::KnownFormat(KnownFormat::Date))),
+    ///     )
+    ///     .into();
+    ///
+    /// assert_eq!(
+    ///     schema.generate_example(&OpenApi::default()),
+    ///     json!({"created": "2024-01-01", "id": 1})
+    /// );
+    /// ```
+    pub fn generate_example(&self, openapi: &OpenApi) -> Value {
+        ExampleGenerator {
+            openapi,
+            stack: Vec::new(),
+        }
+        .generate_schema(self)
+    }
+}
+
+impl OpenApi {
+    /// Generate example value of the `schema` resolving local schema references from
+    /// [`OpenApi::components`] of this [`OpenApi`]. Unresolvable references are generated as
+    /// `null`. See [`Schema::generate_example`] for more details.
+    pub fn generate_example(&self, schema: &RefOr<Schema>) -> Value {
+        ExampleGenerator {
+            openapi: self,
+            stack: Vec::new(),
+        }
+        .generate_ref_or_schema(schema)
+        .unwrap_or_default()
+    }
+
+    /// Fill in _`examples`_ of every schema in [`OpenApi::components`] which does not define
+    /// _`example`_ nor _`examples`_ yet with example generated by [`Schema::generate_example`].
+    ///
+    /// This is useful for showing meaningful payloads in UIs for types without explicitly
+    /// defined examples.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_json::json;
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, RefOr, Schema, Type};
+    /// let mut api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema(
+    ///                 "Pet",
+    ///                 ObjectBuilder::new().property("name", ObjectBuilder::new().schema_type(Type::String)),
+    ///             )
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// api.fill_missing_examples();
+    ///
+    /// let components = api.components.unwrap();
+    /// let RefOr::T(Schema::Object(pet)) = &components.schemas["Pet"] else {
+    ///     panic!("Pet must be an object");
+    /// };
+    /// assert_eq!(pet.examples, [json!({"name": "string"})]);
+    /// ```
+    pub fn fill_missing_examples(&mut self) {
+        let Some(components) = self.components.as_ref() else {
+            return;
+        };
+
+        let examples = components
+            .schemas
+            .iter()
+            .filter_map(|(name, schema)| match schema {
+                RefOr::T(schema) if !has_examples(schema) => Some((
+                    name.clone(),
+                    self.generate_example(&Ref::from_schema_name(name).into()),
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let Some(components) = self.components.as_mut() else {
+            return;
+        };
+        for (name, example) in examples {
+            if let Some(RefOr::T(schema)) = components.schemas.get_mut(&name) {
+                if let Some(examples) = examples_mut(schema) {
+                    examples.push(example);
+                }
+            }
+        }
+    }
+}
+
+fn has_examples(schema: &Schema) -> bool {
+    match schema {
+        Schema::Object(object) => object.example.is_some() || !object.examples.is_empty(),
+        Schema::Array(array) => array.example.is_some() || !array.examples.is_empty(),
+        Schema::OneOf(one_of) => one_of.example.is_some() || !one_of.examples.is_empty(),
+        Schema::AllOf(all_of) => all_of.example.is_some() || !all_of.examples.is_empty(),
+        Schema::AnyOf(any_of) => any_of.example.is_some() || !any_of.examples.is_empty(),
+        _ => true,
+    }
+}
+
+fn examples_mut(schema: &mut Schema) -> Option<&mut Vec<Value>> {
+    match schema {
+        Schema::Object(object) => Some(&mut object.examples),
+        Schema::Array(array) => Some(&mut array.examples),
+        Schema::OneOf(one_of) => Some(&mut one_of.examples),
+        Schema::AllOf(all_of) => Some(&mut all_of.examples),
+        Schema::AnyOf(any_of) => Some(&mut any_of.examples),
+        _ => None,
+    }
+}
+
+/// Return first explicitly defined example value.
+fn explicit_example<'v>(
+    examples: &'v [Value],
+    example: &'v Option<Value>,
+    default: &'v Option<Value>,
+) -> Option<&'v Value> {
+    examples.first().or(example.as_ref()).or(default.as_ref())
+}
+
+struct ExampleGenerator<'a> {
+    openapi: &'a OpenApi,
+    /// References currently being generated used to stop at recursion.
+    stack: Vec<&'a str>,
+}
+
+impl<'a> ExampleGenerator<'a> {
+    /// Generate example of [`RefOr`] schema. Returns `None` for recursive references.
+    fn generate_ref_or_schema(&mut self, schema: &'a RefOr<Schema>) -> Option<Value> {
+        match schema {
+            RefOr::T(schema) => Some(self.generate_schema(schema)),
+            RefOr::Ref(reference) => {
+                let location = reference.ref_location.as_str();
+                if self.stack.contains(&location) {
+                    return None;
+                }
+
+                let schema = self.openapi.resolve_schema(reference)?;
+                self.stack.push(location);
+                let example = self.generate_schema(schema);
+                self.stack.pop();
+
+                Some(example)
+            }
+        }
+    }
+
+    fn generate_schema(&mut self, schema: &'a Schema) -> Value {
+        match schema {
+            Schema::Object(object) => self.generate_object(object),
+            Schema::Array(array) => self.generate_array(array),
+            Schema::OneOf(one_of) => {
+                explicit_example(&one_of.examples, &one_of.example, &one_of.default)
+                    .cloned()
+                    .or_else(|| self.generate_first(&one_of.items))
+                    .unwrap_or_default()
+            }
+            Schema::AnyOf(any_of) => {
+                explicit_example(&any_of.examples, &any_of.example, &any_of.default)
+                    .cloned()
+                    .or_else(|| self.generate_first(&any_of.items))
+                    .unwrap_or_default()
+            }
+            Schema::AllOf(all_of) => {
+                if let Some(example) =
+                    explicit_example(&all_of.examples, &all_of.example, &all_of.default)
+                {
+                    return example.clone();
+                }
+
+                all_of
+                    .items
+                    .iter()
+                    .filter_map(|item| self.generate_ref_or_schema(item))
+                    .fold(Value::Null, |merged, example| match (merged, example) {
+                        (Value::Object(mut merged), Value::Object(example)) => {
+                            merged.extend(example);
+                            Value::Object(merged)
+                        }
+                        (Value::Object(merged), _) => Value::Object(merged),
+                        (_, example) => example,
+                    })
+            }
+            Schema::Bool(_) => Value::Null,
+        }
+    }
+
+    fn generate_first(&mut self, items: &'a [RefOr<Schema>]) -> Option<Value> {
+        items
+            .iter()
+            .find_map(|item| self.generate_ref_or_schema(item))
+    }
+
+    fn generate_object(&mut self, object: &'a Object) -> Value {
+        if let Some(example) = explicit_example(&object.examples, &object.example, &object.default)
+            .or(object.const_value.as_ref())
+            .or(object.enum_values.iter().flatten().next())
+        {
+            return example.clone();
+        }
+
+        let schema_type = match &object.schema_type {
+            SchemaType::Type(schema_type) => Some(schema_type),
+            SchemaType::Array(schema_types) => schema_types
+                .iter()
+                .find(|schema_type| **schema_type != Type::Null)
+                .or(schema_types.first()),
+            SchemaType::AnyValue if !object.properties.is_empty() => Some(&Type::Object),
+            SchemaType::AnyValue => None,
+        };
+
+        match schema_type {
+            Some(Type::Object) => {
+                let mut map = Map::new();
+                for (name, property) in &object.properties {
+                    match self.generate_ref_or_schema(property) {
+                        Some(example) => {
+                            map.insert(name.clone(), example);
+                        }
+                        None if object.required.contains(name) => {
+                            map.insert(name.clone(), Value::Null);
+                        }
+                        None => (),
+                    }
+                }
+                if let (true, Some(AdditionalProperties::RefOr(schema))) =
+                    (map.is_empty(), object.additional_properties.as_deref())
+                {
+                    if let Some(example) = self.generate_ref_or_schema(schema) {
+                        map.insert("additionalProp1".to_string(), example);
+                    }
+                }
+
+                Value::Object(map)
+            }
+            Some(Type::String) => Value::String(generate_string(object)),
+            Some(Type::Integer) => generate_number(object, true),
+            Some(Type::Number) => generate_number(object, false),
+            Some(Type::Boolean) => Value::Bool(true),
+            Some(Type::Array) => Value::Array(Vec::new()),
+            Some(Type::Null) | None => Value::Null,
+        }
+    }
+
+    fn generate_array(&mut self, array: &'a Array) -> Value {
+        if let Some(example) = explicit_example(&array.examples, &array.example, &array.default) {
+            return example.clone();
+        }
+
+        let mut items = array
+            .prefix_items
+            .iter()
+            .map(|item| self.generate_schema(item))
+            .collect::<Vec<_>>();
+
+        if let ArrayItems::RefOrSchema(schema) = &array.items {
+            let len = array
+                .min_items
+                .unwrap_or(1)
+                .max(1)
+                .min(array.max_items.unwrap_or(usize::MAX));
+            if items.len() < len {
+                if let Some(example) = self.generate_ref_or_schema(schema) {
+                    items.resize(len, example);
+                }
+            }
+        }
+
+        Value::Array(items)
+    }
+}
+
+fn generate_string(object: &Object) -> String {
+    if let Some(example) = object.pattern.as_deref().and_then(generate_pattern) {
+        return example;
+    }
+
+    let example = match &object.format {
+        Some(SchemaFormat::KnownFormat(format)) => match format {
+            KnownFormat::Byte => "ZXhhbXBsZQ==",
+            KnownFormat::Binary => "",
+            KnownFormat::Date => "2024-01-01",
+            KnownFormat::DateTime => "2024-01-01T00:00:00Z",
+            KnownFormat::Time => "00:00:00Z",
+            KnownFormat::Duration => "P1D",
+            KnownFormat::Password => "password",
+            #[cfg(feature = "uuid")]
+            KnownFormat::Uuid => "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+            #[cfg(feature = "ulid")]
+            KnownFormat::Ulid => "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            #[cfg(feature = "url")]
+            KnownFormat::Uri | KnownFormat::Iri => "https://example.com",
+            #[cfg(feature = "url")]
+            KnownFormat::UriReference | KnownFormat::IriReference => "/example",
+            KnownFormat::Email | KnownFormat::IdnEmail => "user@example.com",
+            KnownFormat::Hostname | KnownFormat::IdnHostname => "example.com",
+            KnownFormat::Ipv4 => "192.0.2.1",
+            KnownFormat::Ipv6 => "2001:db8::1",
+            KnownFormat::UriTemplate => "https://example.com/{id}",
+            KnownFormat::JsonPointer => "/example",
+            KnownFormat::RelativeJsonPointer => "0/example",
+            KnownFormat::Regex => "^.*$",
+            _ => "string",
+        },
+        _ => "string",
+    };
+    if example != "string" {
+        return example.to_string();
+    }
+
+    let mut example = example.to_string();
+    if let Some(min_length) = object.min_length {
+        let len = example.len();
+        example.extend(std::iter::repeat('a').take(min_length.saturating_sub(len)));
+    }
+    if let Some(max_length) = object.max_length {
+        example.truncate(max_length);
+    }
+
+    example
+}
+
+/// Generate string matching simple regular expression `pattern` consisting of literals,
+/// character classes and quantifiers. Returns `None` for unsupported patterns e.g. ones with
+/// groups or alternation.
+fn generate_pattern(pattern: &str) -> Option<String> {
+    let mut chars = pattern.chars().peekable();
+    let mut example = String::new();
+
+    while let Some(char) = chars.next() {
+        let atom = match char {
+            '^' | '$' => continue,
+            '.' => 'a',
+            '\\' => match chars.next()? {
+                'd' => '0',
+                'w' | 'D' | 'S' => 'a',
+                's' => ' ',
+                'W' => '-',
+                escaped if escaped.is_ascii_alphanumeric() => return None,
+                escaped => escaped,
+            },
+            '[' => {
+                let first = match chars.next()? {
+                    '^' => return None,
+                    '\\' => match chars.next()? {
+                        'd' => '0',
+                        'w' => 'a',
+                        's' => ' ',
+                        escaped if escaped.is_ascii_alphanumeric() => return None,
+                        escaped => escaped,
+                    },
+                    first => first,
+                };
+                let mut is_closed = first == ']';
+                for char in chars.by_ref() {
+                    if char == ']' {
+                        is_closed = true;
+                        break;
+                    }
+                }
+                if !is_closed || first == ']' {
+                    return None;
+                }
+                first
+            }
+            '(' | ')' | '|' | '*' | '+' | '?' | '{' | '}' | ']' => return None,
+            literal => literal,
+        };
+
+        let count = match chars.peek() {
+            Some('*' | '+' | '?') => {
+                chars.next();
+                1
+            }
+            Some('{') => {
+                chars.next();
+                let mut quantifier = String::new();
+                for char in chars.by_ref() {
+                    if char == '}' {
+                        break;
+                    }
+                    quantifier.push(char);
+                }
+                let (min, max) = match quantifier.split_once(',') {
+                    Some((min, max)) => (min, (!max.is_empty()).then_some(max)),
+                    None => (quantifier.as_str(), Some(quantifier.as_str())),
+                };
+                let min = min.parse::<usize>().ok()?;
+                let max = max.map(str::parse::<usize>).transpose().ok()?;
+                if min == 0 && max != Some(0) {
+                    1
+                } else {
+                    min
+                }
+            }
+            _ => 1,
+        };
+        if chars.peek() == Some(&'?') {
+            chars.next();
+        }
+
+        example.extend(std::iter::repeat(atom).take(count));
+    }
+
+    Some(example)
+}
+
+fn generate_number(object: &Object, is_integer: bool) -> Value {
+    let minimum = object.minimum.as_ref().map(to_f64);
+    let maximum = object.maximum.as_ref().map(to_f64);
+    let exclusive_minimum = object.exclusive_minimum.as_ref().map(to_f64);
+    let exclusive_maximum = object.exclusive_maximum.as_ref().map(to_f64);
+    let step = if is_integer { 1.0 } else { 0.5 };
+
+    let lower = match (minimum, exclusive_minimum) {
+        (Some(minimum), Some(exclusive)) if exclusive >= minimum => Some(exclusive + step),
+        (Some(minimum), _) => Some(minimum),
+        (None, Some(exclusive)) => Some(exclusive + step),
+        (None, None) => None,
+    };
+    let upper = match (maximum, exclusive_maximum) {
+        (Some(maximum), Some(exclusive)) if exclusive <= maximum => Some(exclusive - step),
+        (Some(maximum), _) => Some(maximum),
+        (None, Some(exclusive)) => Some(exclusive - step),
+        (None, None) => None,
+    };
+
+    let mut example = match (lower, upper) {
+        (Some(lower), _) if lower > 0.0 => lower,
+        (_, Some(upper)) if upper < 0.0 => upper,
+        _ => 0.0,
+    };
+    if let Some(multiple_of) = object.multiple_of.as_ref().map(to_f64) {
+        if multiple_of > 0.0 {
+            example = if example < 0.0 {
+                (example / multiple_of).floor() * multiple_of
+            } else {
+                (example / multiple_of).ceil() * multiple_of
+            };
+        }
+    }
+
+    if is_integer || example.fract() == 0.0 {
+        Value::from(example.ceil() as i64)
+    } else {
+        Value::from(example)
+    }
+}
+
+fn to_f64(number: &Number) -> f64 {
+    match number {
+        Number::Int(value) => *value as f64,
+        Number::UInt(value) => *value as f64,
+        Number::Float(value) => *value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::schema::{AllOfBuilder, ArrayBuilder, OneOfBuilder};
+    use crate::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref};
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "name",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .examples(["Garfield"]),
+                            )
+                            .property(
+                                "kind",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .enum_values(Some(["cat", "dog"])),
+                            )
+                            .property(
+                                "children",
+                                ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                            )
+                            .property("owner", Ref::from_schema_name("Owner")),
+                    )
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new()
+                            .property(
+                                "email",
+                                ObjectBuilder::new()
+                                    .schema_type(Type::String)
+                                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Email))),
+                            )
+                            .property("pet", Ref::from_schema_name("Pet"))
+                            .required("pet"),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn generate_example_follows_references_and_stops_at_recursion() {
+        let api = api();
+
+        assert_eq!(
+            api.generate_example(&Ref::from_schema_name("Pet").into()),
+            json!({
+                "name": "Garfield",
+                "kind": "cat",
+                "children": [],
+                "owner": {
+                    "email": "user@example.com",
+                    "pet": null,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn generate_example_honors_constraints() {
+        let api = api();
+        let example = |schema: Schema| schema.generate_example(&api);
+
+        let integer = ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .exclusive_minimum(Some(10))
+            .multiple_of(Some(4));
+        assert_eq!(example(integer.into()), json!(12));
+
+        let number = ObjectBuilder::new()
+            .schema_type(Type::Number)
+            .maximum(Some(-1.5));
+        assert_eq!(example(number.into()), json!(-1.5));
+
+        let string = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .min_length(Some(8));
+        assert_eq!(example(string.into()), json!("stringaa"));
+
+        let pattern = ObjectBuilder::new()
+            .schema_type(Type::String)
+            .pattern(Some(r"^[A-Z]{2}-\d{3,}x?$"));
+        assert_eq!(example(pattern.into()), json!("AA-000x"));
+
+        let array = ArrayBuilder::new()
+            .items(ObjectBuilder::new().schema_type(Type::Boolean))
+            .min_items(Some(2));
+        assert_eq!(example(array.into()), json!([true, true]));
+    }
+
+    #[test]
+    fn generate_example_of_composite_schemas() {
+        let api = api();
+
+        let one_of: Schema = OneOfBuilder::new()
+            .item(Ref::from_schema_name("Missing"))
+            .item(ObjectBuilder::new().schema_type(Type::Boolean))
+            .into();
+        assert_eq!(one_of.generate_example(&api), json!(true));
+
+        let all_of: Schema = AllOfBuilder::new()
+            .item(
+                ObjectBuilder::new()
+                    .property("id", ObjectBuilder::new().schema_type(Type::Integer)),
+            )
+            .item(Ref::from_schema_name("Owner"))
+            .into();
+        assert_eq!(all_of.generate_example(&api)["id"], json!(0),);
+    }
+
+    #[test]
+    fn fill_missing_examples_of_components() {
+        let mut api = api();
+        if let Some(RefOr::T(Schema::Object(owner))) = api
+            .components
+            .as_mut()
+            .and_then(|components| components.schemas.get_mut("Owner"))
+        {
+            owner.examples = vec![json!({"pet": null})];
+        }
+
+        api.fill_missing_examples();
+
+        let components = api.components.expect("components must exist");
+        let examples = |name: &str| match &components.schemas[name] {
+            RefOr::T(Schema::Object(object)) => object.examples.clone(),
+            _ => Vec::new(),
+        };
+        assert_eq!(examples("Owner"), [json!({"pet": null})]);
+        assert_eq!(examples("Pet").len(), 1);
+        assert_eq!(examples("Pet")[0]["owner"], json!({"pet": null}));
+    }
+}
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{builder, set_value, Object, RefOr, Schema, Type};
+use super::{builder, set_value, Object, Ref, RefOr, Schema, Type};
 
 builder! {
     HeaderBuilder;
@@ -71,3 +71,15 @@ impl HeaderBuilder {
         set_value!(self description description.map(|description| description.into()))
     }
 }
+
+impl From<HeaderBuilder> for RefOr<Header> {
+    fn from(builder: HeaderBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Header> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
@@ -0,0 +1,1623 @@
+//! Implements validation of JSON instances against [`Schema`]s at runtime.
+//!
+//! Validation is performed with [`OpenApi::validate_instance`] or [`Schema::validate_instance`]
+//! which return list of [`InstanceError`]s found from the instance. Each error carries a
+//! [JSON Pointer][json_pointer] location of the offending value within the instance and a typed
+//! [`InstanceErrorKind`].
+//!
+//! Following keywords are validated: _`type`_, _`enum`_, _`const`_, _`required`_,
+//! _`properties`_, _`additionalProperties`_, _`patternProperties`_, _`propertyNames`_,
+//! _`minProperties`_, _`maxProperties`_, _`dependentRequired`_, _`dependentSchemas`_,
+//! _`minimum`_, _`maximum`_, _`exclusiveMinimum`_, _`exclusiveMaximum`_, _`multipleOf`_,
+//! _`minLength`_, _`maxLength`_, _`pattern`_, _`format`_ for [`KnownFormat`]s, _`items`_,
+//! _`prefixItems`_, _`minItems`_, _`maxItems`_, _`uniqueItems`_, _`contains`_,
+//! _`minContains`_, _`maxContains`_, _`allOf`_, _`oneOf`_, _`anyOf`_, _`not`_ and
+//! _`if`_/_`then`_/_`else`_. Local _`$ref`_s are resolved from the [`OpenApi`] components.
+//!
+//! [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::content::Content;
+use super::path::Parameter;
+use super::schema::{
+    AdditionalProperties, Array, ArrayItems, KnownFormat, Object, Schema, SchemaFormat, SchemaType,
+    Type,
+};
+use super::validation::escape_pointer_segment;
+use super::visit::{walk_content, walk_parameter, walk_schema, Location, Visit};
+use super::{OpenApi, RefOr};
+use crate::Number;
+
+/// Single error found by [`OpenApi::validate_instance`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct InstanceError {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the value within the
+    /// validated instance where the error was found. E.g. _`/pets/0/name`_.
+    pub location: String,
+
+    /// Kind of the error.
+    pub kind: InstanceErrorKind,
+}
+
+impl Display for InstanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+/// Kind of the [`InstanceError`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum InstanceErrorKind {
+    /// Value is not of the type defined by the schema.
+    InvalidType {
+        /// Type defined by the schema.
+        expected: SchemaType,
+    },
+    /// Value is not one of the `enum` values.
+    NotInEnum,
+    /// Value is not equal to the `const` value.
+    NotConst,
+    /// Object is missing a `required` property.
+    MissingRequiredProperty {
+        /// Name of the missing property.
+        property: String,
+    },
+    /// Object has a property not allowed by `additionalProperties`.
+    AdditionalProperty {
+        /// Name of the property.
+        property: String,
+    },
+    /// Object is missing a property required by `dependentRequired` when `property` is present.
+    MissingDependentProperty {
+        /// Name of the present property.
+        property: String,
+        /// Name of the missing property.
+        dependency: String,
+    },
+    /// Object has less properties than `minProperties`.
+    MinProperties {
+        /// Minimum number of properties.
+        min_properties: usize,
+    },
+    /// Object has more properties than `maxProperties`.
+    MaxProperties {
+        /// Maximum number of properties.
+        max_properties: usize,
+    },
+    /// Number is less than `minimum`.
+    Minimum {
+        /// The minimum value.
+        minimum: Number,
+    },
+    /// Number is greater than `maximum`.
+    Maximum {
+        /// The maximum value.
+        maximum: Number,
+    },
+    /// Number is less than or equal to `exclusiveMinimum`.
+    ExclusiveMinimum {
+        /// The exclusive minimum value.
+        exclusive_minimum: Number,
+    },
+    /// Number is greater than or equal to `exclusiveMaximum`.
+    ExclusiveMaximum {
+        /// The exclusive maximum value.
+        exclusive_maximum: Number,
+    },
+    /// Number is not a multiple of `multipleOf`.
+    MultipleOf {
+        /// The divisor.
+        multiple_of: Number,
+    },
+    /// String is shorter than `minLength` characters.
+    MinLength {
+        /// Minimum length of the string.
+        min_length: usize,
+    },
+    /// String is longer than `maxLength` characters.
+    MaxLength {
+        /// Maximum length of the string.
+        max_length: usize,
+    },
+    /// String does not match the `pattern`.
+    Pattern {
+        /// The regular expression.
+        pattern: String,
+    },
+    /// Schema `pattern` or `patternProperties` is not a valid regular expression.
+    InvalidPattern {
+        /// The invalid regular expression.
+        pattern: String,
+    },
+    /// Value does not conform to the [`KnownFormat`].
+    Format {
+        /// The format of the schema.
+        format: KnownFormat,
+    },
+    /// Array has less items than `minItems`.
+    MinItems {
+        /// Minimum number of items.
+        min_items: usize,
+    },
+    /// Array has more items than `maxItems`.
+    MaxItems {
+        /// Maximum number of items.
+        max_items: usize,
+    },
+    /// Array has duplicate items while `uniqueItems` is `true`.
+    UniqueItems,
+    /// Array has items beyond `prefixItems` while `items` is `false`.
+    AdditionalItems,
+    /// Array has less items matching `contains` than `minContains` which defaults to `1`.
+    MinContains {
+        /// Minimum number of matching items.
+        min_contains: usize,
+    },
+    /// Array has more items matching `contains` than `maxContains`.
+    MaxContains {
+        /// Maximum number of matching items.
+        max_contains: usize,
+    },
+    /// Value does not match exactly one of the `oneOf` schemas.
+    OneOf {
+        /// Number of matching schemas.
+        matches: usize,
+    },
+    /// Value does not match any of the `anyOf` schemas.
+    AnyOf,
+    /// Value matches the `not` schema.
+    Not,
+    /// Schema is boolean `false` schema which does not allow any value.
+    False,
+    /// Local `$ref` points to a schema that does not exist in components.
+    UnresolvedReference {
+        /// Reference location e.g. _`#/components/schemas/Pet`_.
+        reference: String,
+    },
+    /// Local `$ref` refers back to a schema already being validated against the same value
+    /// without consuming any of the value e.g. schema _`A`_ being _`allOf: [$ref: A]`_.
+    RecursiveReference {
+        /// Reference location e.g. _`#/components/schemas/Pet`_.
+        reference: String,
+    },
+}
+
+impl Display for InstanceErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidType { expected } => {
+                let expected = serde_json::to_string(expected).map_err(|_| std::fmt::Error)?;
+                write!(f, "value is not of type {expected}")
+            }
+            Self::NotInEnum => write!(f, "value is not one of the enum values"),
+            Self::NotConst => write!(f, "value is not equal to const value"),
+            Self::MissingRequiredProperty { property } => {
+                write!(f, "required property `{property}` is missing")
+            }
+            Self::AdditionalProperty { property } => {
+                write!(f, "additional property `{property}` is not allowed")
+            }
+            Self::MissingDependentProperty {
+                property,
+                dependency,
+            } => write!(
+                f,
+                "property `{dependency}` is required when `{property}` is present"
+            ),
+            Self::MinProperties { min_properties } => {
+                write!(f, "object has less than {min_properties} properties")
+            }
+            Self::MaxProperties { max_properties } => {
+                write!(f, "object has more than {max_properties} properties")
+            }
+            Self::Minimum { minimum } => write!(f, "number is less than {}", to_f64(minimum)),
+            Self::Maximum { maximum } => write!(f, "number is greater than {}", to_f64(maximum)),
+            Self::ExclusiveMinimum { exclusive_minimum } => write!(
+                f,
+                "number is less than or equal to {}",
+                to_f64(exclusive_minimum)
+            ),
+            Self::ExclusiveMaximum { exclusive_maximum } => write!(
+                f,
+                "number is greater than or equal to {}",
+                to_f64(exclusive_maximum)
+            ),
+            Self::MultipleOf { multiple_of } => {
+                write!(f, "number is not a multiple of {}", to_f64(multiple_of))
+            }
+            Self::MinLength { min_length } => {
+                write!(f, "string is shorter than {min_length} characters")
+            }
+            Self::MaxLength { max_length } => {
+                write!(f, "string is longer than {max_length} characters")
+            }
+            Self::Pattern { pattern } => write!(f, "string does not match pattern `{pattern}`"),
+            Self::InvalidPattern { pattern } => {
+                write!(f, "pattern `{pattern}` is not a valid regular expression")
+            }
+            Self::Format { format } => {
+                let format = serde_json::to_string(format).map_err(|_| std::fmt::Error)?;
+                write!(f, "value is not valid {format} format")
+            }
+            Self::MinItems { min_items } => write!(f, "array has less than {min_items} items"),
+            Self::MaxItems { max_items } => write!(f, "array has more than {max_items} items"),
+            Self::UniqueItems => write!(f, "array items are not unique"),
+            Self::AdditionalItems => write!(f, "array has more items than prefixItems allow"),
+            Self::MinContains { min_contains } => write!(
+                f,
+                "array has less than {min_contains} items matching contains"
+            ),
+            Self::MaxContains { max_contains } => write!(
+                f,
+                "array has more than {max_contains} items matching contains"
+            ),
+            Self::OneOf { matches } => write!(
+                f,
+                "value matches {matches} oneOf schemas instead of exactly one"
+            ),
+            Self::AnyOf => write!(f, "value does not match any of the anyOf schemas"),
+            Self::Not => write!(f, "value matches the not schema"),
+            Self::False => write!(f, "no value is allowed"),
+            Self::UnresolvedReference { reference } => {
+                write!(f, "reference `{reference}` does not resolve to any schema")
+            }
+            Self::RecursiveReference { reference } => {
+                write!(
+                    f,
+                    "reference `{reference}` recurses without consuming the value"
+                )
+            }
+        }
+    }
+}
+
+impl OpenApi {
+    /// Validate JSON `instance` against the `schema` and return all found [`InstanceError`]s.
+    ///
+    /// Local schema references of the `schema` are resolved from the [`OpenApi::components`] of
+    /// this [`OpenApi`]. See the [module level documentation][self] for supported keywords.
+    ///
+    /// # Examples
+    ///
+    /// _**Validate response body against response schema.**_
+    /// ```rust
+    /// # use serde_json::json;
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref, Type};
+    /// # use utoipa::openapi::instance_validation::InstanceErrorKind;
+    /// let api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema(
+    ///                 "Pet",
+    ///                 ObjectBuilder::new()
+    ///                     .property("name", ObjectBuilder::new().schema_type(Type::String))
+    ///                     .required("name"),
+    ///             )
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let errors = api
+    ///     .validate_instance(&Ref::from_schema_name("Pet").into(), &json!({"name": 1}))
+    ///     .unwrap_err();
+    /// assert_eq!(errors[0].location, "/name");
+    /// assert!(matches!(errors[0].kind, InstanceErrorKind::InvalidType { .. }));
+    /// ```
+    pub fn validate_instance(
+        &self,
+        schema: &RefOr<Schema>,
+        instance: &Value,
+    ) -> Result<(), Vec<InstanceError>> {
+        let mut validator = InstanceValidator::new(self);
+        validator.validate_ref_or_schema(schema, instance);
+        validator.take_result()
+    }
+}
+
+impl Schema {
+    /// Validate JSON `instance` against this [`Schema`] resolving local schema references from
+    /// given `openapi`. See [`OpenApi::validate_instance`] for more details.
+    pub fn validate_instance(
+        &self,
+        instance: &Value,
+        openapi: &OpenApi,
+    ) -> Result<(), Vec<InstanceError>> {
+        let mut validator = InstanceValidator::new(openapi);
+        validator.validate_schema(self, instance);
+        validator.take_result()
+    }
+}
+
+/// Single mismatch between declared example and its schema found by
+/// [`OpenApi::validate_examples`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ExampleError {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the example within the
+    /// [`OpenApi`] document. E.g. _`/components/schemas/Pet/examples/0`_.
+    pub location: String,
+
+    /// Error found from the example value.
+    pub error: InstanceError,
+}
+
+impl Display for ExampleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.error.location.is_empty() {
+            write!(f, "{}: {}", self.location, self.error.kind)
+        } else {
+            write!(f, "{}: {}", self.location, self.error)
+        }
+    }
+}
+
+impl OpenApi {
+    /// Validate every declared example of this [`OpenApi`] against its schema and return all
+    /// found [`ExampleError`]s.
+    ///
+    /// Following examples are validated:
+    /// * _`example`_ and _`examples`_ of every [`Schema`] against the schema itself.
+    /// * _`example`_ of [`Parameter`]s against the [`Parameter::schema`].
+    /// * _`example`_ and _`value`_ of _`examples`_ of [`Content`] against the
+    ///   [`Content::schema`]. Referenced [`Example`][example]s are resolved from
+    ///   [`OpenApi::components`].
+    ///
+    /// Examples defined only as _`externalValue`_ are not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serde_json::json;
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Type};
+    /// let api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema(
+    ///                 "Pet",
+    ///                 ObjectBuilder::new()
+    ///                     .property("name", ObjectBuilder::new().schema_type(Type::String))
+    ///                     .required("name")
+    ///                     .examples([json!({"nickname": "Garfield"})]),
+    ///             )
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let errors = api.validate_examples();
+    /// assert_eq!(errors[0].location, "/components/schemas/Pet/examples/0");
+    /// ```
+    ///
+    /// [example]: super::example::Example
+    pub fn validate_examples(&self) -> Vec<ExampleError> {
+        let mut validator = ExampleValidator {
+            validator: InstanceValidator::new(self),
+            errors: Vec::new(),
+        };
+        validator.visit_openapi(self, &mut Location::new());
+
+        validator.errors
+    }
+}
+
+struct ExampleValidator<'a> {
+    validator: InstanceValidator<'a>,
+    errors: Vec<ExampleError>,
+}
+
+impl ExampleValidator<'_> {
+    fn validate(
+        &mut self,
+        location: &mut Location,
+        segments: &[&str],
+        schema: &RefOr<Schema>,
+        example: &Value,
+    ) {
+        self.validator.validate_ref_or_schema(schema, example);
+        self.push_errors(location, segments);
+    }
+
+    /// Move errors of the last validated example to the found errors of the example at
+    /// `segments` relative to `location`.
+    fn push_errors(&mut self, location: &mut Location, segments: &[&str]) {
+        if let Err(errors) = self.validator.take_result() {
+            for segment in segments {
+                location.push(segment);
+            }
+            let pointer = location.pointer();
+            for _ in segments {
+                location.pop();
+            }
+
+            self.errors
+                .extend(errors.into_iter().map(|error| ExampleError {
+                    location: pointer.clone(),
+                    error,
+                }));
+        }
+    }
+}
+
+impl<'a> Visit<'a> for ExampleValidator<'a> {
+    fn visit_parameter(&mut self, parameter: &'a Parameter, location: &mut Location) {
+        if let (Some(schema), Some(example)) = (&parameter.schema, &parameter.example) {
+            self.validate(location, &["example"], schema, example);
+        }
+        walk_parameter(self, parameter, location);
+    }
+
+    fn visit_content(&mut self, content: &'a Content, location: &mut Location) {
+        if let Some(schema) = &content.schema {
+            if let Some(example) = &content.example {
+                self.validate(location, &["example"], schema, example);
+            }
+            for (name, example) in &content.examples {
+                let example = match example {
+                    RefOr::T(example) => Some(example),
+                    RefOr::Ref(reference) => self.validator.openapi.resolve_example(reference),
+                };
+                if let Some(value) = example.and_then(|example| example.value.as_ref()) {
+                    self.validate(
+                        location,
+                        &["examples", name.as_str(), "value"],
+                        schema,
+                        value,
+                    );
+                }
+            }
+        }
+        walk_content(self, content, location);
+    }
+
+    fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+        let (example, examples) = match schema {
+            Schema::Object(object) => (&object.example, &object.examples),
+            Schema::Array(array) => (&array.example, &array.examples),
+            Schema::OneOf(one_of) => (&one_of.example, &one_of.examples),
+            Schema::AllOf(all_of) => (&all_of.example, &all_of.examples),
+            Schema::AnyOf(any_of) => (&any_of.example, &any_of.examples),
+            _ => (&None, &Vec::new()),
+        };
+
+        if let Some(example) = example {
+            self.validator.validate_schema(schema, example);
+            self.push_errors(location, &["example"]);
+        }
+        for (index, example) in examples.iter().enumerate() {
+            self.validator.validate_schema(schema, example);
+            self.push_errors(location, &["examples", &index.to_string()]);
+        }
+        walk_schema(self, schema, location);
+    }
+}
+
+struct InstanceValidator<'a> {
+    openapi: &'a OpenApi,
+    location: Vec<String>,
+    errors: Vec<InstanceError>,
+    regexes: HashMap<String, Option<Regex>>,
+    /// References currently being validated with the location of the validated value used to
+    /// stop at recursion.
+    stack: Vec<(String, Vec<String>)>,
+}
+
+impl<'a> InstanceValidator<'a> {
+    fn new(openapi: &'a OpenApi) -> Self {
+        Self {
+            openapi,
+            location: Vec::new(),
+            errors: Vec::new(),
+            regexes: HashMap::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Take errors found since the last call leaving the validator ready for next instance.
+    fn take_result(&mut self) -> Result<(), Vec<InstanceError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.errors))
+        }
+    }
+
+    fn validate_ref_or_schema(&mut self, schema: &RefOr<Schema>, instance: &Value) {
+        match schema {
+            RefOr::T(schema) => self.validate_schema(schema, instance),
+            RefOr::Ref(reference) => {
+                let Some(schema) = self.openapi.resolve_schema(reference) else {
+                    return self.error(InstanceErrorKind::UnresolvedReference {
+                        reference: reference.ref_location.clone(),
+                    });
+                };
+
+                let frame = (reference.ref_location.clone(), self.location.clone());
+                if self.stack.contains(&frame) {
+                    return self.error(InstanceErrorKind::RecursiveReference {
+                        reference: reference.ref_location.clone(),
+                    });
+                }
+
+                self.stack.push(frame);
+                self.validate_schema(schema, instance);
+                self.stack.pop();
+            }
+        }
+    }
+
+    fn validate_schema(&mut self, schema: &Schema, instance: &Value) {
+        match schema {
+            Schema::Object(object) => self.validate_object(object, instance),
+            Schema::Array(array) => self.validate_array(array, instance),
+            Schema::OneOf(one_of) => {
+                if self.validate_type(&one_of.schema_type, instance) {
+                    let matches = one_of
+                        .items
+                        .iter()
+                        .filter(|item| self.is_valid(item, instance))
+                        .count();
+                    if matches != 1 {
+                        self.error(InstanceErrorKind::OneOf { matches });
+                    }
+                }
+            }
+            Schema::AllOf(all_of) => {
+                if self.validate_type(&all_of.schema_type, instance) {
+                    for item in &all_of.items {
+                        self.validate_ref_or_schema(item, instance);
+                    }
+                }
+            }
+            Schema::AnyOf(any_of) => {
+                if self.validate_type(&any_of.schema_type, instance)
+                    && !any_of
+                        .items
+                        .iter()
+                        .any(|item| self.is_valid(item, instance))
+                {
+                    self.error(InstanceErrorKind::AnyOf);
+                }
+            }
+            Schema::Bool(true) => (),
+            Schema::Bool(false) => self.error(InstanceErrorKind::False),
+        }
+    }
+
+    fn validate_object(&mut self, object: &Object, instance: &Value) {
+        if !self.validate_type(&object.schema_type, instance) {
+            return;
+        }
+
+        if let Some(enum_values) = &object.enum_values {
+            if !enum_values.contains(instance) {
+                self.error(InstanceErrorKind::NotInEnum);
+            }
+        }
+        if let Some(const_value) = &object.const_value {
+            if const_value != instance {
+                self.error(InstanceErrorKind::NotConst);
+            }
+        }
+        if let Some(SchemaFormat::KnownFormat(format)) = &object.format {
+            if !self.is_valid_format(format, instance) {
+                self.error(InstanceErrorKind::Format {
+                    format: format.clone(),
+                });
+            }
+        }
+
+        match instance {
+            Value::Object(map) => {
+                for property in &object.required {
+                    if !map.contains_key(property) {
+                        self.error(InstanceErrorKind::MissingRequiredProperty {
+                            property: property.clone(),
+                        });
+                    }
+                }
+                for (property, dependencies) in &object.dependent_required {
+                    if map.contains_key(property) {
+                        for dependency in dependencies {
+                            if !map.contains_key(dependency) {
+                                self.error(InstanceErrorKind::MissingDependentProperty {
+                                    property: property.clone(),
+                                    dependency: dependency.clone(),
+                                });
+                            }
+                        }
+                    }
+                }
+                if let Some(min_properties) = object.min_properties {
+                    if map.len() < min_properties {
+                        self.error(InstanceErrorKind::MinProperties { min_properties });
+                    }
+                }
+                if let Some(max_properties) = object.max_properties {
+                    if map.len() > max_properties {
+                        self.error(InstanceErrorKind::MaxProperties { max_properties });
+                    }
+                }
+
+                for (name, value) in map {
+                    if let Some(property_names) = &object.property_names {
+                        self.validate_schema(property_names, &Value::String(name.clone()));
+                    }
+
+                    let mut is_evaluated = false;
+                    if let Some(property) = object.properties.get(name) {
+                        is_evaluated = true;
+                        self.with(name, |this| this.validate_ref_or_schema(property, value));
+                    }
+                    for (pattern, property) in &object.pattern_properties {
+                        if self.is_match(pattern, name) {
+                            is_evaluated = true;
+                            self.with(name, |this| this.validate_ref_or_schema(property, value));
+                        }
+                    }
+                    if !is_evaluated {
+                        match object.additional_properties.as_deref() {
+                            Some(AdditionalProperties::FreeForm(false)) => {
+                                self.with(name, |this| {
+                                    this.error(InstanceErrorKind::AdditionalProperty {
+                                        property: name.clone(),
+                                    })
+                                })
+                            }
+                            Some(AdditionalProperties::RefOr(schema)) => {
+                                self.with(name, |this| this.validate_ref_or_schema(schema, value))
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                for (property, schema) in &object.dependent_schemas {
+                    if map.contains_key(property) {
+                        self.validate_ref_or_schema(schema, instance);
+                    }
+                }
+            }
+            Value::Number(number) => {
+                let value = number.as_f64().unwrap_or_default();
+                if let Some(minimum) = &object.minimum {
+                    if value < to_f64(minimum) {
+                        self.error(InstanceErrorKind::Minimum {
+                            minimum: minimum.clone(),
+                        });
+                    }
+                }
+                if let Some(maximum) = &object.maximum {
+                    if value > to_f64(maximum) {
+                        self.error(InstanceErrorKind::Maximum {
+                            maximum: maximum.clone(),
+                        });
+                    }
+                }
+                if let Some(exclusive_minimum) = &object.exclusive_minimum {
+                    if value <= to_f64(exclusive_minimum) {
+                        self.error(InstanceErrorKind::ExclusiveMinimum {
+                            exclusive_minimum: exclusive_minimum.clone(),
+                        });
+                    }
+                }
+                if let Some(exclusive_maximum) = &object.exclusive_maximum {
+                    if value >= to_f64(exclusive_maximum) {
+                        self.error(InstanceErrorKind::ExclusiveMaximum {
+                            exclusive_maximum: exclusive_maximum.clone(),
+                        });
+                    }
+                }
+                if let Some(multiple_of) = &object.multiple_of {
+                    if !is_multiple_of(number, multiple_of) {
+                        self.error(InstanceErrorKind::MultipleOf {
+                            multiple_of: multiple_of.clone(),
+                        });
+                    }
+                }
+            }
+            Value::String(string) => {
+                let length = string.chars().count();
+                if let Some(min_length) = object.min_length {
+                    if length < min_length {
+                        self.error(InstanceErrorKind::MinLength { min_length });
+                    }
+                }
+                if let Some(max_length) = object.max_length {
+                    if length > max_length {
+                        self.error(InstanceErrorKind::MaxLength { max_length });
+                    }
+                }
+                if let Some(pattern) = &object.pattern {
+                    if !self.is_match(pattern, string) {
+                        self.error(InstanceErrorKind::Pattern {
+                            pattern: pattern.clone(),
+                        });
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        if let Some(not) = &object.not {
+            if self.is_valid(not, instance) {
+                self.error(InstanceErrorKind::Not);
+            }
+        }
+        if let Some(if_schema) = &object.if_schema {
+            let branch = if self.is_valid(if_schema, instance) {
+                &object.then_schema
+            } else {
+                &object.else_schema
+            };
+            if let Some(branch) = branch {
+                self.validate_ref_or_schema(branch, instance);
+            }
+        }
+    }
+
+    fn validate_array(&mut self, array: &Array, instance: &Value) {
+        if !self.validate_type(&array.schema_type, instance) {
+            return;
+        }
+        let Value::Array(items) = instance else {
+            return;
+        };
+
+        if let Some(min_items) = array.min_items {
+            if items.len() < min_items {
+                self.error(InstanceErrorKind::MinItems { min_items });
+            }
+        }
+        if let Some(max_items) = array.max_items {
+            if items.len() > max_items {
+                self.error(InstanceErrorKind::MaxItems { max_items });
+            }
+        }
+        if array.unique_items
+            && items
+                .iter()
+                .enumerate()
+                .any(|(index, item)| items[..index].contains(item))
+        {
+            self.error(InstanceErrorKind::UniqueItems);
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            match array.prefix_items.get(index) {
+                Some(prefix_item) => {
+                    self.with(index, |this| this.validate_schema(prefix_item, item))
+                }
+                None => match &array.items {
+                    ArrayItems::RefOrSchema(schema) => {
+                        self.with(index, |this| this.validate_ref_or_schema(schema, item))
+                    }
+                    ArrayItems::False => {
+                        self.error(InstanceErrorKind::AdditionalItems);
+                        break;
+                    }
+                },
+            }
+        }
+
+        if let Some(contains) = &array.contains {
+            let matches = items
+                .iter()
+                .filter(|item| self.is_valid(contains, item))
+                .count();
+            let min_contains = array.min_contains.unwrap_or(1);
+            if matches < min_contains {
+                self.error(InstanceErrorKind::MinContains { min_contains });
+            }
+            if let Some(max_contains) = array.max_contains {
+                if matches > max_contains {
+                    self.error(InstanceErrorKind::MaxContains { max_contains });
+                }
+            }
+        }
+    }
+
+    /// Validate type of the `instance` and return `true` if it matches the schema type.
+    fn validate_type(&mut self, schema_type: &SchemaType, instance: &Value) -> bool {
+        let is_type = |schema_type: &Type| match schema_type {
+            Type::Object => instance.is_object(),
+            Type::String => instance.is_string(),
+            Type::Integer => instance
+                .as_f64()
+                .is_some_and(|number| number.fract() == 0.0),
+            Type::Number => instance.is_number(),
+            Type::Boolean => instance.is_boolean(),
+            Type::Array => instance.is_array(),
+            Type::Null => instance.is_null(),
+        };
+        let is_valid = match schema_type {
+            SchemaType::Type(schema_type) => is_type(schema_type),
+            SchemaType::Array(schema_types) => schema_types.iter().any(is_type),
+            SchemaType::AnyValue => true,
+        };
+
+        if !is_valid {
+            self.error(InstanceErrorKind::InvalidType {
+                expected: schema_type.clone(),
+            });
+        }
+        is_valid
+    }
+
+    /// Check whether `instance` is valid against the `schema` without recording errors.
+    fn is_valid(&mut self, schema: &RefOr<Schema>, instance: &Value) -> bool {
+        let errors = self.errors.len();
+        self.validate_ref_or_schema(schema, instance);
+        let is_valid = self.errors.len() == errors;
+        self.errors.truncate(errors);
+
+        is_valid
+    }
+
+    /// Check whether `value` matches the `pattern`. Invalid pattern is reported as
+    /// [`InstanceErrorKind::InvalidPattern`] and never matches.
+    fn is_match(&mut self, pattern: &str, value: &str) -> bool {
+        let regex = self
+            .regexes
+            .entry(pattern.to_string())
+            .or_insert_with(|| Regex::new(pattern).ok());
+
+        match regex {
+            Some(regex) => regex.is_match(value),
+            None => {
+                self.error(InstanceErrorKind::InvalidPattern {
+                    pattern: pattern.to_string(),
+                });
+                false
+            }
+        }
+    }
+
+    fn is_valid_format(&mut self, format: &KnownFormat, instance: &Value) -> bool {
+        if let Value::Number(number) = instance {
+            let in_range = |min: f64, max: f64| {
+                number
+                    .as_f64()
+                    .is_some_and(|number| number.fract() == 0.0 && number >= min && number <= max)
+            };
+
+            return match format {
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::Int8 => in_range(i8::MIN as f64, i8::MAX as f64),
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::Int16 => in_range(i16::MIN as f64, i16::MAX as f64),
+                KnownFormat::Int32 => in_range(i32::MIN as f64, i32::MAX as f64),
+                KnownFormat::Int64 => number.is_i64() || in_range(i64::MIN as f64, i64::MAX as f64),
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::UInt8 => in_range(0.0, u8::MAX as f64),
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::UInt16 => in_range(0.0, u16::MAX as f64),
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::UInt32 => in_range(0.0, u32::MAX as f64),
+                #[cfg(feature = "non_strict_integers")]
+                KnownFormat::UInt64 => number.is_u64() || in_range(0.0, u64::MAX as f64),
+                _ => true,
+            };
+        }
+        let Value::String(value) = instance else {
+            return true;
+        };
+
+        match format {
+            KnownFormat::Byte => is_base64(value),
+            KnownFormat::Date => is_date(value),
+            KnownFormat::DateTime => is_date_time(value),
+            KnownFormat::Time => is_time(value),
+            KnownFormat::Duration => is_duration(value),
+            KnownFormat::Email => is_email(value, false),
+            KnownFormat::IdnEmail => is_email(value, true),
+            KnownFormat::Hostname => is_hostname(value, false),
+            KnownFormat::IdnHostname => is_hostname(value, true),
+            KnownFormat::Ipv4 => value.parse::<Ipv4Addr>().is_ok(),
+            KnownFormat::Ipv6 => value.parse::<Ipv6Addr>().is_ok(),
+            #[cfg(feature = "uuid")]
+            KnownFormat::Uuid => is_uuid(value),
+            #[cfg(feature = "ulid")]
+            KnownFormat::Ulid => is_ulid(value),
+            #[cfg(feature = "url")]
+            KnownFormat::Uri => is_uri(value, false),
+            #[cfg(feature = "url")]
+            KnownFormat::Iri => is_uri(value, true),
+            #[cfg(feature = "url")]
+            KnownFormat::UriReference => is_uri_reference(value, false),
+            #[cfg(feature = "url")]
+            KnownFormat::IriReference => is_uri_reference(value, true),
+            KnownFormat::UriTemplate => is_uri_template(value),
+            KnownFormat::JsonPointer => is_json_pointer(value),
+            KnownFormat::RelativeJsonPointer => is_relative_json_pointer(value),
+            KnownFormat::Regex => Regex::new(value).is_ok(),
+            _ => true,
+        }
+    }
+
+    fn with<S: ToString>(&mut self, segment: S, f: impl FnOnce(&mut Self)) {
+        self.location.push(segment.to_string());
+        f(self);
+        self.location.pop();
+    }
+
+    fn error(&mut self, kind: InstanceErrorKind) {
+        self.errors.push(InstanceError {
+            location: self
+                .location
+                .iter()
+                .map(|segment| format!("/{}", escape_pointer_segment(segment)))
+                .collect(),
+            kind,
+        });
+    }
+}
+
+fn to_f64(number: &Number) -> f64 {
+    match number {
+        Number::Int(value) => *value as f64,
+        Number::UInt(value) => *value as f64,
+        Number::Float(value) => *value,
+    }
+}
+
+/// Check whether `value` is a multiple of `multiple_of`. Integers are compared exactly and
+/// floating point numbers with tolerance relative to the quotient, e.g. _`0.3 / 0.1`_ is
+/// _`2.9999999999999996`_ in floating point arithmetic.
+fn is_multiple_of(value: &serde_json::Number, multiple_of: &Number) -> bool {
+    let integer = value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from));
+    let divisor = match multiple_of {
+        Number::Int(divisor) => Some(*divisor as i128),
+        Number::UInt(divisor) => Some(*divisor as i128),
+        Number::Float(divisor) if divisor.fract() == 0.0 && divisor.abs() < u64::MAX as f64 => {
+            Some(*divisor as i128)
+        }
+        Number::Float(_) => None,
+    };
+    if let (Some(integer), Some(divisor)) = (integer, divisor) {
+        return divisor == 0 || integer % divisor == 0;
+    }
+
+    let divisor = to_f64(multiple_of);
+    if divisor == 0.0 {
+        return true;
+    }
+    let quotient = value.as_f64().unwrap_or_default() / divisor;
+
+    (quotient - quotient.round()).abs() <= 4.0 * f64::EPSILON * quotient.abs().max(1.0)
+}
+
+/// Parse fixed width decimal number of `digits` from the start of `value`.
+fn parse_digits(value: &str, digits: usize) -> Option<u32> {
+    let digits = value.get(..digits)?;
+    if digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn is_base64(value: &str) -> bool {
+    let data = value.trim_end_matches('=');
+    value.len() % 4 == 0
+        && value.len() - data.len() <= 2
+        && data
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/')
+}
+
+/// Check _`full-date`_ of RFC 3339 e.g. _`2024-02-29`_.
+fn is_date(value: &str) -> bool {
+    // date consists of ASCII only thus the byte offsets below are always char boundaries
+    if !value.is_ascii() {
+        return false;
+    }
+    let (Some(year), Some(month), Some(day)) = (
+        parse_digits(value, 4),
+        value.get(5..).and_then(|value| parse_digits(value, 2)),
+        value.get(8..).and_then(|value| parse_digits(value, 2)),
+    ) else {
+        return false;
+    };
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    value.len() == 10
+        && &value[4..5] == "-"
+        && &value[7..8] == "-"
+        && (1..=days_in_month).contains(&day)
+}
+
+/// Check _`full-time`_ of RFC 3339 e.g. _`10:20:30.123Z`_ or _`10:20:30+02:00`_.
+fn is_time(value: &str) -> bool {
+    // time consists of ASCII only thus the byte offsets below are always char boundaries
+    if !value.is_ascii() {
+        return false;
+    }
+    let is_partial_time = |value: &str| {
+        let (Some(hour), Some(minute), Some(second)) = (
+            parse_digits(value, 2),
+            value.get(3..).and_then(|value| parse_digits(value, 2)),
+            value.get(6..).and_then(|value| parse_digits(value, 2)),
+        ) else {
+            return false;
+        };
+        let fraction = &value[8..];
+        let is_fraction = fraction.is_empty()
+            || fraction.strip_prefix('.').is_some_and(|digits| {
+                !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+            });
+
+        &value[2..3] == ":"
+            && &value[5..6] == ":"
+            && hour < 24
+            && minute < 60
+            && second <= 60
+            && is_fraction
+    };
+
+    if let Some(partial_time) = value.strip_suffix('Z').or_else(|| value.strip_suffix('z')) {
+        return is_partial_time(partial_time);
+    }
+
+    let Some(offset_start) = value.len().checked_sub(6) else {
+        return false;
+    };
+    let (partial_time, offset) = value.split_at(offset_start);
+    let is_offset = matches!(&offset[..1], "+" | "-")
+        && &offset[3..4] == ":"
+        && parse_digits(&offset[1..], 2).is_some_and(|hour| hour < 24)
+        && parse_digits(&offset[4..], 2).is_some_and(|minute| minute < 60);
+
+    is_offset && is_partial_time(partial_time)
+}
+
+/// Check _`date-time`_ of RFC 3339 e.g. _`2024-02-29T10:20:30Z`_.
+fn is_date_time(value: &str) -> bool {
+    value.len() > 11
+        && matches!(value.get(10..11), Some("T" | "t"))
+        && is_date(&value[..10])
+        && is_time(&value[11..])
+}
+
+/// Check ISO 8601 duration e.g. _`P1DT12H`_ or _`P2W`_.
+fn is_duration(value: &str) -> bool {
+    let Some(value) = value.strip_prefix('P') else {
+        return false;
+    };
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let is_components = |value: &str, designators: &str| {
+        let mut designators = designators.chars();
+        let mut digits = 0;
+        for char in value.chars() {
+            if char.is_ascii_digit() {
+                digits += 1;
+            } else if digits == 0 || !designators.any(|designator| designator == char) {
+                return false;
+            } else {
+                digits = 0;
+            }
+        }
+        digits == 0
+    };
+
+    let is_time = time.map_or(true, |time| !time.is_empty() && is_components(time, "HMS"));
+    let is_date = if date.ends_with('W') {
+        is_components(date, "W") && time.is_none()
+    } else {
+        is_components(date, "YMD")
+    };
+
+    (!date.is_empty() || time.is_some()) && is_date && is_time
+}
+
+fn is_email(value: &str, is_idn: bool) -> bool {
+    let Some((local, domain)) = value.rsplit_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !local.chars().any(char::is_whitespace)
+        && (is_idn || local.is_ascii())
+        && is_hostname(domain, is_idn)
+}
+
+fn is_hostname(value: &str, is_idn: bool) -> bool {
+    value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|char| {
+                    char.is_ascii_alphanumeric() || char == '-' || (is_idn && !char.is_ascii())
+                })
+        })
+}
+
+#[cfg(feature = "uuid")]
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(index, char)| match index {
+            8 | 13 | 18 | 23 => char == '-',
+            _ => char.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(feature = "ulid")]
+fn is_ulid(value: &str) -> bool {
+    value.len() == 26
+        && value.starts_with(|char: char| ('0'..='7').contains(&char))
+        && value.chars().all(|char| {
+            char.is_ascii_alphanumeric()
+                && !matches!(char.to_ascii_uppercase(), 'I' | 'L' | 'O' | 'U')
+        })
+}
+
+#[cfg(feature = "url")]
+fn is_uri(value: &str, is_iri: bool) -> bool {
+    let Some((scheme, _)) = value.split_once(':') else {
+        return false;
+    };
+
+    scheme.starts_with(|char: char| char.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '+' | '-' | '.'))
+        && is_uri_reference(value, is_iri)
+}
+
+#[cfg(feature = "url")]
+fn is_uri_reference(value: &str, is_iri: bool) -> bool {
+    value
+        .chars()
+        .all(|char| !char.is_whitespace() && !char.is_control() && (is_iri || char.is_ascii()))
+}
+
+fn is_uri_template(value: &str) -> bool {
+    let mut is_expression = false;
+    for char in value.chars() {
+        match char {
+            '{' if !is_expression => is_expression = true,
+            '}' if is_expression => is_expression = false,
+            '{' | '}' => return false,
+            _ => (),
+        }
+    }
+    !is_expression
+}
+
+fn is_json_pointer(value: &str) -> bool {
+    (value.is_empty() || value.starts_with('/'))
+        && value
+            .split('~')
+            .skip(1)
+            .all(|escaped| escaped.starts_with(['0', '1']))
+}
+
+fn is_relative_json_pointer(value: &str) -> bool {
+    let pointer = value.trim_start_matches(|char: char| char.is_ascii_digit());
+    let prefix = &value[..value.len() - pointer.len()];
+
+    !prefix.is_empty()
+        && (prefix == "0" || !prefix.starts_with('0'))
+        && (pointer == "#" || is_json_pointer(pointer))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::schema::{AllOfBuilder, ArrayBuilder, OneOfBuilder};
+    use crate::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref};
+
+    use super::*;
+
+    /// Get errors sorted by location so that the order does not depend on `preserve_order`
+    /// features.
+    fn errors(
+        api: &OpenApi,
+        schema: impl Into<RefOr<Schema>>,
+        instance: Value,
+    ) -> Vec<(String, InstanceErrorKind)> {
+        let mut errors = api
+            .validate_instance(&schema.into(), &instance)
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|error| (error.location, error.kind))
+            .collect::<Vec<_>>();
+        errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        errors
+    }
+
+    fn pet() -> ObjectBuilder {
+        ObjectBuilder::new()
+            .property(
+                "name",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .min_length(Some(1))
+                    .max_length(Some(5))
+                    .pattern(Some("^[a-z]+$")),
+            )
+            .required("name")
+            .property(
+                "age",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .minimum(Some(0))
+                    .maximum(Some(30))
+                    .multiple_of(Some(2)),
+            )
+            .property(
+                "kind",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .enum_values(Some(["cat", "dog"])),
+            )
+            .property(
+                "born",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .format(Some(SchemaFormat::KnownFormat(KnownFormat::Date))),
+            )
+            .property(
+                "tags",
+                ArrayBuilder::new()
+                    .items(ObjectBuilder::new().schema_type(Type::String))
+                    .min_items(Some(1))
+                    .max_items(Some(2))
+                    .unique_items(true),
+            )
+            .additional_properties(Some(AdditionalProperties::FreeForm(false)))
+    }
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .components(Some(ComponentsBuilder::new().schema("Pet", pet()).build()))
+            .build()
+    }
+
+    #[test]
+    fn validate_valid_instance() {
+        let api = api();
+
+        let instance = json!({
+            "name": "cat",
+            "age": 4,
+            "kind": "cat",
+            "born": "2024-02-29",
+            "tags": ["a", "b"]
+        });
+
+        assert_eq!(errors(&api, Ref::from_schema_name("Pet"), instance), []);
+    }
+
+    #[test]
+    fn validate_object_keywords() {
+        let api = api();
+
+        let instance = json!({
+            "name": "Garfield",
+            "age": 31.5,
+            "kind": "fish",
+            "born": "2023-02-29",
+            "tags": ["a", "a", "b"],
+            "owner": null
+        });
+
+        assert_eq!(
+            errors(&api, Ref::from_schema_name("Pet"), instance),
+            [
+                (
+                    "/age".to_string(),
+                    InstanceErrorKind::InvalidType {
+                        expected: Type::Integer.into()
+                    }
+                ),
+                (
+                    "/born".to_string(),
+                    InstanceErrorKind::Format {
+                        format: KnownFormat::Date
+                    }
+                ),
+                ("/kind".to_string(), InstanceErrorKind::NotInEnum),
+                (
+                    "/name".to_string(),
+                    InstanceErrorKind::MaxLength { max_length: 5 }
+                ),
+                (
+                    "/name".to_string(),
+                    InstanceErrorKind::Pattern {
+                        pattern: "^[a-z]+$".to_string()
+                    }
+                ),
+                (
+                    "/owner".to_string(),
+                    InstanceErrorKind::AdditionalProperty {
+                        property: "owner".to_string()
+                    }
+                ),
+                (
+                    "/tags".to_string(),
+                    InstanceErrorKind::MaxItems { max_items: 2 }
+                ),
+                ("/tags".to_string(), InstanceErrorKind::UniqueItems),
+            ]
+        );
+        assert_eq!(
+            errors(&api, Ref::from_schema_name("Pet"), json!({"age": 3})),
+            [
+                (
+                    String::new(),
+                    InstanceErrorKind::MissingRequiredProperty {
+                        property: "name".to_string()
+                    }
+                ),
+                (
+                    "/age".to_string(),
+                    InstanceErrorKind::MultipleOf {
+                        multiple_of: Number::Int(2)
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_multiple_of_decimals() {
+        let api = api();
+        let number = |multiple_of: f64| {
+            ObjectBuilder::new()
+                .schema_type(Type::Number)
+                .multiple_of(Some(multiple_of))
+        };
+
+        assert_eq!(errors(&api, number(0.1), json!(0.3)), []);
+        assert_eq!(errors(&api, number(0.01), json!(19.99)), []);
+        assert_eq!(errors(&api, number(0.5), json!(1e20)), []);
+        assert_eq!(
+            errors(&api, number(0.1), json!(0.35)),
+            [(
+                String::new(),
+                InstanceErrorKind::MultipleOf {
+                    multiple_of: Number::Float(0.1)
+                }
+            )]
+        );
+        assert_eq!(
+            errors(&api, number(2.0), json!(u64::MAX)),
+            [(
+                String::new(),
+                InstanceErrorKind::MultipleOf {
+                    multiple_of: Number::Float(2.0)
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_composite_schemas() {
+        let api = api();
+        let string = || ObjectBuilder::new().schema_type(Type::String);
+        let one_of: RefOr<Schema> = OneOfBuilder::new()
+            .item(string())
+            .item(string().min_length(Some(2)))
+            .into();
+
+        assert_eq!(errors(&api, one_of.clone(), json!("a")), []);
+        assert_eq!(
+            errors(&api, one_of, json!("ab")),
+            [(String::new(), InstanceErrorKind::OneOf { matches: 2 })]
+        );
+
+        let all_of = AllOfBuilder::new()
+            .item(Ref::from_schema_name("Pet"))
+            .item(Ref::from_schema_name("Missing"));
+        assert_eq!(
+            errors(&api, all_of, json!({"name": "cat"})),
+            [(
+                String::new(),
+                InstanceErrorKind::UnresolvedReference {
+                    reference: "#/components/schemas/Missing".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_recursive_references() {
+        let api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Node",
+                        ObjectBuilder::new()
+                            .property("next", Ref::from_schema_name("Node"))
+                            .property("value", ObjectBuilder::new().schema_type(Type::Integer)),
+                    )
+                    .schema(
+                        "Loop",
+                        AllOfBuilder::new().item(Ref::from_schema_name("Loop")),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            errors(
+                &api,
+                Ref::from_schema_name("Node"),
+                json!({"next": {"next": {"value": "a"}}})
+            ),
+            [(
+                "/next/next/value".to_string(),
+                InstanceErrorKind::InvalidType {
+                    expected: SchemaType::Type(Type::Integer)
+                }
+            )]
+        );
+        assert_eq!(
+            errors(&api, Ref::from_schema_name("Loop"), json!({})),
+            [(
+                String::new(),
+                InstanceErrorKind::RecursiveReference {
+                    reference: "#/components/schemas/Loop".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_known_formats() {
+        let api = api();
+        let format = |format: KnownFormat| {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::KnownFormat(format)))
+        };
+        let cases = [
+            (
+                KnownFormat::DateTime,
+                "2024-01-01T10:20:30.5+02:00",
+                "2024-01-01 10:20",
+            ),
+            (KnownFormat::Time, "23:59:60Z", "24:00:00Z"),
+            (KnownFormat::Duration, "P1DT12H", "P1H"),
+            (KnownFormat::Email, "john@example.com", "john.example.com"),
+            (KnownFormat::Hostname, "api.example.com", "-api.example.com"),
+            (KnownFormat::Ipv4, "127.0.0.1", "256.0.0.1"),
+            (KnownFormat::Ipv6, "::1", "::g"),
+            (KnownFormat::Byte, "aGVsbG8=", "aGVsbG8"),
+            (KnownFormat::JsonPointer, "/a~1b", "a/b"),
+            (KnownFormat::RelativeJsonPointer, "1/a", "01/a"),
+        ];
+
+        for (known_format, valid, invalid) in cases {
+            assert_eq!(errors(&api, format(known_format.clone()), json!(valid)), []);
+            assert_eq!(
+                errors(&api, format(known_format.clone()), json!(invalid)),
+                [(
+                    String::new(),
+                    InstanceErrorKind::Format {
+                        format: known_format
+                    }
+                )]
+            );
+        }
+
+        let non_ascii_cases = [
+            (KnownFormat::Date, "2024€02-29"),
+            (KnownFormat::Time, "€aaaa"),
+            (KnownFormat::DateTime, "2024-02-29€00:00:00Z"),
+        ];
+        for (known_format, invalid) in non_ascii_cases {
+            assert_eq!(
+                errors(&api, format(known_format.clone()), json!(invalid)),
+                [(
+                    String::new(),
+                    InstanceErrorKind::Format {
+                        format: known_format
+                    }
+                )]
+            );
+        }
+
+        let int32 = ObjectBuilder::new()
+            .schema_type(Type::Integer)
+            .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int32)));
+        assert_eq!(
+            errors(&api, int32, json!(i64::from(i32::MAX) + 1)),
+            [(
+                String::new(),
+                InstanceErrorKind::Format {
+                    format: KnownFormat::Int32
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_examples_of_document() {
+        use crate::openapi::example::ExampleBuilder;
+        use crate::openapi::path::{OperationBuilder, ParameterBuilder};
+        use crate::openapi::{ContentBuilder, HttpMethod, PathItem, PathsBuilder, ResponseBuilder};
+
+        let operation = OperationBuilder::new()
+            .parameter(
+                ParameterBuilder::new()
+                    .name("limit")
+                    .schema(Some(ObjectBuilder::new().schema_type(Type::Integer)))
+                    .example(Some(json!("ten"))),
+            )
+            .response(
+                "200",
+                ResponseBuilder::new().content(
+                    "application/json",
+                    ContentBuilder::new()
+                        .schema(Some(Ref::from_schema_name("Pet")))
+                        .examples_from_iter([
+                            (
+                                "valid",
+                                RefOr::T(
+                                    ExampleBuilder::new()
+                                        .value(Some(json!({"name": "cat"})))
+                                        .build(),
+                                ),
+                            ),
+                            ("stale", RefOr::Ref(Ref::new("#/components/examples/Stale"))),
+                        ])
+                        .build(),
+                ),
+            );
+        let api = OpenApiBuilder::new()
+            .paths(PathsBuilder::new().path("/pets", PathItem::new(HttpMethod::Get, operation)))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", pet().examples([json!({"name": "cat"}), json!({})]))
+                    .example(
+                        "Stale",
+                        ExampleBuilder::new().value(Some(json!({"nickname": "cat"}))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let errors = api
+            .validate_examples()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "/paths/~1pets/get/parameters/0/example: value is not of type \"integer\"",
+                "/paths/~1pets/get/responses/200/content/application~1json/examples/stale/value: required property `name` is missing",
+                "/paths/~1pets/get/responses/200/content/application~1json/examples/stale/value: /nickname: additional property `nickname` is not allowed",
+                "/components/schemas/Pet/examples/1: required property `name` is missing",
+            ]
+        );
+    }
+}
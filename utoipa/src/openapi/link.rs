@@ -6,7 +6,7 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use super::extensions::Extensions;
-use super::{builder, Server};
+use super::{builder, Ref, RefOr, Server};
 
 builder! {
     LinkBuilder;
@@ -50,7 +50,7 @@ builder! {
         /// be any value supported by JSON or an [expression][expression] e.g. `$path.id`
         ///
         /// [expression]: https://spec.openapis.org/oas/latest.html#runtime-expressions
-        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub parameters: BTreeMap<String, serde_json::Value>,
 
         /// A literal value or an [expression][expression] to be used as request body when operation is called.
@@ -141,3 +141,15 @@ impl LinkBuilder {
         self
     }
 }
+
+impl From<LinkBuilder> for RefOr<Link> {
+    fn from(builder: LinkBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Link> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
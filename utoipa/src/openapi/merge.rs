@@ -0,0 +1,691 @@
+//! Implements conflict aware merging of [`OpenApi`] documents.
+//!
+//! Merge is performed with [`OpenApi::merge_with`] using one of the [`MergeStrategy`]s. Unlike
+//! [`OpenApi::merge`] it compares the whole definitions of items with same name and reports
+//! every conflicting definition as [`MergeConflict`] instead of silently discarding it.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::extensions::Extensions;
+use super::info::Info;
+use super::path::{Operation, Parameter, PathItem};
+use super::schema::Components;
+use super::validation::escape_pointer_segment;
+use super::{OpenApi, RefOr};
+
+/// Strategy for resolving conflicting definitions in [`OpenApi::merge_with`].
+///
+/// Definitions are considered conflicting when items with same identity e.g. component with
+/// same name, operation with same path and method or tag with same name are not equal.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum MergeStrategy {
+    /// Fail the merge with [`MergeError`] if any conflicting definitions are found. In case of
+    /// error `self` is left untouched.
+    Error,
+    /// Keep the definition of `self` on conflict.
+    KeepFirst,
+    /// Replace the definition of `self` with definition of `other` on conflict.
+    KeepLast,
+    /// Merge conflicting [`PathItem`]s and [`Operation`]s field by field. Tags of operations
+    /// are combined and parameters, responses and callbacks are merged by their identity.
+    /// Remaining conflicting fields and definitions keep the value of `self`.
+    DeepMerge,
+}
+
+/// Report of successful [`OpenApi::merge_with`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MergeReport {
+    /// Conflicting definitions resolved according to the used [`MergeStrategy`].
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl MergeReport {
+    /// Returns `true` if no conflicting definitions were found.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Conflicting definition found in [`OpenApi::merge_with`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MergeConflict {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the conflicting
+    /// definition in the merged document.
+    pub location: String,
+}
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: conflicting definitions", self.location)
+    }
+}
+
+/// Error returned from [`OpenApi::merge_with`] with [`MergeStrategy::Error`] when conflicting
+/// definitions are found.
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+pub struct MergeError {
+    /// All found conflicting definitions.
+    pub conflicts: Vec<MergeConflict>,
+}
+
+// Conflicts are listed by location since [`MergeConflict`] implements `Debug` only with `debug`
+// feature.
+impl std::fmt::Debug for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeError")
+            .field(
+                "conflicts",
+                &self
+                    .conflicts
+                    .iter()
+                    .map(|conflict| &conflict.location)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "conflicting definitions at: ")?;
+        for (index, conflict) in self.conflicts.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", conflict.location)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+impl OpenApi {
+    /// Merge `other` [`OpenApi`] into `self` resolving conflicting definitions with given
+    /// [`MergeStrategy`].
+    ///
+    /// In addition to what [`OpenApi::merge`] merges this will merge _`info`_,
+    /// _`external_docs`_ and _`extensions`_. Fields of _`info`_ are merged one by one so that
+    /// missing fields are filled from `other` and each conflicting field is reported
+    /// separately. Operations of path items and webhooks with same
+    /// path are combined. Operations with same path and method, reusable components with same
+    /// name and tags with same name are compared by their whole definition and not equal
+    /// definitions are reported as [`MergeConflict`]s. _`servers`_ and _`security`_
+    /// requirements are combined without conflicts.
+    ///
+    /// Returns [`MergeReport`] listing the resolved conflicts or [`MergeError`] if
+    /// [`MergeStrategy::Error`] is used and conflicts were found.
+    ///
+    /// # Examples
+    ///
+    /// _**Fail merge on conflicting schema definitions.**_
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Type};
+    /// # use utoipa::openapi::merge::MergeStrategy;
+    /// let mut api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Id", ObjectBuilder::new().schema_type(Type::Integer))
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    /// let other = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Id", ObjectBuilder::new().schema_type(Type::String))
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let error = api.merge_with(other, MergeStrategy::Error).unwrap_err();
+    /// assert_eq!(error.conflicts[0].location, "/components/schemas/Id");
+    /// ```
+    pub fn merge_with(
+        &mut self,
+        other: OpenApi,
+        strategy: MergeStrategy,
+    ) -> Result<MergeReport, MergeError> {
+        let mut merger = Merger {
+            strategy,
+            location: Vec::new(),
+            conflicts: Vec::new(),
+        };
+
+        if strategy == MergeStrategy::Error {
+            let mut merged = self.clone();
+            merger.merge_openapi(&mut merged, other);
+            if !merger.conflicts.is_empty() {
+                return Err(MergeError {
+                    conflicts: merger.conflicts,
+                });
+            }
+            *self = merged;
+        } else {
+            merger.merge_openapi(self, other);
+        }
+
+        Ok(MergeReport {
+            conflicts: merger.conflicts,
+        })
+    }
+}
+
+/// Map of named items that can be merged.
+trait MergeMap<T>: IntoIterator<Item = (String, T)> {
+    fn get_mut(&mut self, key: &str) -> Option<&mut T>;
+
+    fn insert(&mut self, key: String, value: T);
+}
+
+impl<T> MergeMap<T> for BTreeMap<String, T> {
+    fn get_mut(&mut self, key: &str) -> Option<&mut T> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: String, value: T) {
+        BTreeMap::insert(self, key, value);
+    }
+}
+
+#[cfg(feature = "preserve_path_order")]
+impl<T> MergeMap<T> for indexmap::IndexMap<String, T> {
+    fn get_mut(&mut self, key: &str) -> Option<&mut T> {
+        indexmap::IndexMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: String, value: T) {
+        indexmap::IndexMap::insert(self, key, value);
+    }
+}
+
+struct Merger {
+    strategy: MergeStrategy,
+    location: Vec<String>,
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger {
+    fn merge_openapi(&mut self, this: &mut OpenApi, that: OpenApi) {
+        self.with("openapi", |m| {
+            m.merge_value(&mut this.openapi, that.openapi)
+        });
+        self.with("info", |m| m.merge_info(&mut this.info, that.info));
+
+        if let Some(servers) = that.servers {
+            let this_servers = this.servers.get_or_insert(Vec::new());
+            for server in servers {
+                if !this_servers.contains(&server) {
+                    this_servers.push(server);
+                }
+            }
+        }
+
+        self.with("paths", |m| {
+            m.merge_map(
+                &mut this.paths.paths,
+                that.paths.paths,
+                Self::merge_path_item,
+            );
+            m.merge_extensions(&mut this.paths.extensions, that.paths.extensions);
+        });
+        self.with("webhooks", |m| {
+            m.merge_map(&mut this.webhooks, that.webhooks, Self::merge_path_item)
+        });
+        self.with("components", |m| {
+            m.merge_option_with(
+                &mut this.components,
+                that.components,
+                Self::merge_components,
+            )
+        });
+
+        if let Some(security) = that.security {
+            let this_security = this.security.get_or_insert(Vec::new());
+            for requirement in security {
+                if !this_security.contains(&requirement) {
+                    this_security.push(requirement);
+                }
+            }
+        }
+
+        if let Some(tags) = that.tags {
+            let this_tags = this.tags.get_or_insert(Vec::new());
+            for tag in tags {
+                match this_tags
+                    .iter()
+                    .position(|this_tag| this_tag.name == tag.name)
+                {
+                    Some(index) => self.with("tags", |m| {
+                        m.with(index, |m| m.merge_value(&mut this_tags[index], tag))
+                    }),
+                    None => this_tags.push(tag),
+                }
+            }
+        }
+
+        self.with("externalDocs", |m| {
+            m.merge_option(&mut this.external_docs, that.external_docs)
+        });
+        if this.schema.is_empty() {
+            this.schema = that.schema;
+        } else if !that.schema.is_empty() {
+            self.with("$schema", |m| m.merge_value(&mut this.schema, that.schema));
+        }
+        self.merge_extensions(&mut this.extensions, that.extensions);
+    }
+
+    /// Merge [`Info`] field by field. Empty _`title`_ and _`version`_ are considered missing.
+    fn merge_info(&mut self, this: &mut Info, that: Info) {
+        for (key, this_value, that_value) in [
+            ("title", &mut this.title, that.title),
+            ("version", &mut this.version, that.version),
+        ] {
+            if this_value.is_empty() {
+                *this_value = that_value;
+            } else if !that_value.is_empty() {
+                self.with(key, |m| m.merge_value(this_value, that_value));
+            }
+        }
+        self.with("description", |m| {
+            m.merge_option(&mut this.description, that.description)
+        });
+        self.with("termsOfService", |m| {
+            m.merge_option(&mut this.terms_of_service, that.terms_of_service)
+        });
+        self.with("contact", |m| {
+            m.merge_option(&mut this.contact, that.contact)
+        });
+        self.with("license", |m| {
+            m.merge_option(&mut this.license, that.license)
+        });
+        self.merge_extensions(&mut this.extensions, that.extensions);
+    }
+
+    fn merge_path_item(&mut self, this: &mut PathItem, that: PathItem) {
+        self.with("summary", |m| {
+            m.merge_option(&mut this.summary, that.summary)
+        });
+        self.with("description", |m| {
+            m.merge_option(&mut this.description, that.description)
+        });
+        self.with("servers", |m| {
+            m.merge_option(&mut this.servers, that.servers)
+        });
+        self.merge_parameters(&mut this.parameters, that.parameters);
+
+        for (method, this_operation, that_operation) in [
+            ("get", &mut this.get, that.get),
+            ("put", &mut this.put, that.put),
+            ("post", &mut this.post, that.post),
+            ("delete", &mut this.delete, that.delete),
+            ("options", &mut this.options, that.options),
+            ("head", &mut this.head, that.head),
+            ("patch", &mut this.patch, that.patch),
+            ("trace", &mut this.trace, that.trace),
+        ] {
+            self.with(method, |m| {
+                if m.strategy == MergeStrategy::DeepMerge {
+                    m.merge_option_with(this_operation, that_operation, Self::merge_operation)
+                } else {
+                    m.merge_option(this_operation, that_operation)
+                }
+            });
+        }
+
+        self.merge_extensions(&mut this.extensions, that.extensions);
+    }
+
+    fn merge_operation(&mut self, this: &mut Operation, that: Operation) {
+        if let Some(tags) = that.tags {
+            let this_tags = this.tags.get_or_insert(Vec::new());
+            for tag in tags {
+                if !this_tags.contains(&tag) {
+                    this_tags.push(tag);
+                }
+            }
+        }
+
+        self.with("summary", |m| {
+            m.merge_option(&mut this.summary, that.summary)
+        });
+        self.with("description", |m| {
+            m.merge_option(&mut this.description, that.description)
+        });
+        self.with("operationId", |m| {
+            m.merge_option(&mut this.operation_id, that.operation_id)
+        });
+        self.with("externalDocs", |m| {
+            m.merge_option(&mut this.external_docs, that.external_docs)
+        });
+        self.merge_parameters(&mut this.parameters, that.parameters);
+        self.with("requestBody", |m| {
+            m.merge_option(&mut this.request_body, that.request_body)
+        });
+        self.with("responses", |m| {
+            m.merge_map(
+                &mut this.responses.responses,
+                that.responses.responses,
+                Self::merge_value,
+            );
+            m.merge_extensions(&mut this.responses.extensions, that.responses.extensions);
+        });
+        self.with("callbacks", |m| {
+            m.merge_option_with(&mut this.callbacks, that.callbacks, |m, this, that| {
+                m.merge_map(this, that, Self::merge_value)
+            })
+        });
+        self.with("deprecated", |m| {
+            m.merge_option(&mut this.deprecated, that.deprecated)
+        });
+        self.with("security", |m| {
+            m.merge_option(&mut this.security, that.security)
+        });
+        self.with("servers", |m| {
+            m.merge_option(&mut this.servers, that.servers)
+        });
+        self.merge_extensions(&mut this.extensions, that.extensions);
+    }
+
+    /// Merge parameters identified by name and location or by reference.
+    fn merge_parameters(
+        &mut self,
+        this: &mut Option<Vec<RefOr<Parameter>>>,
+        that: Option<Vec<RefOr<Parameter>>>,
+    ) {
+        let Some(parameters) = that else {
+            return;
+        };
+        let this_parameters = this.get_or_insert(Vec::new());
+
+        for parameter in parameters {
+            let position = this_parameters.iter().position(|this_parameter| {
+                match (this_parameter, &parameter) {
+                    (RefOr::T(this_parameter), RefOr::T(parameter)) => {
+                        this_parameter.name == parameter.name
+                            && this_parameter.parameter_in == parameter.parameter_in
+                    }
+                    (RefOr::Ref(this_ref), RefOr::Ref(that_ref)) => {
+                        this_ref.ref_location == that_ref.ref_location
+                    }
+                    _ => false,
+                }
+            });
+
+            match position {
+                Some(index) => self.with("parameters", |m| {
+                    m.with(index, |m| {
+                        m.merge_value(&mut this_parameters[index], parameter)
+                    })
+                }),
+                None => this_parameters.push(parameter),
+            }
+        }
+    }
+
+    fn merge_components(&mut self, this: &mut Components, that: Components) {
+        self.with("schemas", |m| {
+            m.merge_map(&mut this.schemas, that.schemas, Self::merge_value)
+        });
+        self.with("responses", |m| {
+            m.merge_map(&mut this.responses, that.responses, Self::merge_value)
+        });
+        self.with("parameters", |m| {
+            m.merge_map(&mut this.parameters, that.parameters, Self::merge_value)
+        });
+        self.with("examples", |m| {
+            m.merge_map(&mut this.examples, that.examples, Self::merge_value)
+        });
+        self.with("requestBodies", |m| {
+            m.merge_map(
+                &mut this.request_bodies,
+                that.request_bodies,
+                Self::merge_value,
+            )
+        });
+        self.with("headers", |m| {
+            m.merge_map(&mut this.headers, that.headers, Self::merge_value)
+        });
+        self.with("securitySchemes", |m| {
+            m.merge_map(
+                &mut this.security_schemes,
+                that.security_schemes,
+                Self::merge_value,
+            )
+        });
+        self.with("links", |m| {
+            m.merge_map(&mut this.links, that.links, Self::merge_value)
+        });
+        self.with("callbacks", |m| {
+            m.merge_map(&mut this.callbacks, that.callbacks, Self::merge_value)
+        });
+        self.with("pathItems", |m| {
+            m.merge_map(&mut this.path_items, that.path_items, Self::merge_value)
+        });
+        self.merge_extensions(&mut this.extensions, that.extensions);
+    }
+
+    fn merge_extensions(&mut self, this: &mut Option<Extensions>, that: Option<Extensions>) {
+        self.merge_option_with(this, that, |m, this, that| {
+            let mut names = that.keys().collect::<Vec<_>>();
+            names.sort();
+
+            for name in names {
+                let value = that[name].clone();
+                match this.get_mut(name) {
+                    Some(this_value) => m.with(name, |m| m.merge_value(this_value, value)),
+                    None => {
+                        this.insert(name.clone(), value);
+                    }
+                }
+            }
+        });
+    }
+
+    fn merge_map<M: MergeMap<T>, T>(
+        &mut self,
+        this: &mut M,
+        that: M,
+        merge: impl Fn(&mut Self, &mut T, T),
+    ) {
+        for (name, value) in that {
+            match this.get_mut(&name) {
+                Some(this_value) => self.with(&name, |m| merge(m, this_value, value)),
+                None => this.insert(name, value),
+            }
+        }
+    }
+
+    fn merge_option<T: PartialEq>(&mut self, this: &mut Option<T>, that: Option<T>) {
+        self.merge_option_with(this, that, Self::merge_value)
+    }
+
+    fn merge_option_with<T>(
+        &mut self,
+        this: &mut Option<T>,
+        that: Option<T>,
+        merge: impl FnOnce(&mut Self, &mut T, T),
+    ) {
+        match (this.as_mut(), that) {
+            (Some(this_value), Some(value)) => merge(self, this_value, value),
+            (None, Some(value)) => *this = Some(value),
+            (_, None) => (),
+        }
+    }
+
+    fn merge_value<T: PartialEq>(&mut self, this: &mut T, that: T) {
+        if *this != that {
+            self.conflicts.push(MergeConflict {
+                location: self
+                    .location
+                    .iter()
+                    .map(|segment| format!("/{}", escape_pointer_segment(segment)))
+                    .collect(),
+            });
+            if self.strategy == MergeStrategy::KeepLast {
+                *this = that;
+            }
+        }
+    }
+
+    fn with<S: ToString>(&mut self, segment: S, f: impl FnOnce(&mut Self)) {
+        self.location.push(segment.to_string());
+        f(self);
+        self.location.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        ComponentsBuilder, HttpMethod, Info, ObjectBuilder, OpenApiBuilder, PathItem, PathsBuilder,
+        ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn api(id_type: Type, description: &str) -> OpenApi {
+        OpenApiBuilder::new()
+            .info(Info::new("api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .tag("pets")
+                            .description(Some(description))
+                            .parameter(
+                                ParameterBuilder::new()
+                                    .name(description)
+                                    .parameter_in(ParameterIn::Query),
+                            )
+                            .response("200", ResponseBuilder::new().description(description)),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Id", ObjectBuilder::new().schema_type(id_type))
+                    .build(),
+            ))
+            .build()
+    }
+
+    fn locations(conflicts: &[MergeConflict]) -> Vec<&str> {
+        conflicts
+            .iter()
+            .map(|conflict| conflict.location.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn merge_with_equal_definitions_has_no_conflicts() {
+        let mut first = api(Type::Integer, "first");
+
+        let report = first
+            .merge_with(api(Type::Integer, "first"), MergeStrategy::Error)
+            .expect("equal definitions must merge");
+
+        assert!(report.is_empty());
+        assert_eq!(first, api(Type::Integer, "first"));
+    }
+
+    #[test]
+    fn merge_with_error_leaves_self_untouched() {
+        let mut first = api(Type::Integer, "first");
+
+        let error = first
+            .merge_with(api(Type::String, "last"), MergeStrategy::Error)
+            .expect_err("conflicting definitions must fail");
+
+        assert_eq!(
+            locations(&error.conflicts),
+            ["/paths/~1pets/get", "/components/schemas/Id"]
+        );
+        assert_eq!(first, api(Type::Integer, "first"));
+    }
+
+    #[test]
+    fn merge_with_keep_first_and_keep_last() {
+        let mut first = api(Type::Integer, "first");
+        let report = first
+            .merge_with(api(Type::String, "last"), MergeStrategy::KeepFirst)
+            .expect("keep first must not fail");
+
+        assert_eq!(
+            locations(&report.conflicts),
+            ["/paths/~1pets/get", "/components/schemas/Id"]
+        );
+        assert_eq!(first, api(Type::Integer, "first"));
+
+        let mut first = api(Type::Integer, "first");
+        let report = first
+            .merge_with(api(Type::String, "last"), MergeStrategy::KeepLast)
+            .expect("keep last must not fail");
+
+        assert_eq!(report.conflicts.len(), 2);
+        assert_eq!(first, api(Type::String, "last"));
+    }
+
+    #[test]
+    fn merge_with_deep_merge_operations() {
+        let mut first = api(Type::Integer, "first");
+        let mut last = api(Type::Integer, "last");
+        last.info = Info::new("other api", "2.0.0");
+        last.info.description = Some("Pet store".to_string());
+        let operation = last
+            .paths
+            .paths
+            .get_mut("/pets")
+            .unwrap()
+            .get
+            .as_mut()
+            .unwrap();
+        operation.tags = Some(vec!["animals".to_string()]);
+        operation.summary = Some("List pets".to_string());
+
+        let report = first
+            .merge_with(last, MergeStrategy::DeepMerge)
+            .expect("deep merge must not fail");
+
+        assert_eq!(
+            locations(&report.conflicts),
+            [
+                "/info/title",
+                "/info/version",
+                "/paths/~1pets/get/description",
+                "/paths/~1pets/get/responses/200"
+            ]
+        );
+        assert_eq!(first.info.title, "api");
+        assert_eq!(first.info.description.as_deref(), Some("Pet store"));
+
+        let operation = first.paths.paths["/pets"].get.as_ref().unwrap();
+        assert_eq!(
+            operation.tags,
+            Some(vec!["pets".to_string(), "animals".to_string()])
+        );
+        assert_eq!(operation.summary.as_deref(), Some("List pets"));
+        assert_eq!(operation.description.as_deref(), Some("first"));
+        let parameter_names = operation
+            .parameters
+            .iter()
+            .flatten()
+            .map(|parameter| match parameter {
+                RefOr::T(parameter) => parameter.name.as_str(),
+                RefOr::Ref(_) => unreachable!("parameters must be inline"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(parameter_names, ["first", "last"]);
+    }
+}
@@ -0,0 +1,526 @@
+//! Implements normalization of [`OpenApi`] documents by simplifying redundant [`Schema`]
+//! structures.
+use std::any::Any;
+
+use super::schema::{AllOf, AnyOf, Object, OneOf, Schema, SchemaType, Type};
+use super::visit::{self, Component, Location, VisitMut};
+use super::{Extensions, OpenApi, RefOr};
+
+impl OpenApi {
+    /// Normalize this [`OpenApi`] by simplifying every [`Schema`] of the document with
+    /// [`Schema::simplify`].
+    ///
+    /// In addition to what [`Schema::simplify`] does, composite schemas having only a single
+    /// reference are replaced with the reference itself. If the composite schema has a
+    /// description it is moved to the [`Ref::description`][ref_description].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{AllOfBuilder, ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref, RefOr};
+    /// let mut api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Pet", ObjectBuilder::new())
+    ///             .schema(
+    ///                 "Owner",
+    ///                 ObjectBuilder::new().property(
+    ///                     "pet",
+    ///                     AllOfBuilder::new()
+    ///                         .item(Ref::from_schema_name("Pet"))
+    ///                         .description(Some("Pet of the owner")),
+    ///                 ),
+    ///             )
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// api.normalize();
+    ///
+    /// let owner = serde_json::to_value(&api.components.unwrap().schemas["Owner"]).unwrap();
+    /// assert_eq!(
+    ///     owner["properties"]["pet"],
+    ///     serde_json::json!({"$ref": "#/components/schemas/Pet", "description": "Pet of the owner"})
+    /// );
+    /// ```
+    ///
+    /// [ref_description]: super::Ref::description
+    pub fn normalize(&mut self) {
+        Simplifier.visit_openapi_mut(self, &mut Location::new());
+    }
+}
+
+impl Schema {
+    /// Simplify this [`Schema`] and all of its nested schemas by removing redundant structures.
+    ///
+    /// Simplification does not change the set of values the [`Schema`] accepts apart from the
+    /// note about [`OneOf`] below. It will:
+    /// * Flatten nested [`AllOf`]s and nested [`OneOf`]s or [`AnyOf`]s of same kind when the
+    ///   nested schema has no other fields than its items.
+    /// * Merge compatible [`Object`]s of [`AllOf`] holding only properties into a single
+    ///   [`Object`] when they do not define the same property differently.
+    /// * Remove duplicate items from [`AllOf`], [`OneOf`] and [`AnyOf`].
+    /// * Collapse trivial composite schemas with a single item to the item itself. Title,
+    ///   description, default value and examples of [`AllOf`] are moved to the [`Object`] item
+    ///   when the item does not define them.
+    ///
+    /// Nested duplicate schemas of a [`OneOf`] could match the same value thus the result is
+    /// only equal to the original when the items of the [`OneOf`] are disjoint, which is the
+    /// intention of such schemas in practice e.g. _`Option<Option<T>>`_ producing _`null`_ twice.
+    ///
+    /// See also [`OpenApi::normalize`] for simplifying all schemas of the document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::schema::{AllOfBuilder, ObjectBuilder, Schema, Type};
+    /// let mut schema: Schema = AllOfBuilder::new()
+    ///     .item(ObjectBuilder::new().property("id", ObjectBuilder::new().schema_type(Type::Integer)))
+    ///     .item(ObjectBuilder::new().property("name", ObjectBuilder::new().schema_type(Type::String)))
+    ///     .into();
+    ///
+    /// schema.simplify();
+    ///
+    /// let Schema::Object(object) = schema else {
+    ///     panic!("expected merged object");
+    /// };
+    /// assert_eq!(object.properties.len(), 2);
+    /// ```
+    pub fn simplify(&mut self) {
+        Simplifier.visit_schema_mut(self, &mut Location::new());
+    }
+}
+
+/// Simplify the [`Schema`] itself assuming its nested schemas are already simplified.
+fn simplify_schema(schema: &mut Schema) {
+    match schema {
+        Schema::AllOf(all_of) => {
+            let is_bare = is_bare_all_of(all_of, false);
+            all_of.items = flatten(std::mem::take(&mut all_of.items), |item| match item {
+                Schema::AllOf(nested) if is_bare_all_of(nested, false) => {
+                    Some(std::mem::take(&mut nested.items))
+                }
+                _ => None,
+            });
+            merge_objects(&mut all_of.items);
+
+            if all_of.items.len() == 1 {
+                if is_bare {
+                    // single reference is collapsed by the parent owning the `RefOr`
+                    if let Some(item) = take_single_schema(&mut all_of.items) {
+                        *schema = item;
+                    }
+                } else if let Some(object) = move_annotations(all_of) {
+                    *schema = Schema::Object(object);
+                }
+            }
+        }
+        Schema::OneOf(one_of) => {
+            one_of.items = flatten(std::mem::take(&mut one_of.items), |item| match item {
+                Schema::OneOf(nested) if is_bare_one_of(nested, false) => {
+                    Some(std::mem::take(&mut nested.items))
+                }
+                _ => None,
+            });
+
+            if one_of.items.len() == 1 && is_bare_one_of(one_of, false) {
+                if let Some(item) = take_single_schema(&mut one_of.items) {
+                    *schema = item;
+                }
+            }
+        }
+        Schema::AnyOf(any_of) => {
+            any_of.items = flatten(std::mem::take(&mut any_of.items), |item| match item {
+                Schema::AnyOf(nested) if is_bare_any_of(nested, false) => {
+                    Some(std::mem::take(&mut nested.items))
+                }
+                _ => None,
+            });
+
+            if any_of.items.len() == 1 && is_bare_any_of(any_of, false) {
+                if let Some(item) = take_single_schema(&mut any_of.items) {
+                    *schema = item;
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Replace already simplified composite schema of the [`RefOr`] having only single reference
+/// with the reference itself.
+fn collapse_single_reference(schema: &mut RefOr<Schema>) {
+    let RefOr::T(inner) = schema else {
+        return;
+    };
+
+    let (items, description) = match inner {
+        Schema::AllOf(all_of) if is_bare_all_of(all_of, true) => {
+            (&all_of.items, &all_of.description)
+        }
+        Schema::OneOf(one_of) if is_bare_one_of(one_of, true) => {
+            (&one_of.items, &one_of.description)
+        }
+        Schema::AnyOf(any_of) if is_bare_any_of(any_of, true) => {
+            (&any_of.items, &any_of.description)
+        }
+        _ => return,
+    };
+    let [RefOr::Ref(reference)] = items.as_slice() else {
+        return;
+    };
+    if description.is_some() && !reference.description.is_empty() {
+        return;
+    }
+
+    let mut reference = reference.clone();
+    if let Some(description) = description {
+        reference.description = description.clone();
+    }
+    *schema = RefOr::Ref(reference);
+}
+
+/// Take the only item of composite schema items if it is a [`Schema`].
+fn take_single_schema(items: &mut Vec<RefOr<Schema>>) -> Option<Schema> {
+    match items.as_slice() {
+        [RefOr::T(_)] => match items.pop() {
+            Some(RefOr::T(schema)) => Some(schema),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Replace items with the items of nested composite schema returned by `nested` and remove
+/// duplicate items.
+fn flatten(
+    items: Vec<RefOr<Schema>>,
+    mut nested: impl FnMut(&mut Schema) -> Option<Vec<RefOr<Schema>>>,
+) -> Vec<RefOr<Schema>> {
+    let mut flattened: Vec<RefOr<Schema>> = Vec::with_capacity(items.len());
+    for mut item in items {
+        let items = match &mut item {
+            RefOr::T(schema) => nested(schema).unwrap_or_else(|| vec![item]),
+            RefOr::Ref(_) => vec![item],
+        };
+        for item in items {
+            if !flattened.contains(&item) {
+                flattened.push(item);
+            }
+        }
+    }
+
+    flattened
+}
+
+/// Merge [`Object`] items of [`AllOf`] that only define properties to the first such item
+/// when they do not define the same property differently.
+fn merge_objects(items: &mut Vec<RefOr<Schema>>) {
+    let mut target: Option<usize> = None;
+    let mut index = 0;
+    while index < items.len() {
+        let RefOr::T(Schema::Object(object)) = &items[index] else {
+            index += 1;
+            continue;
+        };
+        if !is_properties_only(object) {
+            index += 1;
+            continue;
+        }
+        let Some(target_index) = target else {
+            target = Some(index);
+            index += 1;
+            continue;
+        };
+        let RefOr::T(Schema::Object(target_object)) = &items[target_index] else {
+            unreachable!("merge target must be an object");
+        };
+        let conflicts = object.properties.iter().any(|(name, schema)| {
+            target_object
+                .properties
+                .get(name)
+                .is_some_and(|existing| existing != schema)
+        });
+        if conflicts {
+            index += 1;
+            continue;
+        }
+
+        let RefOr::T(Schema::Object(object)) = items.remove(index) else {
+            unreachable!("merged item must be an object");
+        };
+        let RefOr::T(Schema::Object(target_object)) = &mut items[target_index] else {
+            unreachable!("merge target must be an object");
+        };
+        if object.schema_type == SchemaType::Type(Type::Object) {
+            target_object.schema_type = SchemaType::Type(Type::Object);
+        }
+        for (name, schema) in object.properties {
+            target_object.properties.entry(name).or_insert(schema);
+        }
+        for required in object.required {
+            if !target_object.required.contains(&required) {
+                target_object.required.push(required);
+            }
+        }
+    }
+}
+
+/// Move annotations of [`AllOf`] with single [`Object`] item to the [`Object`] when the
+/// [`Object`] does not define them already.
+fn move_annotations(all_of: &mut AllOf) -> Option<Object> {
+    let [RefOr::T(Schema::Object(object))] = all_of.items.as_slice() else {
+        return None;
+    };
+    let can_move = all_of.discriminator.is_none()
+        && is_empty(&all_of.extensions)
+        && (all_of.schema_type.is_any_value() || all_of.schema_type == object.schema_type)
+        && (all_of.title.is_none() || object.title.is_none())
+        && (all_of.description.is_none() || object.description.is_none())
+        && (all_of.default.is_none() || object.default.is_none())
+        && (all_of.example.is_none() || object.example.is_none())
+        && (all_of.examples.is_empty() || object.examples.is_empty());
+    if !can_move {
+        return None;
+    }
+
+    let Some(RefOr::T(Schema::Object(mut object))) = all_of.items.pop() else {
+        return None;
+    };
+    object.title = object.title.or(all_of.title.take());
+    object.description = object.description.or(all_of.description.take());
+    object.default = object.default.or(all_of.default.take());
+    object.example = object.example.or(all_of.example.take());
+    if object.examples.is_empty() {
+        object.examples = std::mem::take(&mut all_of.examples);
+    }
+
+    Some(object)
+}
+
+fn is_properties_only(object: &Object) -> bool {
+    (object.schema_type.is_any_value() || object.schema_type == SchemaType::Type(Type::Object))
+        && Object {
+            schema_type: SchemaType::Type(Type::Object),
+            properties: Default::default(),
+            required: Vec::new(),
+            extensions: None,
+            ..object.clone()
+        } == Object::default()
+        && is_empty(&object.extensions)
+}
+
+fn is_bare_all_of(all_of: &AllOf, allow_description: bool) -> bool {
+    all_of.schema_type.is_any_value()
+        && all_of.title.is_none()
+        && (allow_description || all_of.description.is_none())
+        && all_of.default.is_none()
+        && all_of.example.is_none()
+        && all_of.examples.is_empty()
+        && all_of.discriminator.is_none()
+        && is_empty(&all_of.extensions)
+}
+
+fn is_bare_one_of(one_of: &OneOf, allow_description: bool) -> bool {
+    one_of.schema_type.is_any_value()
+        && one_of.title.is_none()
+        && (allow_description || one_of.description.is_none())
+        && one_of.default.is_none()
+        && one_of.example.is_none()
+        && one_of.examples.is_empty()
+        && one_of.discriminator.is_none()
+        && is_empty(&one_of.extensions)
+}
+
+fn is_bare_any_of(any_of: &AnyOf, allow_description: bool) -> bool {
+    any_of.schema_type.is_any_value()
+        && (allow_description || any_of.description.is_none())
+        && any_of.default.is_none()
+        && any_of.example.is_none()
+        && any_of.examples.is_empty()
+        && any_of.discriminator.is_none()
+        && is_empty(&any_of.extensions)
+}
+
+fn is_empty(extensions: &Option<Extensions>) -> bool {
+    extensions
+        .as_ref()
+        .map_or(true, |extensions| extensions.is_empty())
+}
+
+/// Simplifies every [`Schema`] after its nested schemas have been simplified.
+struct Simplifier;
+
+impl VisitMut for Simplifier {
+    fn visit_schema_mut(&mut self, schema: &mut Schema, location: &mut Location) {
+        visit::walk_schema_mut(self, schema, location);
+        simplify_schema(schema);
+    }
+
+    fn visit_ref_or_mut<T: Component>(&mut self, ref_or: &mut RefOr<T>, location: &mut Location) {
+        visit::walk_ref_or_mut(self, ref_or, location);
+        if let Some(schema) = (ref_or as &mut dyn Any).downcast_mut::<RefOr<Schema>>() {
+            collapse_single_reference(schema);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::schema::{AllOfBuilder, AnyOfBuilder, OneOfBuilder};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, ObjectBuilder, OpenApiBuilder, Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn simplify(schema: impl Into<Schema>) -> serde_json::Value {
+        let mut schema = schema.into();
+        schema.simplify();
+        serde_json::to_value(schema).unwrap()
+    }
+
+    #[test]
+    fn simplify_flattens_and_dedupes_composite_schemas() {
+        let null = || ObjectBuilder::new().schema_type(Type::Null);
+        assert_eq!(
+            simplify(
+                OneOfBuilder::new().item(null()).item(
+                    OneOfBuilder::new()
+                        .item(null())
+                        .item(Ref::from_schema_name("Pet"))
+                )
+            ),
+            json!({"oneOf": [{"type": "null"}, {"$ref": "#/components/schemas/Pet"}]})
+        );
+        assert_eq!(
+            simplify(
+                AnyOfBuilder::new()
+                    .item(AnyOfBuilder::new().item(Ref::from_schema_name("Pet")))
+                    .item(Ref::from_schema_name("Owner"))
+                    .item(Ref::from_schema_name("Pet"))
+            ),
+            json!({"anyOf": [{"$ref": "#/components/schemas/Pet"}, {"$ref": "#/components/schemas/Owner"}]})
+        );
+        assert_eq!(
+            simplify(
+                OneOfBuilder::new()
+                    .item(null())
+                    .item(null())
+                    .description(Some("kept"))
+            ),
+            json!({"oneOf": [{"type": "null"}], "description": "kept"})
+        );
+    }
+
+    #[test]
+    fn simplify_merges_all_of_objects() {
+        let property = |schema_type| ObjectBuilder::new().schema_type(schema_type);
+        assert_eq!(
+            simplify(
+                AllOfBuilder::new()
+                    .item(
+                        AllOfBuilder::new()
+                            .item(ObjectBuilder::new().property("id", property(Type::Integer)))
+                            .item(Ref::from_schema_name("Base"))
+                    )
+                    .item(
+                        ObjectBuilder::new()
+                            .property("name", property(Type::String))
+                            .required("name")
+                    )
+            ),
+            json!({
+                "allOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "id": {"type": "integer"},
+                            "name": {"type": "string"}
+                        },
+                        "required": ["name"]
+                    },
+                    {"$ref": "#/components/schemas/Base"}
+                ]
+            })
+        );
+        assert_eq!(
+            simplify(
+                AllOfBuilder::new()
+                    .item(ObjectBuilder::new().property("id", property(Type::Integer)))
+                    .item(ObjectBuilder::new().property("id", property(Type::String)))
+            ),
+            json!({
+                "allOf": [
+                    {"type": "object", "properties": {"id": {"type": "integer"}}},
+                    {"type": "object", "properties": {"id": {"type": "string"}}}
+                ]
+            })
+        );
+        assert_eq!(
+            simplify(
+                AllOfBuilder::new()
+                    .item(ObjectBuilder::new().property("id", property(Type::Integer)))
+                    .description(Some("Pet"))
+            ),
+            json!({"type": "object", "description": "Pet", "properties": {"id": {"type": "integer"}}})
+        );
+    }
+
+    #[test]
+    fn normalize_collapses_single_reference_wrappers() {
+        let wrapper = |description: Option<&str>| {
+            AllOfBuilder::new()
+                .item(Ref::from_schema_name("Pet"))
+                .description(description)
+        };
+        let mut api = OpenApiBuilder::new()
+            .paths(crate::openapi::PathsBuilder::new().path(
+                "/pets",
+                crate::openapi::PathItem::new(
+                    crate::openapi::HttpMethod::Get,
+                    crate::openapi::path::OperationBuilder::new().response(
+                        "200",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            ContentBuilder::new().schema(Some(wrapper(None))).build(),
+                        ),
+                    ),
+                ),
+            ))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", ObjectBuilder::new())
+                    .schema("Alias", wrapper(Some("Alias of pet")))
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new().property(
+                            "pets",
+                            crate::openapi::ArrayBuilder::new().items(wrapper(None)),
+                        ),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        api.normalize();
+
+        let api = serde_json::to_value(api).unwrap();
+        let pet = json!({"$ref": "#/components/schemas/Pet"});
+        assert_eq!(
+            api["paths"]["/pets"]["get"]["responses"]["200"]["content"]["application/json"]
+                ["schema"],
+            pet
+        );
+        assert_eq!(
+            api["components"]["schemas"]["Alias"],
+            json!({"$ref": "#/components/schemas/Pet", "description": "Alias of pet"})
+        );
+        assert_eq!(
+            api["components"]["schemas"]["Owner"]["properties"]["pets"]["items"],
+            pet
+        );
+    }
+}
@@ -0,0 +1,1108 @@
+//! Implements [OpenAPI Overlay][overlay] types and applying them to [`OpenApi`] documents.
+//!
+//! Overlay is a separate document consisting of ordered list of [`Action`]s which either update
+//! or remove parts of [`OpenApi`] document selected by [JSONPath][json_path] _`target`_s. It can
+//! be used to e.g. enrich generated documents with descriptions and _`x-`_ extensions without
+//! touching the Rust source.
+//!
+//! Following subset of JSONPath is supported in targets:
+//! * Root identifier _`$`_ and current node identifier _`@`_ within filters.
+//! * Name selectors _`.name`_, _`['name']`_ and _`["name"]`_. Shorthand names may contain _`-`_
+//!   e.g. _`$.info.x-logo`_.
+//! * Wildcard selectors _`.*`_ and _`[*]`_, and index selectors _`[0]`_ and _`[-1]`_.
+//! * Descendant segments e.g. _`$..description`_ and _`$..[*]`_.
+//! * Unions of selectors e.g. _`['get','post']`_.
+//! * Filter selectors with existence tests, comparisons _`==`_, _`!=`_, _`<`_, _`<=`_, _`>`_,
+//!   _`>=`_ and logical operators _`&&`_, _`||`_ and _`!`_ e.g.
+//!   _`$.paths.*.*.parameters[?@.in == 'header' && !@.required]`_.
+//!
+//! # Examples
+//!
+//! _**Add description and extension to an operation.**_
+//! ```rust
+//! # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+//! # use utoipa::openapi::path::OperationBuilder;
+//! # use utoipa::openapi::overlay::Overlay;
+//! let mut api = OpenApiBuilder::new()
+//!     .paths(PathsBuilder::new().path(
+//!         "/pets",
+//!         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("list_pets"))),
+//!     ))
+//!     .build();
+//!
+//! let overlay = Overlay::from_json(r#"{
+//!     "overlay": "1.0.0",
+//!     "info": { "title": "Docs", "version": "1.0.0" },
+//!     "actions": [{
+//!         "target": "$.paths['/pets'].get",
+//!         "update": { "description": "List all pets.", "x-internal": false }
+//!     }]
+//! }"#).unwrap();
+//!
+//! api.apply_overlay(&overlay).unwrap();
+//!
+//! let operation = api.paths.get_path_item("/pets").unwrap().get.as_ref().unwrap();
+//! assert_eq!(operation.description.as_deref(), Some("List all pets."));
+//! ```
+//!
+//! [overlay]: https://spec.openapis.org/overlay/v1.0.0.html
+//! [json_path]: https://www.rfc-editor.org/rfc/rfc9535
+use std::fmt::Display;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::extensions::Extensions;
+use super::{builder, set_value, OpenApi};
+
+builder! {
+    OverlayBuilder;
+
+    /// Implements [OpenAPI Overlay Object][overlay].
+    ///
+    /// Overlay is applied to [`OpenApi`] document with [`OpenApi::apply_overlay`].
+    ///
+    /// [overlay]: https://spec.openapis.org/overlay/v1.0.0.html#overlay-object
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub struct Overlay {
+        /// Version of the Overlay specification the document uses. Defaults to _`1.0.0`_.
+        pub overlay: String,
+
+        /// Metadata of the overlay.
+        pub info: OverlayInfo,
+
+        /// URI of the document this overlay is meant to be applied to.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub extends: Option<String>,
+
+        /// Ordered list of [`Action`]s applied to the target document.
+        pub actions: Vec<Action>,
+
+        /// Optional extensions "x-something".
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl Default for Overlay {
+    fn default() -> Self {
+        Self {
+            overlay: "1.0.0".to_string(),
+            info: OverlayInfo::default(),
+            extends: None,
+            actions: Vec::new(),
+            extensions: None,
+        }
+    }
+}
+
+impl Overlay {
+    /// Construct a new [`Overlay`] with given title, version and actions.
+    pub fn new<T: Into<String>, V: Into<String>, A: IntoIterator<Item = Action>>(
+        title: T,
+        version: V,
+        actions: A,
+    ) -> Self {
+        Self {
+            info: OverlayInfo::new(title, version),
+            actions: actions.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Parse [`Overlay`] from JSON string. This method essentially calls
+    /// [`serde_json::from_str`] method.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Parse [`Overlay`] from YAML string. This method essentially calls
+    /// [`serde_yaml::from_str`] method.
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "yaml")))]
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+impl OverlayBuilder {
+    /// Add version of the Overlay specification.
+    pub fn overlay<S: Into<String>>(mut self, overlay: S) -> Self {
+        set_value!(self overlay overlay.into())
+    }
+
+    /// Add metadata of the overlay.
+    pub fn info<I: Into<OverlayInfo>>(mut self, info: I) -> Self {
+        set_value!(self info info.into())
+    }
+
+    /// Add URI of the document this overlay is meant to be applied to.
+    pub fn extends<S: Into<String>>(mut self, extends: Option<S>) -> Self {
+        set_value!(self extends extends.map(|extends| extends.into()))
+    }
+
+    /// Add new [`Action`] to the end of the actions.
+    pub fn action<A: Into<Action>>(mut self, action: A) -> Self {
+        self.actions.push(action.into());
+
+        self
+    }
+
+    /// Add openapi extensions (x-something) of the overlay.
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+builder! {
+    OverlayInfoBuilder;
+
+    /// Implements [OpenAPI Overlay Info Object][info].
+    ///
+    /// [info]: https://spec.openapis.org/overlay/v1.0.0.html#info-object
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub struct OverlayInfo {
+        /// Title of the overlay.
+        pub title: String,
+
+        /// Version of the overlay document.
+        pub version: String,
+
+        /// Optional extensions "x-something".
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl OverlayInfo {
+    /// Construct a new [`OverlayInfo`] with given title and version.
+    pub fn new<T: Into<String>, V: Into<String>>(title: T, version: V) -> Self {
+        Self {
+            title: title.into(),
+            version: version.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl OverlayInfoBuilder {
+    /// Add title of the overlay.
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        set_value!(self title title.into())
+    }
+
+    /// Add version of the overlay document.
+    pub fn version<S: Into<String>>(mut self, version: S) -> Self {
+        set_value!(self version version.into())
+    }
+
+    /// Add openapi extensions (x-something) of the overlay info.
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+builder! {
+    ActionBuilder;
+
+    /// Implements [OpenAPI Overlay Action Object][action].
+    ///
+    /// Action either merges [`Action::update`] to every node selected by [`Action::target`] or
+    /// removes the selected nodes when [`Action::remove`] is `true`.
+    ///
+    /// When updating, objects are merged recursively with values of _`update`_ replacing
+    /// existing values, and when target node is an array the _`update`_ is appended to it.
+    ///
+    /// [action]: https://spec.openapis.org/overlay/v1.0.0.html#action-object
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
+    pub struct Action {
+        /// JSONPath expression selecting the nodes of the target document e.g. _`$.info`_.
+        pub target: String,
+
+        /// Description of the action. Markdown syntax is supported.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Value merged to every selected node.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub update: Option<Value>,
+
+        /// Remove selected nodes from the target document when `true`.
+        #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+        pub remove: bool,
+
+        /// Optional extensions "x-something".
+        #[serde(skip_serializing_if = "Option::is_none", flatten)]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl Action {
+    /// Construct a new [`Action`] merging `update` to every node selected by `target`.
+    pub fn update<S: Into<String>, V: Into<Value>>(target: S, update: V) -> Self {
+        Self {
+            target: target.into(),
+            update: Some(update.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new [`Action`] removing every node selected by `target`.
+    pub fn remove<S: Into<String>>(target: S) -> Self {
+        Self {
+            target: target.into(),
+            remove: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl ActionBuilder {
+    /// Add JSONPath expression selecting the nodes of the target document.
+    pub fn target<S: Into<String>>(mut self, target: S) -> Self {
+        set_value!(self target target.into())
+    }
+
+    /// Add description of the action.
+    pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add value merged to every selected node.
+    pub fn update<V: Into<Value>>(mut self, update: Option<V>) -> Self {
+        set_value!(self update update.map(|update| update.into()))
+    }
+
+    /// Define whether selected nodes are removed.
+    pub fn remove(mut self, remove: bool) -> Self {
+        set_value!(self remove remove)
+    }
+
+    /// Add openapi extensions (x-something) of the action.
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+}
+
+/// Error returned from [`OpenApi::apply_overlay`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverlayError {
+    /// [`Action::target`] is not a valid or supported JSONPath expression.
+    InvalidTarget {
+        /// The invalid target.
+        target: String,
+        /// Description of the error.
+        message: String,
+    },
+    /// Document resulting from the applied actions is not a valid [`OpenApi`] document.
+    InvalidDocument {
+        /// Description of the error.
+        message: String,
+    },
+}
+
+impl Display for OverlayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTarget { target, message } => {
+                write!(f, "invalid target `{target}`: {message}")
+            }
+            Self::InvalidDocument { message } => {
+                write!(f, "overlay results invalid document: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OverlayError {}
+
+impl OpenApi {
+    /// Apply [`Action`]s of the [`Overlay`] in order to this [`OpenApi`].
+    ///
+    /// Targets not selecting any nodes are ignored. Actions are applied to the JSON
+    /// representation of the document which is converted back to [`OpenApi`] once all actions
+    /// are applied. The JSON representation keeps the order of e.g. paths and properties only
+    /// when _`serde_json/preserve_order`_ feature is enabled, otherwise they are ordered
+    /// alphabetically.
+    ///
+    /// Returns [`OverlayError`] if any of the targets is not valid JSONPath expression or if
+    /// resulting document is not valid [`OpenApi`] document. On error `self` is left untouched.
+    /// See [module level documentation][mod] for examples.
+    ///
+    /// [mod]: crate::openapi::overlay
+    pub fn apply_overlay(&mut self, overlay: &Overlay) -> Result<(), OverlayError> {
+        let invalid_document = |error: serde_json::Error| OverlayError::InvalidDocument {
+            message: error.to_string(),
+        };
+
+        let mut document = serde_json::to_value(&*self).map_err(invalid_document)?;
+        for action in &overlay.actions {
+            let path =
+                JsonPath::parse(&action.target).map_err(|message| OverlayError::InvalidTarget {
+                    target: action.target.clone(),
+                    message,
+                })?;
+            let mut targets = path.select(&document);
+
+            if action.remove {
+                // remove from the end so that removing array items does not shift the indexes
+                // of yet to be removed items
+                targets.sort();
+                targets.dedup();
+                for target in targets.iter().rev() {
+                    remove(&mut document, target);
+                }
+            } else if let Some(update) = &action.update {
+                for target in &targets {
+                    if let Some(node) = get_mut(&mut document, target) {
+                        merge(node, update);
+                    }
+                }
+            }
+        }
+
+        *self = serde_json::from_value(document).map_err(invalid_document)?;
+
+        Ok(())
+    }
+}
+
+fn merge(node: &mut Value, update: &Value) {
+    match (node, update) {
+        (Value::Object(node), Value::Object(update)) => {
+            for (key, value) in update {
+                match node.get_mut(key) {
+                    Some(existing) if existing.is_object() && value.is_object() => {
+                        merge(existing, value)
+                    }
+                    _ => {
+                        node.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(node), update) => node.push(update.clone()),
+        (node, update) => *node = update.clone(),
+    }
+}
+
+fn get_mut<'v>(document: &'v mut Value, path: &[PathSegment]) -> Option<&'v mut Value> {
+    path.iter()
+        .try_fold(document, |node, segment| match segment {
+            PathSegment::Key(key) => node.get_mut(key),
+            PathSegment::Index(index) => node.get_mut(index),
+        })
+}
+
+fn remove(document: &mut Value, path: &[PathSegment]) {
+    let Some((last, parent)) = path.split_last() else {
+        return;
+    };
+
+    match (get_mut(document, parent), last) {
+        (Some(Value::Object(object)), PathSegment::Key(key)) => {
+            object.remove(key);
+        }
+        (Some(Value::Array(array)), PathSegment::Index(index)) if *index < array.len() => {
+            array.remove(*index);
+        }
+        _ => (),
+    }
+}
+
+/// Segment of normalized path to a node selected by [`JsonPath`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parsed JSONPath query. See [module level documentation][self] for supported syntax.
+struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+struct Segment {
+    is_descendant: bool,
+    selectors: Vec<Selector>,
+}
+
+enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Filter(Expression),
+}
+
+enum Expression {
+    Or(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Exists(Query),
+    Comparison(Comparable, Comparison, Comparable),
+}
+
+#[derive(Clone, Copy)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+enum Comparable {
+    Literal(Value),
+    Query(Query),
+}
+
+struct Query {
+    is_root: bool,
+    path: JsonPath,
+}
+
+type Node<'v> = (Vec<PathSegment>, &'v Value);
+
+impl JsonPath {
+    fn parse(path: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            chars: path.chars().peekable(),
+        };
+        if parser.chars.next() != Some('$') {
+            return Err("path must start with `$`".to_string());
+        }
+
+        let path = parser.parse_segments()?;
+        match parser.chars.next() {
+            None => Ok(path),
+            Some(char) => Err(format!("unexpected character `{char}`")),
+        }
+    }
+
+    /// Select normalized paths of all nodes matching this path from the `document`.
+    fn select(&self, document: &Value) -> Vec<Vec<PathSegment>> {
+        self.select_nodes(document, document)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect()
+    }
+
+    fn select_nodes<'v>(&self, root: &'v Value, current: &'v Value) -> Vec<Node<'v>> {
+        self.segments
+            .iter()
+            .fold(vec![(Vec::new(), current)], |nodes, segment| {
+                let nodes = if segment.is_descendant {
+                    nodes.into_iter().flat_map(descendants).collect()
+                } else {
+                    nodes
+                };
+
+                nodes
+                    .iter()
+                    .flat_map(|node| {
+                        segment
+                            .selectors
+                            .iter()
+                            .flat_map(move |selector| selector.select(root, node))
+                    })
+                    .collect()
+            })
+    }
+}
+
+/// Return the `node` itself and all of its descendants in document order.
+fn descendants((path, value): Node<'_>) -> Vec<Node<'_>> {
+    let mut nodes = vec![(path.clone(), value)];
+    for child in children(&path, value) {
+        nodes.extend(descendants(child));
+    }
+
+    nodes
+}
+
+fn children<'v>(path: &[PathSegment], value: &'v Value) -> Vec<Node<'v>> {
+    let child = |segment: PathSegment| {
+        let mut path = path.to_vec();
+        path.push(segment);
+        path
+    };
+
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| (child(PathSegment::Key(key.clone())), value))
+            .collect(),
+        Value::Array(array) => array
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (child(PathSegment::Index(index)), value))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl Selector {
+    fn select<'v>(&self, root: &'v Value, (path, value): &Node<'v>) -> Vec<Node<'v>> {
+        let child = |segment: PathSegment, value: &'v Value| {
+            let mut path = path.clone();
+            path.push(segment);
+            (path, value)
+        };
+
+        match self {
+            Self::Name(name) => value
+                .get(name)
+                .map(|value| child(PathSegment::Key(name.clone()), value))
+                .into_iter()
+                .collect(),
+            Self::Index(index) => value
+                .as_array()
+                .and_then(|array| {
+                    let index = if *index < 0 {
+                        array.len().checked_sub(index.unsigned_abs() as usize)?
+                    } else {
+                        *index as usize
+                    };
+                    Some(child(PathSegment::Index(index), array.get(index)?))
+                })
+                .into_iter()
+                .collect(),
+            Self::Wildcard => children(path, value),
+            Self::Filter(expression) => children(path, value)
+                .into_iter()
+                .filter(|(_, value)| expression.test(root, value))
+                .collect(),
+        }
+    }
+}
+
+impl Expression {
+    fn test(&self, root: &Value, current: &Value) -> bool {
+        match self {
+            Self::Or(left, right) => left.test(root, current) || right.test(root, current),
+            Self::And(left, right) => left.test(root, current) && right.test(root, current),
+            Self::Not(expression) => !expression.test(root, current),
+            Self::Exists(query) => !query.select(root, current).is_empty(),
+            Self::Comparison(left, comparison, right) => {
+                let left = left.value(root, current);
+                let right = right.value(root, current);
+                compare(left.as_ref(), *comparison, right.as_ref())
+            }
+        }
+    }
+}
+
+impl Query {
+    fn select<'v>(&self, root: &'v Value, current: &'v Value) -> Vec<Node<'v>> {
+        self.path
+            .select_nodes(root, if self.is_root { root } else { current })
+    }
+}
+
+impl Comparable {
+    /// Resolve value of the comparable. Queries not selecting exactly one node have no value.
+    fn value(&self, root: &Value, current: &Value) -> Option<Value> {
+        match self {
+            Self::Literal(value) => Some(value.clone()),
+            Self::Query(query) => match query.select(root, current).as_slice() {
+                [(_, value)] => Some((*value).clone()),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn compare(left: Option<&Value>, comparison: Comparison, right: Option<&Value>) -> bool {
+    let is_equal = match (left, right) {
+        (Some(Value::Number(left)), Some(Value::Number(right))) => left.as_f64() == right.as_f64(),
+        (left, right) => left == right,
+    };
+    let is_less = match (left, right) {
+        (Some(Value::Number(left)), Some(Value::Number(right))) => left.as_f64() < right.as_f64(),
+        (Some(Value::String(left)), Some(Value::String(right))) => left < right,
+        _ => false,
+    };
+
+    match comparison {
+        Comparison::Eq => is_equal,
+        Comparison::Ne => !is_equal,
+        Comparison::Lt => is_less,
+        Comparison::Le => is_less || is_equal,
+        Comparison::Gt => compare(right, Comparison::Lt, left),
+        Comparison::Ge => compare(right, Comparison::Le, left),
+    }
+}
+
+struct Parser<'p> {
+    chars: Peekable<Chars<'p>>,
+}
+
+impl Parser<'_> {
+    fn parse_segments(&mut self) -> Result<JsonPath, String> {
+        let mut segments = Vec::new();
+        loop {
+            match self.chars.peek() {
+                Some('.') => {
+                    self.chars.next();
+                    let is_descendant = self.chars.next_if_eq(&'.').is_some();
+                    let selectors = if is_descendant && self.chars.peek() == Some(&'[') {
+                        self.chars.next();
+                        self.parse_bracketed_selectors()?
+                    } else {
+                        vec![self.parse_shorthand_selector()?]
+                    };
+                    segments.push(Segment {
+                        is_descendant,
+                        selectors,
+                    });
+                }
+                Some('[') => {
+                    self.chars.next();
+                    segments.push(Segment {
+                        is_descendant: false,
+                        selectors: self.parse_bracketed_selectors()?,
+                    });
+                }
+                _ => return Ok(JsonPath { segments }),
+            }
+        }
+    }
+
+    fn parse_shorthand_selector(&mut self) -> Result<Selector, String> {
+        if self.chars.next_if_eq(&'*').is_some() {
+            return Ok(Selector::Wildcard);
+        }
+
+        let mut name = String::new();
+        while let Some(char) = self
+            .chars
+            .next_if(|char| char.is_alphanumeric() || matches!(char, '_' | '-' | '$'))
+        {
+            name.push(char);
+        }
+
+        if name.is_empty() {
+            Err("expected name or `*` after `.`".to_string())
+        } else {
+            Ok(Selector::Name(name))
+        }
+    }
+
+    fn parse_bracketed_selectors(&mut self) -> Result<Vec<Selector>, String> {
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let selector = match self.chars.peek() {
+                Some('\'' | '"') => Selector::Name(self.parse_string()?),
+                Some('*') => {
+                    self.chars.next();
+                    Selector::Wildcard
+                }
+                Some('?') => {
+                    self.chars.next();
+                    Selector::Filter(self.parse_or()?)
+                }
+                Some(char) if char.is_ascii_digit() || *char == '-' => {
+                    let index = self.parse_number()?;
+                    Selector::Index(
+                        index
+                            .as_i64()
+                            .ok_or_else(|| format!("invalid index `{index}`"))?,
+                    )
+                }
+                _ => return Err("expected selector within `[]`".to_string()),
+            };
+            selectors.push(selector);
+
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(',') => (),
+                Some(']') => return Ok(selectors),
+                _ => return Err("expected `,` or `]`".to_string()),
+            }
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_and()?;
+        while self.eat("||") {
+            expression = Expression::Or(Box::new(expression), Box::new(self.parse_and()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_unary()?;
+        while self.eat("&&") {
+            expression = Expression::And(Box::new(expression), Box::new(self.parse_unary()?));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, String> {
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'!') && !self.eat("!=") {
+            self.chars.next();
+            return Ok(Expression::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat("(") {
+            let expression = self.parse_or()?;
+            if !self.eat(")") {
+                return Err("expected `)`".to_string());
+            }
+            return Ok(expression);
+        }
+
+        let left = self.parse_comparable()?;
+        let comparison = [
+            ("==", Comparison::Eq),
+            ("!=", Comparison::Ne),
+            ("<=", Comparison::Le),
+            (">=", Comparison::Ge),
+            ("<", Comparison::Lt),
+            (">", Comparison::Gt),
+        ]
+        .into_iter()
+        .find_map(|(token, comparison)| self.eat(token).then_some(comparison));
+
+        match (left, comparison) {
+            (left, Some(comparison)) => Ok(Expression::Comparison(
+                left,
+                comparison,
+                self.parse_comparable()?,
+            )),
+            (Comparable::Query(query), None) => Ok(Expression::Exists(query)),
+            (Comparable::Literal(_), None) => Err("expected comparison after literal".to_string()),
+        }
+    }
+
+    fn parse_comparable(&mut self) -> Result<Comparable, String> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some(identifier @ ('@' | '$')) => {
+                let is_root = *identifier == '$';
+                self.chars.next();
+                Ok(Comparable::Query(Query {
+                    is_root,
+                    path: self.parse_segments()?,
+                }))
+            }
+            Some('\'' | '"') => Ok(Comparable::Literal(Value::String(self.parse_string()?))),
+            Some(char) if char.is_ascii_digit() || *char == '-' => {
+                Ok(Comparable::Literal(Value::Number(self.parse_number()?)))
+            }
+            _ => [
+                ("true", Value::Bool(true)),
+                ("false", Value::Bool(false)),
+                ("null", Value::Null),
+            ]
+            .into_iter()
+            .find_map(|(token, value)| self.eat(token).then_some(Comparable::Literal(value)))
+            .ok_or_else(|| "expected query or literal in filter".to_string()),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        let Some(quote) = self.chars.next() else {
+            return Err("expected string".to_string());
+        };
+
+        let mut string = String::new();
+        loop {
+            match self.chars.next() {
+                Some('\\') => match self.chars.next() {
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    Some(char) => string.push(char),
+                    None => return Err("unterminated string".to_string()),
+                },
+                Some(char) if char == quote => return Ok(string),
+                Some(char) => string.push(char),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<serde_json::Number, String> {
+        let mut number = String::new();
+        while let Some(char) = self
+            .chars
+            .next_if(|char| char.is_ascii_digit() || matches!(char, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            number.push(char);
+        }
+
+        serde_json::from_str(&number).map_err(|_| format!("invalid number `{number}`"))
+    }
+
+    /// Consume `token` after optional whitespace if next characters match it.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let mut lookahead = self.chars.clone();
+        if token.chars().all(|char| lookahead.next() == Some(char)) {
+            self.chars = lookahead;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|char| char.is_whitespace()).is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder, Required};
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        let parameter = |name: &str, parameter_in: ParameterIn| {
+            ParameterBuilder::new()
+                .name(name)
+                .parameter_in(parameter_in)
+                .required(Required::False)
+        };
+
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .operation_id(Some("list_pets"))
+                                .tag("animals")
+                                .parameter(parameter("limit", ParameterIn::Query))
+                                .parameter(parameter("x-debug", ParameterIn::Header))
+                                .parameter(parameter("x-trace", ParameterIn::Header)),
+                        ),
+                    )
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Delete,
+                            OperationBuilder::new()
+                                .operation_id(Some("delete_pet"))
+                                .description(Some("Delete pet")),
+                        ),
+                    ),
+            )
+            .build()
+    }
+
+    fn select(path: &str, document: &Value) -> Vec<String> {
+        JsonPath::parse(path)
+            .expect("path must be valid")
+            .select(document)
+            .into_iter()
+            .map(|path| {
+                path.iter()
+                    .map(|segment| match segment {
+                        PathSegment::Key(key) => format!("/{key}"),
+                        PathSegment::Index(index) => format!("/{index}"),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn json_path_selects_nodes() {
+        let document = json!({
+            "a": {"b": [{"c": 1, "d": "x"}, {"c": 2}, {"c": 3, "d": "y"}]},
+            "e": {"c": 4}
+        });
+
+        assert_eq!(select("$.a.b[-1].c", &document), ["/a/b/2/c"]);
+        assert_eq!(
+            select("$['a'][\"b\"][0,1].c", &document),
+            ["/a/b/0/c", "/a/b/1/c"]
+        );
+        assert_eq!(select("$.a.b[*].d", &document), ["/a/b/0/d", "/a/b/2/d"]);
+        assert_eq!(
+            select("$..c", &document),
+            ["/a/b/0/c", "/a/b/1/c", "/a/b/2/c", "/e/c"]
+        );
+        assert_eq!(select("$.a.b[?@.c > 1 && !@.d]", &document), ["/a/b/1"]);
+        assert_eq!(
+            select("$.a.b[?(@.d == 'x' || @.c == 3)].c", &document),
+            ["/a/b/0/c", "/a/b/2/c"]
+        );
+        assert_eq!(select("$.*[?@ == 4]", &document), ["/e/c"]);
+
+        for invalid in ["a.b", "$.", "$[", "$[?@.a ==]", "$['a'"] {
+            assert!(
+                JsonPath::parse(invalid).is_err(),
+                "{invalid} must be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_overlay_updates_and_removes_nodes() {
+        let mut api = api();
+        let overlay = Overlay::new(
+            "Docs",
+            "1.0.0",
+            [
+                Action::update("$.info", json!({"description": "Pet store"})),
+                Action::update("$.paths.*.*", json!({"x-audience": "public"})),
+                Action::update("$.paths['/pets'].get.tags", "pets"),
+                Action::remove("$.paths.*.*.parameters[?@.in == 'header']"),
+                Action::remove("$..description"),
+            ],
+        );
+
+        api.apply_overlay(&overlay).expect("overlay must apply");
+
+        let api = serde_json::to_value(api).expect("api must serialize");
+        assert_eq!(api["info"].get("description"), None);
+        assert_eq!(
+            api["paths"]["/pets"]["get"],
+            json!({
+                "operationId": "list_pets",
+                "parameters": [{"name": "limit", "in": "query", "required": false}],
+                "responses": {},
+                "tags": ["animals", "pets"],
+                "x-audience": "public",
+            })
+        );
+        assert_eq!(
+            api["paths"]["/pets/{id}"]["delete"],
+            json!({
+                "operationId": "delete_pet",
+                "responses": {},
+                "x-audience": "public",
+            })
+        );
+    }
+
+    #[test]
+    fn apply_overlay_reports_errors() {
+        let mut api = api();
+
+        let invalid_target = Overlay::new("Docs", "1.0.0", [Action::remove("paths")]);
+        assert!(matches!(
+            api.apply_overlay(&invalid_target),
+            Err(OverlayError::InvalidTarget { .. })
+        ));
+
+        let invalid_document = Overlay::new(
+            "Docs",
+            "1.0.0",
+            [
+                Action::update("$.info", json!({"description": "Pet store"})),
+                Action::update("$.paths", json!({"/pets": "invalid"})),
+            ],
+        );
+        assert!(matches!(
+            api.apply_overlay(&invalid_document),
+            Err(OverlayError::InvalidDocument { .. })
+        ));
+        assert_eq!(api, self::api());
+    }
+
+    #[cfg(all(feature = "preserve_order", feature = "preserve_path_order"))]
+    #[test]
+    fn apply_overlay_preserves_order() {
+        use crate::openapi::{ComponentsBuilder, ObjectBuilder};
+
+        // order is kept only when some crate of the build enables `serde_json/preserve_order`
+        let map = serde_json::Map::from_iter([
+            (String::from("b"), Value::Null),
+            (String::from("a"), Value::Null),
+        ]);
+        if map.keys().next().map(String::as_str) != Some("b") {
+            return;
+        }
+
+        let mut api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+                    )
+                    .path(
+                        "/owners",
+                        PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new())
+                            .property("age", ObjectBuilder::new()),
+                    )
+                    .build(),
+            ))
+            .build();
+        let expected = api.clone();
+
+        api.apply_overlay(&Overlay::new("Docs", "1.0.0", []))
+            .expect("overlay must apply");
+
+        assert_eq!(
+            api.to_json().expect("api must serialize"),
+            expected.to_json().expect("api must serialize")
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn parse_yaml_overlay() {
+        let overlay = Overlay::from_yaml(
+            r#"
+overlay: 1.0.0
+info:
+  title: Docs
+  version: 1.0.0
+actions:
+  - target: $.info
+    description: Add contact
+    update:
+      contact:
+        name: Docs team
+  - target: $.paths['/pets/{id}']
+    remove: true
+"#,
+        )
+        .expect("overlay must parse");
+
+        assert_eq!(
+            serde_json::to_value(overlay.actions).expect("actions must serialize"),
+            json!([
+                {
+                    "target": "$.info",
+                    "description": "Add contact",
+                    "update": {"contact": {"name": "Docs team"}},
+                },
+                {"target": "$.paths['/pets/{id}']", "remove": true},
+            ])
+        );
+    }
+}
@@ -1,17 +1,20 @@
 //! Implements [OpenAPI Path Object][paths] types.
 //!
 //! [paths]: https://spec.openapis.org/oas/latest.html#paths-object
+use std::collections::BTreeMap;
+
 use crate::Path;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
     builder,
+    callback::Callback,
     extensions::Extensions,
     request_body::RequestBody,
     response::{Response, Responses},
     security::SecurityRequirement,
-    set_value, Deprecated, ExternalDocs, RefOr, Required, Schema, Server,
+    set_value, Deprecated, ExternalDocs, Ref, RefOr, Required, Schema, Server,
 };
 
 #[cfg(not(feature = "preserve_path_order"))]
@@ -193,28 +196,7 @@ impl PathsBuilder {
     /// let _ = paths.path_from::<MyPath>();
     /// ```
     pub fn path_from<P: Path>(self) -> Self {
-        let methods = P::methods();
-        let operation = P::operation();
-
-        // for one operation method avoid clone
-        let path_item = if methods.len() == 1 {
-            PathItem::new(
-                methods
-                    .into_iter()
-                    .next()
-                    .expect("must have one operation method"),
-                operation,
-            )
-        } else {
-            methods
-                .into_iter()
-                .fold(PathItemBuilder::new(), |path_item, method| {
-                    path_item.operation(method, operation.clone())
-                })
-                .build()
-        };
-
-        self.path(P::path(), path_item)
+        self.path(P::path(), PathItem::from_path::<P>())
     }
 }
 
@@ -247,8 +229,13 @@ builder! {
         /// List of [`Parameter`]s common to all [`Operation`]s in this [`PathItem`]. Parameters cannot
         /// contain duplicate parameters. They can be overridden in [`Operation`] level but cannot be
         /// removed there.
+        ///
+        /// Parameters can be either inlined [`Parameter`]s or [`Ref`]s to reusable parameters of
+        /// the [`Components`][components].
+        ///
+        /// [components]: ../schema/struct.Components.html
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Get [`Operation`] for the [`PathItem`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -306,6 +293,67 @@ impl PathItem {
         path_item
     }
 
+    /// Iterate over [`Operation`]s of this [`PathItem`] together with the [`HttpMethod`] they
+    /// are mapped to. Operations are returned in the order they are defined in the OpenAPI
+    /// specification.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::path::{HttpMethod, OperationBuilder, PathItem};
+    /// let path_item = PathItem::new(HttpMethod::Get, OperationBuilder::new());
+    /// assert_eq!(path_item.operations().count(), 1);
+    /// ```
+    pub fn operations(&self) -> impl Iterator<Item = (HttpMethod, &Operation)> {
+        [
+            (HttpMethod::Get, &self.get),
+            (HttpMethod::Put, &self.put),
+            (HttpMethod::Post, &self.post),
+            (HttpMethod::Delete, &self.delete),
+            (HttpMethod::Options, &self.options),
+            (HttpMethod::Head, &self.head),
+            (HttpMethod::Patch, &self.patch),
+            (HttpMethod::Trace, &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(http_method, operation)| {
+            operation.as_ref().map(|operation| (http_method, operation))
+        })
+    }
+
+    /// Iterate over mutable [`Operation`]s of this [`PathItem`] together with the [`HttpMethod`]
+    /// they are mapped to.
+    pub fn operations_mut(&mut self) -> impl Iterator<Item = (HttpMethod, &mut Operation)> {
+        [
+            (HttpMethod::Get, &mut self.get),
+            (HttpMethod::Put, &mut self.put),
+            (HttpMethod::Post, &mut self.post),
+            (HttpMethod::Delete, &mut self.delete),
+            (HttpMethod::Options, &mut self.options),
+            (HttpMethod::Head, &mut self.head),
+            (HttpMethod::Patch, &mut self.patch),
+            (HttpMethod::Trace, &mut self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(http_method, operation)| {
+            operation.as_mut().map(|operation| (http_method, operation))
+        })
+    }
+
+    /// Remove [`Operation`] mapped to given [`HttpMethod`] returning the removed operation.
+    pub(super) fn remove_operation(&mut self, http_method: HttpMethod) -> Option<Operation> {
+        match http_method {
+            HttpMethod::Get => self.get.take(),
+            HttpMethod::Put => self.put.take(),
+            HttpMethod::Post => self.post.take(),
+            HttpMethod::Delete => self.delete.take(),
+            HttpMethod::Options => self.options.take(),
+            HttpMethod::Head => self.head.take(),
+            HttpMethod::Patch => self.patch.take(),
+            HttpMethod::Trace => self.trace.take(),
+        }
+    }
+
     /// Constructs a new [`PathItem`] with given [`Operation`] set for provided [`HttpMethod`]s.
     pub fn from_http_methods<I: IntoIterator<Item = HttpMethod>, O: Into<Operation>>(
         http_methods: I,
@@ -329,6 +377,30 @@ impl PathItem {
         path_item
     }
 
+    /// Constructs a new [`PathItem`] from [`Operation`] and [`HttpMethod`]s of given [`trait@Path`].
+    pub(super) fn from_path<P: Path>() -> Self {
+        let methods = P::methods();
+        let operation = P::operation();
+
+        // for one operation method avoid clone
+        if methods.len() == 1 {
+            PathItem::new(
+                methods
+                    .into_iter()
+                    .next()
+                    .expect("must have one operation method"),
+                operation,
+            )
+        } else {
+            methods
+                .into_iter()
+                .fold(PathItemBuilder::new(), |path_item, method| {
+                    path_item.operation(method, operation.clone())
+                })
+                .build()
+        }
+    }
+
     /// Merge all defined [`Operation`]s from given [`PathItem`] to `self` if `self` does not have
     /// existing operation.
     pub fn merge_operations(&mut self, path_item: PathItem) {
@@ -395,8 +467,11 @@ impl PathItemBuilder {
     }
 
     /// Append list of [`Parameter`]s common to all [`Operation`]s to this [`PathItem`].
-    pub fn parameters<I: IntoIterator<Item = Parameter>>(mut self, parameters: Option<I>) -> Self {
-        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().collect()))
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        parameters: Option<I>,
+    ) -> Self {
+        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().map(Into::into).collect()))
     }
 
     /// Add openapi extensions (x-something) to this [`PathItem`].
@@ -405,6 +480,18 @@ impl PathItemBuilder {
     }
 }
 
+impl From<PathItemBuilder> for RefOr<PathItem> {
+    fn from(builder: PathItemBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<PathItem> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 /// HTTP method of the operation.
 ///
 /// List of supported HTTP methods <https://spec.openapis.org/oas/latest.html#path-item-object>
@@ -430,6 +517,22 @@ pub enum HttpMethod {
     Trace,
 }
 
+impl HttpMethod {
+    /// Lowercase name of the [`HttpMethod`] as used in [`PathItem`] fields.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+            Self::Options => "options",
+            Self::Head => "head",
+            Self::Patch => "patch",
+            Self::Trace => "trace",
+        }
+    }
+}
+
 builder! {
     OperationBuilder;
 
@@ -485,8 +588,13 @@ builder! {
         pub external_docs: Option<ExternalDocs>,
 
         /// List of applicable parameters for this [`Operation`].
+        ///
+        /// Parameters can be either inlined [`Parameter`]s or [`Ref`]s to reusable parameters of
+        /// the [`Components`][components].
+        ///
+        /// [components]: ../schema/struct.Components.html
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Optional request body for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -495,10 +603,15 @@ builder! {
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
 
-        // TODO
-        #[allow(missing_docs)]
+        /// Map of possible out-of band [`Callback`]s related to the [`Operation`]. The key is
+        /// unique identifier of the [`Callback`].
+        ///
+        /// When used with derive [`#[utoipa::path(...)]`][derive_path] attribute macro the
+        /// callbacks can be defined with _`callbacks(...)`_ attribute.
+        ///
+        /// [derive_path]: ../../attr.path.html
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub callbacks: Option<String>,
+        pub callbacks: Option<BTreeMap<String, RefOr<Callback>>>,
 
         /// Define whether the operation is deprecated or not and thus should be avoided consuming.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -564,7 +677,7 @@ impl OperationBuilder {
     }
 
     /// Add or change parameters of the [`Operation`].
-    pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
         mut self,
         parameters: Option<I>,
     ) -> Self {
@@ -584,7 +697,10 @@ impl OperationBuilder {
     }
 
     /// Append parameter to [`Operation`] parameters.
-    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+    ///
+    /// Parameter can be either inlined [`Parameter`] or [`Ref`] to reusable parameter e.g.
+    /// `Ref::from_parameter_name("page")`.
+    pub fn parameter<P: Into<RefOr<Parameter>>>(mut self, parameter: P) -> Self {
         match self.parameters {
             Some(ref mut parameters) => parameters.push(parameter.into()),
             None => {
@@ -621,6 +737,52 @@ impl OperationBuilder {
         self
     }
 
+    /// Add or change [`Callback`]s of the [`Operation`]. Callbacks are given as iterator of
+    /// unique callback name and the [`Callback`] or reference to a [`Callback`].
+    pub fn callbacks<I: IntoIterator<Item = (N, C)>, N: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        callbacks: Option<I>,
+    ) -> Self {
+        set_value!(self callbacks callbacks.map(|callbacks| callbacks
+            .into_iter()
+            .map(|(name, callback)| (name.into(), callback.into()))
+            .collect()))
+    }
+
+    /// Append [`Callback`] with unique _`name`_ to [`Operation`] callbacks.
+    ///
+    /// # Examples
+    ///
+    /// _**Add callback which is called when payment status changes.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, PathItem, Response};
+    /// # use utoipa::openapi::callback::CallbackBuilder;
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let operation = OperationBuilder::new()
+    ///     .callback(
+    ///         "paymentStatus",
+    ///         CallbackBuilder::new().path(
+    ///             "{$request.body#/callbackUrl}",
+    ///             PathItem::new(
+    ///                 HttpMethod::Post,
+    ///                 OperationBuilder::new().response("200", Response::new("Status received")),
+    ///             ),
+    ///         ),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn callback<N: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: N,
+        callback: C,
+    ) -> Self {
+        self.callbacks
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), callback.into());
+
+        self
+    }
+
     /// Add or change deprecated status of the [`Operation`].
     pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
         set_value!(self deprecated deprecated)
@@ -734,7 +896,7 @@ builder! {
         /// Example of [`Parameter`]'s potential value. This examples will override example
         /// within [`Parameter::schema`] if defined.
         #[serde(skip_serializing_if = "Option::is_none")]
-        example: Option<Value>,
+        pub(crate) example: Option<Value>,
 
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
@@ -869,6 +1031,18 @@ pub enum ParameterStyle {
     DeepObject,
 }
 
+impl From<ParameterBuilder> for RefOr<Parameter> {
+    fn from(builder: ParameterBuilder) -> Self {
+        Self::T(builder.build())
+    }
+}
+
+impl From<Ref> for RefOr<Parameter> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{HttpMethod, Operation, OperationBuilder};
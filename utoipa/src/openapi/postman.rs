@@ -0,0 +1,677 @@
+//! Implements export of [`OpenApi`] documents to [Postman Collection v2.1][postman] format.
+//!
+//! [postman]: https://schema.postman.com/collection/json/v2.1.0/draft-07/docs/index.html
+use std::collections::BTreeSet;
+
+use serde_json::{json, Value};
+
+use super::path::{Operation, Parameter, ParameterIn};
+use super::security::{ApiKey, Flow, HttpAuthScheme, SecurityRequirement, SecurityScheme};
+use super::{Content, HttpMethod, OpenApi, PathItem, RefOr, Required};
+
+/// Postman Collection v2.1 schema URL.
+const SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+impl OpenApi {
+    /// Export this [`OpenApi`] as [Postman Collection v2.1][postman] JSON value.
+    ///
+    /// Exported collection can be imported to Postman, Insomnia and other API clients:
+    /// * Requests are grouped to folders by the first tag of the operation in order of
+    ///   top level _`tags`_. Requests of operations without tags are placed to the root of the
+    ///   collection.
+    /// * Request URLs start with _`{{baseUrl}}`_ collection variable which defaults to the URL
+    ///   of the first [`Server`][server]. Path parameters are exported as path variables e.g.
+    ///   _`/pets/:id`_, and query, header and cookie parameters are exported as query
+    ///   parameters and headers which are disabled unless required. Parameter values are
+    ///   examples generated with [`OpenApi::generate_example`].
+    /// * Request bodies and saved response examples use the _`example`_ of the media type, the
+    ///   first of its _`examples`_ or an example generated from its schema.
+    /// * Authorization is derived from the first scheme of the first [`SecurityRequirement`]:
+    ///   _`Http`_ _`bearer`_ and _`basic`_, _`ApiKey`_ in _`header`_, _`query`_ or _`cookie`_
+    ///   and the first _`OAuth2`_ flow are supported. Credentials are left to collection
+    ///   variables e.g. _`{{bearerToken}}`_ or _`{{clientId}}`_. Top level security is exported as
+    ///   authorization of the collection and operation level security as authorization of the
+    ///   request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, InfoBuilder, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    /// # use utoipa::openapi::server::Server;
+    /// let api = OpenApiBuilder::new()
+    ///     .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+    ///     .servers(Some([Server::new("https://pets.example.com")]))
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets/{id}",
+    ///         PathItem::new(
+    ///             HttpMethod::Get,
+    ///             OperationBuilder::new()
+    ///                 .tag("pets")
+    ///                 .summary(Some("Get pet"))
+    ///                 .parameter(ParameterBuilder::new().name("id").parameter_in(ParameterIn::Path)),
+    ///         ),
+    ///     ))
+    ///     .build();
+    ///
+    /// let collection = api.to_postman_collection();
+    ///
+    /// assert_eq!(collection["info"]["name"], "Pets");
+    /// assert_eq!(collection["variable"][0]["value"], "https://pets.example.com");
+    /// assert_eq!(collection["item"][0]["name"], "pets");
+    /// assert_eq!(collection["item"][0]["item"][0]["request"]["url"]["raw"], "{{baseUrl}}/pets/:id");
+    /// ```
+    ///
+    /// [postman]: https://schema.postman.com/collection/json/v2.1.0/draft-07/docs/index.html
+    /// [server]: crate::openapi::server::Server
+    pub fn to_postman_collection(&self) -> Value {
+        let mut exporter = Exporter {
+            api: self,
+            variables: BTreeSet::new(),
+        };
+
+        let mut folders = self
+            .tags
+            .iter()
+            .flatten()
+            .map(|tag| (tag.name.as_str(), tag.description.as_deref(), Vec::new()))
+            .collect::<Vec<_>>();
+        let mut items = Vec::new();
+        for (path, path_item) in &self.paths.paths {
+            for (http_method, operation) in path_item.operations() {
+                let item = exporter.request_item(path, path_item, http_method, operation);
+                match operation.tags.iter().flatten().next() {
+                    Some(tag) => match folders.iter_mut().find(|(name, _, _)| name == tag) {
+                        Some((_, _, folder)) => folder.push(item),
+                        None => folders.push((tag, None, vec![item])),
+                    },
+                    None => items.push(item),
+                }
+            }
+        }
+
+        let mut collection_items = folders
+            .into_iter()
+            .filter(|(_, _, folder)| !folder.is_empty())
+            .map(|(name, description, folder)| {
+                let mut folder = json!({ "name": name, "item": folder });
+                if let Some(description) = description {
+                    folder["description"] = Value::from(description);
+                }
+                folder
+            })
+            .collect::<Vec<_>>();
+        collection_items.extend(items);
+
+        let mut info = json!({ "name": self.info.title, "schema": SCHEMA });
+        if let Some(description) = &self.info.description {
+            info["description"] = Value::from(description.as_str());
+        }
+        let mut collection = json!({ "info": info, "item": collection_items });
+        if let Some(auth) = self
+            .security
+            .as_ref()
+            .and_then(|security| exporter.auth(security))
+        {
+            collection["auth"] = auth;
+        }
+
+        let base_url = self
+            .servers
+            .iter()
+            .flatten()
+            .next()
+            .map(|server| server.url.trim_end_matches('/'))
+            .unwrap_or_default();
+        let mut variables = vec![json!({ "key": "baseUrl", "value": base_url })];
+        variables.extend(
+            exporter
+                .variables
+                .into_iter()
+                .map(|variable| json!({ "key": variable, "value": "" })),
+        );
+        collection["variable"] = Value::Array(variables);
+
+        collection
+    }
+}
+
+struct Exporter<'a> {
+    api: &'a OpenApi,
+    /// Names of the credential variables used by the authorizations.
+    variables: BTreeSet<String>,
+}
+
+impl<'a> Exporter<'a> {
+    fn request_item(
+        &mut self,
+        path: &str,
+        path_item: &'a PathItem,
+        http_method: HttpMethod,
+        operation: &'a Operation,
+    ) -> Value {
+        let method = http_method.as_str().to_uppercase();
+        let name = operation
+            .summary
+            .clone()
+            .or_else(|| operation.operation_id.clone())
+            .unwrap_or_else(|| format!("{method} {path}"));
+
+        let parameters = self.parameters(path_item, operation);
+        let mut headers = Vec::new();
+        let mut cookies = Vec::new();
+        let mut query = Vec::new();
+        let mut path_variables = Vec::new();
+        for parameter in parameters {
+            let value = self.parameter_value(parameter);
+            let mut entry = json!({ "key": parameter.name, "value": value });
+            if let Some(description) = &parameter.description {
+                entry["description"] = Value::from(description.as_str());
+            }
+            let required = matches!(parameter.required, Required::True);
+            match parameter.parameter_in {
+                ParameterIn::Path => path_variables.push(entry),
+                ParameterIn::Query => {
+                    entry["disabled"] = Value::Bool(!required);
+                    query.push(entry);
+                }
+                ParameterIn::Header => {
+                    entry["disabled"] = Value::Bool(!required);
+                    headers.push(entry);
+                }
+                ParameterIn::Cookie => cookies.push(format!("{}={value}", parameter.name)),
+            }
+        }
+        if !cookies.is_empty() {
+            headers.push(json!({ "key": "Cookie", "value": cookies.join("; ") }));
+        }
+
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.replace('{', ":").replace('}', ""))
+            .collect::<Vec<_>>();
+        let mut raw = format!("{{{{baseUrl}}}}/{}", segments.join("/"));
+        let enabled_query = query
+            .iter()
+            .filter(|entry| entry["disabled"] == Value::Bool(false))
+            .map(|entry| format!("{}={}", text(&entry["key"]), text(&entry["value"])))
+            .collect::<Vec<_>>();
+        if !enabled_query.is_empty() {
+            raw.push('?');
+            raw.push_str(&enabled_query.join("&"));
+        }
+        let mut url = json!({ "raw": raw, "host": ["{{baseUrl}}"], "path": segments });
+        if !query.is_empty() {
+            url["query"] = Value::Array(query);
+        }
+        if !path_variables.is_empty() {
+            url["variable"] = Value::Array(path_variables);
+        }
+
+        let mut request = json!({ "method": method, "url": url });
+        if let Some((content_type, content)) = operation
+            .request_body
+            .as_ref()
+            .and_then(|request_body| request_body.content.iter().next())
+        {
+            headers.push(json!({ "key": "Content-Type", "value": content_type }));
+            request["body"] = self.body(content_type, content);
+        }
+        request["header"] = Value::Array(headers);
+        if let Some(description) = &operation.description {
+            request["description"] = Value::from(description.as_str());
+        }
+        if let Some(security) = &operation.security {
+            request["auth"] = self
+                .auth(security)
+                .unwrap_or_else(|| json!({ "type": "noauth" }));
+        }
+
+        let responses = self.responses(operation, &request);
+
+        json!({ "name": name, "request": request, "response": responses })
+    }
+
+    /// Resolve parameters of the operation. Operation parameters override the path item
+    /// parameters with the same name and location.
+    fn parameters(&self, path_item: &'a PathItem, operation: &'a Operation) -> Vec<&'a Parameter> {
+        let api = self.api;
+        let resolve = |parameter: &'a RefOr<Parameter>| match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => api.resolve_parameter(reference),
+        };
+        let operation_parameters = operation
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .collect::<Vec<_>>();
+
+        let mut parameters = path_item
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .filter(|parameter| {
+                !operation_parameters.iter().any(|overriding| {
+                    overriding.name == parameter.name
+                        && overriding.parameter_in == parameter.parameter_in
+                })
+            })
+            .collect::<Vec<_>>();
+        parameters.extend(operation_parameters);
+
+        parameters
+    }
+
+    fn parameter_value(&self, parameter: &Parameter) -> String {
+        parameter
+            .schema
+            .as_ref()
+            .map(|schema| match self.api.generate_example(schema) {
+                Value::Null => String::new(),
+                value => text(&value),
+            })
+            .unwrap_or_default()
+    }
+
+    fn example(&self, content: &Content) -> Option<Value> {
+        content
+            .example
+            .clone()
+            .or_else(|| {
+                content.examples.values().find_map(|example| match example {
+                    RefOr::T(example) => example.value.clone(),
+                    RefOr::Ref(reference) => self
+                        .api
+                        .resolve_example(reference)
+                        .and_then(|example| example.value.clone()),
+                })
+            })
+            .or_else(|| {
+                content
+                    .schema
+                    .as_ref()
+                    .map(|schema| self.api.generate_example(schema))
+            })
+    }
+
+    fn body(&self, content_type: &str, content: &Content) -> Value {
+        let example = self.example(content).unwrap_or(Value::Null);
+        let form = |value_key: &str| {
+            example
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(key, value)| json!({ "key": key, value_key: text(value), "type": "text" }))
+                .collect::<Vec<_>>()
+        };
+
+        match content_type {
+            "application/x-www-form-urlencoded" => {
+                json!({ "mode": "urlencoded", "urlencoded": form("value") })
+            }
+            "multipart/form-data" => json!({ "mode": "formdata", "formdata": form("value") }),
+            content_type if content_type.contains("json") => json!({
+                "mode": "raw",
+                "raw": serde_json::to_string_pretty(&example).unwrap_or_default(),
+                "options": { "raw": { "language": "json" } },
+            }),
+            _ => json!({ "mode": "raw", "raw": text(&example) }),
+        }
+    }
+
+    /// Saved response examples of the operation.
+    fn responses(&self, operation: &Operation, request: &Value) -> Vec<Value> {
+        operation
+            .responses
+            .responses
+            .iter()
+            .filter_map(|(status, response)| {
+                let response = match response {
+                    RefOr::T(response) => response,
+                    RefOr::Ref(reference) => self.api.resolve_response(reference)?,
+                };
+                let (content_type, content) = response.content.iter().next()?;
+                let example = self.example(content)?;
+                let body = if content_type.contains("json") {
+                    serde_json::to_string_pretty(&example).unwrap_or_default()
+                } else {
+                    text(&example)
+                };
+
+                let mut saved = json!({
+                    "name": format!("{status} {}", response.description).trim(),
+                    "originalRequest": request,
+                    "header": [{ "key": "Content-Type", "value": content_type }],
+                    "body": body,
+                });
+                if let Ok(code) = status.parse::<u16>() {
+                    saved["code"] = Value::from(code);
+                }
+                if content_type.contains("json") {
+                    saved["_postman_previewlanguage"] = Value::from("json");
+                }
+                Some(saved)
+            })
+            .collect()
+    }
+
+    /// Postman authorization of the first scheme of the first security requirement or `None`
+    /// if there is no requirement or the scheme is not supported.
+    fn auth(&mut self, security: &[SecurityRequirement]) -> Option<Value> {
+        let (name, scopes) = security.first()?.value.iter().next()?;
+        let scheme = self.api.components.as_ref()?.security_schemes.get(name)?;
+        let attributes = |entries: &[(&str, Value)]| {
+            entries
+                .iter()
+                .map(|(key, value)| json!({ "key": key, "value": value, "type": "string" }))
+                .collect::<Vec<_>>()
+        };
+
+        let auth = match scheme {
+            SecurityScheme::Http(http) => match http.scheme {
+                HttpAuthScheme::Bearer => {
+                    let token = self.variable("bearerToken");
+                    json!({ "type": "bearer", "bearer": attributes(&[("token", token)]) })
+                }
+                HttpAuthScheme::Basic => {
+                    let username = self.variable("username");
+                    let password = self.variable("password");
+                    json!({
+                        "type": "basic",
+                        "basic": attributes(&[("username", username), ("password", password)]),
+                    })
+                }
+                _ => return None,
+            },
+            SecurityScheme::ApiKey(api_key) => {
+                let (key, value, location) = match api_key {
+                    ApiKey::Header(api_key) => {
+                        (api_key.name.clone(), self.variable(name), "header")
+                    }
+                    ApiKey::Query(api_key) => (api_key.name.clone(), self.variable(name), "query"),
+                    // Postman does not support api keys in cookies so the cookie is sent as header
+                    ApiKey::Cookie(api_key) => (
+                        String::from("Cookie"),
+                        Value::from(format!("{}={}", api_key.name, text(&self.variable(name)))),
+                        "header",
+                    ),
+                };
+                json!({
+                    "type": "apikey",
+                    "apikey": attributes(&[
+                        ("key", Value::from(key)),
+                        ("value", value),
+                        ("in", Value::from(location)),
+                    ]),
+                })
+            }
+            SecurityScheme::OAuth2(oauth2) => {
+                let flow = oauth2.flows.values().next()?;
+                let (grant_type, auth_url, token_url) = match flow {
+                    Flow::Implicit(flow) => ("implicit", Some(&flow.authorization_url), None),
+                    Flow::Password(flow) => ("password_credentials", None, Some(&flow.token_url)),
+                    Flow::ClientCredentials(flow) => {
+                        ("client_credentials", None, Some(&flow.token_url))
+                    }
+                    Flow::AuthorizationCode(flow) => (
+                        "authorization_code",
+                        Some(&flow.authorization_url),
+                        Some(&flow.token_url),
+                    ),
+                };
+                // all scopes of the flow are requested if the requirement does not list any
+                let scope = if scopes.is_empty() {
+                    serde_json::to_value(flow)
+                        .ok()
+                        .and_then(|flow| {
+                            flow["scopes"]
+                                .as_object()
+                                .map(|scopes| scopes.keys().cloned().collect::<Vec<_>>())
+                        })
+                        .unwrap_or_default()
+                } else {
+                    scopes.clone()
+                };
+
+                let mut entries = vec![
+                    ("grant_type", Value::from(grant_type)),
+                    ("clientId", self.variable("clientId")),
+                ];
+                if grant_type != "implicit" {
+                    entries.push(("clientSecret", self.variable("clientSecret")));
+                }
+                if let Some(auth_url) = auth_url {
+                    entries.push(("authUrl", Value::from(auth_url.as_str())));
+                }
+                if let Some(token_url) = token_url {
+                    entries.push(("accessTokenUrl", Value::from(token_url.as_str())));
+                }
+                if grant_type == "password_credentials" {
+                    entries.push(("username", self.variable("username")));
+                    entries.push(("password", self.variable("password")));
+                }
+                entries.push(("scope", Value::from(scope.join(" "))));
+
+                json!({ "type": "oauth2", "oauth2": attributes(&entries) })
+            }
+            SecurityScheme::OpenIdConnect(_) | SecurityScheme::MutualTls { .. } => return None,
+        };
+
+        Some(auth)
+    }
+
+    /// Register collection variable and return reference to it.
+    fn variable(&mut self, name: &str) -> Value {
+        self.variables.insert(String::from(name));
+        Value::from(format!("{{{{{name}}}}}"))
+    }
+}
+
+/// Value as plain text e.g. strings without quotes.
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder};
+    use crate::openapi::request_body::RequestBodyBuilder;
+    use crate::openapi::security::{ApiKeyValue, ClientCredentials, Http, OAuth2, Scopes};
+    use crate::openapi::server::Server;
+    use crate::openapi::tag::TagBuilder;
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, InfoBuilder, ObjectBuilder, OpenApiBuilder,
+        PathsBuilder, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    #[test]
+    fn to_postman_collection() {
+        let pet = ObjectBuilder::new()
+            .property(
+                "name",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .examples([json!("Lassie")]),
+            )
+            .required("name");
+        let no_scopes: [&str; 0] = [];
+        let api = OpenApiBuilder::new()
+            .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+            .servers(Some([Server::new("https://pets.example.com/")]))
+            .tags(Some([TagBuilder::new()
+                .name("pets")
+                .description(Some("Pet operations"))
+                .build()]))
+            .security(Some([SecurityRequirement::new("session", no_scopes)]))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Put,
+                            OperationBuilder::new()
+                                .tag("pets")
+                                .operation_id(Some("update_pet"))
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("id")
+                                        .parameter_in(ParameterIn::Path)
+                                        .required(Required::True)
+                                        .schema(Some(
+                                            ObjectBuilder::new().schema_type(Type::Integer),
+                                        )),
+                                )
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("dry_run")
+                                        .parameter_in(ParameterIn::Query)
+                                        .schema(Some(
+                                            ObjectBuilder::new().schema_type(Type::Boolean),
+                                        )),
+                                )
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("X-Request-Id")
+                                        .parameter_in(ParameterIn::Header)
+                                        .required(Required::True)
+                                        .schema(Some(
+                                            ObjectBuilder::new()
+                                                .schema_type(Type::String)
+                                                .examples([json!("abc")]),
+                                        )),
+                                )
+                                .request_body(Some(
+                                    RequestBodyBuilder::new()
+                                        .content(
+                                            "application/json",
+                                            ContentBuilder::new().schema(Some(pet)).build(),
+                                        )
+                                        .build(),
+                                ))
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new().description("Pet updated").content(
+                                        "application/json",
+                                        ContentBuilder::new()
+                                            .example(Some(json!({"name": "Rex"})))
+                                            .build(),
+                                    ),
+                                )
+                                .securities(Some([SecurityRequirement::new("oauth", no_scopes)])),
+                        ),
+                    )
+                    .path(
+                        "/health",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .securities(Some([SecurityRequirement::new("bearer", no_scopes)])),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .security_scheme(
+                        "bearer",
+                        SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+                    )
+                    .security_scheme(
+                        "session",
+                        SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("SESSION"))),
+                    )
+                    .security_scheme(
+                        "oauth",
+                        SecurityScheme::OAuth2(OAuth2::new([Flow::ClientCredentials(
+                            ClientCredentials::new(
+                                "https://auth.example.com/token",
+                                Scopes::from_iter([
+                                    ("write:pets", "modify pets"),
+                                    ("read:pets", "read pets"),
+                                ]),
+                            ),
+                        )])),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let collection = api.to_postman_collection();
+
+        assert_eq!(
+            collection["auth"],
+            json!({"type": "apikey", "apikey": [
+                {"key": "key", "value": "Cookie", "type": "string"},
+                {"key": "value", "value": "SESSION={{session}}", "type": "string"},
+                {"key": "in", "value": "header", "type": "string"},
+            ]})
+        );
+        assert_eq!(
+            collection["variable"],
+            json!([
+                {"key": "baseUrl", "value": "https://pets.example.com"},
+                {"key": "bearerToken", "value": ""},
+                {"key": "clientId", "value": ""},
+                {"key": "clientSecret", "value": ""},
+                {"key": "session", "value": ""},
+            ])
+        );
+
+        let folder = &collection["item"][0];
+        assert_eq!(folder["name"], "pets");
+        assert_eq!(folder["description"], "Pet operations");
+        let update_pet = &folder["item"][0];
+        assert_eq!(update_pet["name"], "update_pet");
+        let request = &update_pet["request"];
+        assert_eq!(request["method"], "PUT");
+        assert_eq!(
+            request["url"],
+            json!({
+                "raw": "{{baseUrl}}/pets/:id",
+                "host": ["{{baseUrl}}"],
+                "path": ["pets", ":id"],
+                "query": [{"key": "dry_run", "value": "true", "disabled": true}],
+                "variable": [{"key": "id", "value": "0"}],
+            })
+        );
+        assert_eq!(
+            request["header"],
+            json!([
+                {"key": "X-Request-Id", "value": "abc", "disabled": false},
+                {"key": "Content-Type", "value": "application/json"},
+            ])
+        );
+        assert_eq!(request["body"]["raw"], "{\n  \"name\": \"Lassie\"\n}");
+        assert_eq!(
+            request["auth"],
+            json!({"type": "oauth2", "oauth2": [
+                {"key": "grant_type", "value": "client_credentials", "type": "string"},
+                {"key": "clientId", "value": "{{clientId}}", "type": "string"},
+                {"key": "clientSecret", "value": "{{clientSecret}}", "type": "string"},
+                {"key": "accessTokenUrl", "value": "https://auth.example.com/token", "type": "string"},
+                {"key": "scope", "value": "read:pets write:pets", "type": "string"},
+            ]})
+        );
+        assert_eq!(update_pet["response"][0]["name"], "200 Pet updated");
+        assert_eq!(update_pet["response"][0]["code"], 200);
+        assert_eq!(
+            update_pet["response"][0]["body"],
+            "{\n  \"name\": \"Rex\"\n}"
+        );
+
+        let health = &collection["item"][1];
+        assert_eq!(health["name"], "GET /health");
+        assert_eq!(health["request"]["auth"]["type"], "bearer");
+        assert_eq!(health["response"], json!([]));
+    }
+}
@@ -0,0 +1,380 @@
+//! Implements removal of unreferenced [`Components`] from [`OpenApi`] documents.
+use std::collections::BTreeSet;
+
+use super::schema::{AllOf, AnyOf, OneOf, Schema};
+use super::security::SecurityRequirement;
+use super::validation::unescape_pointer_segment;
+use super::visit::{walk_ref_or, walk_schema, Location, Visit};
+use super::{Components, OpenApi, Ref};
+
+impl OpenApi {
+    /// Remove every component from [`OpenApi::components`] that is not reachable from
+    /// [`OpenApi::paths`], [`OpenApi::webhooks`] or top level [`OpenApi::security`].
+    ///
+    /// Component is reachable when it is referenced with [`RefOr::Ref`][ref] from reachable part
+    /// of the document or from other reachable component. Security schemes are reachable when
+    /// named by top level or [`Operation`][operation] [`SecurityRequirement`]s. Pruning is applied
+    /// to all kinds of components e.g. schemas, responses, parameters and security schemes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder};
+    /// let mut api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Unused", ObjectBuilder::new())
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// api.prune_unused_components();
+    ///
+    /// assert!(api.components.unwrap().schemas.is_empty());
+    /// ```
+    ///
+    /// [ref]: super::RefOr::Ref
+    /// [operation]: super::path::Operation
+    pub fn prune_unused_components(&mut self) {
+        let reachable = self.reachable_components();
+        self.retain_components(|kind, name| reachable.contains(&(kind.to_string(), name.clone())));
+    }
+
+    /// Get `(kind, name)` pairs of every component reachable from [`OpenApi::paths`],
+    /// [`OpenApi::webhooks`] or top level [`OpenApi::security`] e.g. `("schemas", "Pet")`.
+    pub(super) fn reachable_components(&self) -> BTreeSet<(String, String)> {
+        let Some(components) = self.components.as_ref() else {
+            return BTreeSet::new();
+        };
+
+        let mut collector = ReferenceCollector {
+            components,
+            references: BTreeSet::new(),
+        };
+
+        let mut location = Location::new();
+        for path_item in self.paths.paths.values().chain(self.webhooks.values()) {
+            collector.visit_path_item(path_item, &mut location);
+        }
+        for requirement in self.security.iter().flatten() {
+            collector.visit_security_requirement(requirement, &mut location);
+        }
+
+        collector.references
+    }
+
+    /// Retain only components for which `retain` returns `true` when called with kind and name
+    /// of the component e.g. `("schemas", "Pet")`.
+    pub(super) fn retain_components(&mut self, retain: impl Fn(&str, &String) -> bool) {
+        let Some(components) = self.components.as_mut() else {
+            return;
+        };
+
+        components.schemas.retain(|name, _| retain("schemas", name));
+        components
+            .responses
+            .retain(|name, _| retain("responses", name));
+        components
+            .parameters
+            .retain(|name, _| retain("parameters", name));
+        components
+            .examples
+            .retain(|name, _| retain("examples", name));
+        components
+            .request_bodies
+            .retain(|name, _| retain("requestBodies", name));
+        components.headers.retain(|name, _| retain("headers", name));
+        components
+            .security_schemes
+            .retain(|name, _| retain("securitySchemes", name));
+        components.links.retain(|name, _| retain("links", name));
+        components
+            .callbacks
+            .retain(|name, _| retain("callbacks", name));
+        components
+            .path_items
+            .retain(|name, _| retain("pathItems", name));
+    }
+}
+
+/// Collects `(kind, name)` pairs of every reachable component e.g. `("schemas", "Pet")`.
+struct ReferenceCollector<'a> {
+    components: &'a Components,
+    references: BTreeSet<(String, String)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_security_requirement(
+        &mut self,
+        security_requirement: &'a SecurityRequirement,
+        _: &mut Location,
+    ) {
+        for name in security_requirement.value.keys() {
+            self.references
+                .insert(("securitySchemes".to_string(), name.clone()));
+        }
+    }
+
+    /// Mark components named in _`mapping`_ of the schema [`Discriminator`][discriminator]
+    /// reachable before visiting the nested schemas.
+    ///
+    /// [discriminator]: super::schema::Discriminator
+    fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+        if let Schema::OneOf(OneOf { discriminator, .. })
+        | Schema::AllOf(AllOf { discriminator, .. })
+        | Schema::AnyOf(AnyOf { discriminator, .. }) = schema
+        {
+            for reference in discriminator.iter().flat_map(|d| d.mapping.values()) {
+                self.reach(reference);
+            }
+        }
+        walk_schema(self, schema, location);
+    }
+
+    fn visit_ref(&mut self, reference: &'a Ref, _: &mut Location) {
+        self.reach(&reference.ref_location);
+    }
+}
+
+impl<'a> ReferenceCollector<'a> {
+    /// Mark the referenced component reachable and visit it if it was not already visited.
+    /// References pointing inside of a component e.g. `#/components/schemas/Pet/properties/name`
+    /// make the whole component reachable.
+    fn reach(&mut self, reference: &str) {
+        let Some((kind, name)) = reference.strip_prefix("#/components/").and_then(|pointer| {
+            let mut segments = pointer.splitn(3, '/');
+            Some((segments.next()?, segments.next()?))
+        }) else {
+            return;
+        };
+        let name = unescape_pointer_segment(name);
+
+        if !self.references.insert((kind.to_string(), name.clone())) {
+            return;
+        }
+
+        let components = self.components;
+        let mut location = Location::new();
+        location.push("components");
+        location.push(kind);
+        location.push(&name);
+        let location = &mut location;
+
+        match kind {
+            "schemas" => {
+                if let Some(schema) = components.schemas.get(&name) {
+                    walk_ref_or(self, schema, location, Self::visit_schema);
+                }
+            }
+            "responses" => {
+                if let Some(response) = components.responses.get(&name) {
+                    walk_ref_or(self, response, location, Self::visit_response);
+                }
+            }
+            "parameters" => {
+                if let Some(parameter) = components.parameters.get(&name) {
+                    walk_ref_or(self, parameter, location, Self::visit_parameter);
+                }
+            }
+            "examples" => {
+                if let Some(example) = components.examples.get(&name) {
+                    walk_ref_or(self, example, location, Self::visit_example);
+                }
+            }
+            "requestBodies" => {
+                if let Some(request_body) = components.request_bodies.get(&name) {
+                    walk_ref_or(self, request_body, location, Self::visit_request_body);
+                }
+            }
+            "headers" => {
+                if let Some(header) = components.headers.get(&name) {
+                    walk_ref_or(self, header, location, Self::visit_header);
+                }
+            }
+            "links" => {
+                if let Some(link) = components.links.get(&name) {
+                    walk_ref_or(self, link, location, Self::visit_link);
+                }
+            }
+            "callbacks" => {
+                if let Some(callback) = components.callbacks.get(&name) {
+                    walk_ref_or(self, callback, location, Self::visit_callback);
+                }
+            }
+            "pathItems" => {
+                if let Some(path_item) = components.path_items.get(&name) {
+                    walk_ref_or(self, path_item, location, Self::visit_path_item);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::schema::{Discriminator, OneOfBuilder};
+    use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, Ref, ResponseBuilder, SecurityRequirement,
+    };
+
+    #[test]
+    fn prune_unused_components_keeps_transitively_referenced_components() {
+        let api_key = || SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key")));
+        let mut api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .parameter(Ref::from_parameter_name("page"))
+                            .response("200", Ref::from_response_name("Pets"))
+                            .security(SecurityRequirement::new("api_key", [] as [&str; 0])),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new().property("owner", Ref::from_schema_name("Owner")),
+                    )
+                    .schema("Owner", ObjectBuilder::new())
+                    .schema("Unused", ObjectBuilder::new())
+                    .response(
+                        "Pets",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            ContentBuilder::new()
+                                .schema(Some(Ref::from_schema_name("Pet")))
+                                .build(),
+                        ),
+                    )
+                    .response("UnusedResponse", ResponseBuilder::new())
+                    .parameter(
+                        "page",
+                        ParameterBuilder::new()
+                            .name("page")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .parameter(
+                        "unused",
+                        ParameterBuilder::new()
+                            .name("unused")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .security_scheme("api_key", api_key())
+                    .security_scheme("unused_key", api_key())
+                    .build(),
+            ))
+            .build();
+
+        api.prune_unused_components();
+
+        let components = api.components.expect("OpenApi must have components");
+        assert_eq!(
+            components.schemas.keys().collect::<Vec<_>>(),
+            ["Owner", "Pet"]
+        );
+        assert_eq!(components.responses.keys().collect::<Vec<_>>(), ["Pets"]);
+        assert_eq!(components.parameters.keys().collect::<Vec<_>>(), ["page"]);
+        assert_eq!(
+            components.security_schemes.keys().collect::<Vec<_>>(),
+            ["api_key"]
+        );
+    }
+
+    #[test]
+    fn prune_unused_components_handles_recursive_schemas() {
+        let mut api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/nodes",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new().content(
+                                "application/json",
+                                ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name("Node")))
+                                    .build(),
+                            ),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Node",
+                        ObjectBuilder::new().property("next", Ref::from_schema_name("Node")),
+                    )
+                    .schema(
+                        "Orphan",
+                        ObjectBuilder::new().property("node", Ref::from_schema_name("Node")),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        api.prune_unused_components();
+
+        let components = api.components.expect("OpenApi must have components");
+        assert_eq!(components.schemas.keys().collect::<Vec<_>>(), ["Node"]);
+    }
+
+    #[test]
+    fn prune_unused_components_follows_deep_references_and_discriminator_mapping() {
+        let mut api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new().content(
+                                "application/json",
+                                ContentBuilder::new()
+                                    .schema(Some(Ref::from_schema_name("Pet")))
+                                    .build(),
+                            ),
+                        ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        OneOfBuilder::new()
+                            .item(Ref::new("#/components/schemas/Owner/properties/name"))
+                            .discriminator(Some(Discriminator::with_mapping(
+                                "kind",
+                                [("dog", "#/components/schemas/Dog")],
+                            ))),
+                    )
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new().property("name", ObjectBuilder::new()),
+                    )
+                    .schema("Dog", ObjectBuilder::new())
+                    .schema("Unused", ObjectBuilder::new())
+                    .build(),
+            ))
+            .build();
+
+        api.prune_unused_components();
+
+        let components = api.components.expect("OpenApi must have components");
+        assert_eq!(
+            components.schemas.keys().collect::<Vec<_>>(),
+            ["Dog", "Owner", "Pet"]
+        );
+    }
+}
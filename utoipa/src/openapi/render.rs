@@ -0,0 +1,881 @@
+//! Implements rendering of [`OpenApi`] documents to static reference documentation.
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use super::path::{Operation, Parameter, ParameterIn};
+use super::schema::{AdditionalProperties, ArrayItems, Schema, SchemaFormat, SchemaType, Type};
+use super::{Content, Deprecated, HttpMethod, OpenApi, PathItem, RefOr, Required};
+
+impl OpenApi {
+    /// Render this [`OpenApi`] as [Markdown][markdown] reference documentation.
+    ///
+    /// The document contains a section per tag with a subsection per operation tagged with it.
+    /// Each operation lists its parameters, request body and responses. Operations without tags
+    /// are listed in the _`default`_ section. The document ends with a section per component
+    /// schema with a table of the schema properties including their descriptions, allowed enum
+    /// values, defaults and examples. References to component schemas are rendered as links to
+    /// the schema sections.
+    ///
+    /// This is useful when the API reference needs to be published as static or printable
+    /// document e.g. along with other project documentation. See also [`OpenApi::to_asciidoc`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, InfoBuilder, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let api = OpenApiBuilder::new()
+    ///     .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(
+    ///             HttpMethod::Get,
+    ///             OperationBuilder::new().tag("pets").summary(Some("List pets")),
+    ///         ),
+    ///     ))
+    ///     .build();
+    ///
+    /// let markdown = api.to_markdown();
+    ///
+    /// assert!(markdown.starts_with("# Pets\n"));
+    /// assert!(markdown.contains("## pets\n"));
+    /// assert!(markdown.contains("### GET `/pets`\n"));
+    /// ```
+    ///
+    /// [markdown]: https://spec.commonmark.org/
+    pub fn to_markdown(&self) -> String {
+        Renderer::new(self, Syntax::Markdown).render()
+    }
+
+    /// Render this [`OpenApi`] as [AsciiDoc][asciidoc] reference documentation.
+    ///
+    /// The document has the same structure as the one rendered with [`OpenApi::to_markdown`]
+    /// and can be converted e.g. to HTML or PDF with _Asciidoctor_.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{InfoBuilder, OpenApiBuilder};
+    /// let api = OpenApiBuilder::new()
+    ///     .info(InfoBuilder::new().title("Pets").version("1.0.0"))
+    ///     .build();
+    ///
+    /// assert!(api.to_asciidoc().starts_with("= Pets\n"));
+    /// ```
+    ///
+    /// [asciidoc]: https://docs.asciidoctor.org/asciidoc/latest/
+    pub fn to_asciidoc(&self) -> String {
+        Renderer::new(self, Syntax::AsciiDoc).render()
+    }
+}
+
+/// Name of the section of operations without tags.
+const DEFAULT_TAG: &str = "default";
+
+/// Markup syntax of the rendered document.
+#[derive(Clone, Copy)]
+enum Syntax {
+    Markdown,
+    AsciiDoc,
+}
+
+impl Syntax {
+    fn heading(self, out: &mut String, level: usize, text: &str, anchor: Option<&str>) {
+        match self {
+            Self::Markdown => {
+                if let Some(anchor) = anchor {
+                    let _ = writeln!(out, "<a id=\"{anchor}\"></a>");
+                }
+                let _ = writeln!(out, "{} {text}\n", "#".repeat(level));
+            }
+            Self::AsciiDoc => {
+                if let Some(anchor) = anchor {
+                    let _ = writeln!(out, "[[{anchor}]]");
+                }
+                let _ = writeln!(out, "{} {text}\n", "=".repeat(level));
+            }
+        }
+    }
+
+    fn paragraph(self, out: &mut String, text: &str) {
+        let _ = writeln!(out, "{}\n", text.trim());
+    }
+
+    fn list(self, out: &mut String, items: &[String]) {
+        let bullet = match self {
+            Self::Markdown => "-",
+            Self::AsciiDoc => "*",
+        };
+        for item in items {
+            let _ = writeln!(out, "{bullet} {item}");
+        }
+        out.push('\n');
+    }
+
+    fn table(self, out: &mut String, headers: &[&str], rows: &[Vec<String>]) {
+        match self {
+            Self::Markdown => {
+                let _ = writeln!(out, "| {} |", headers.join(" | "));
+                let _ = writeln!(out, "|{}", " --- |".repeat(headers.len()));
+                for row in rows {
+                    let cells = row.iter().map(|cell| self.cell(cell)).collect::<Vec<_>>();
+                    let _ = writeln!(out, "| {} |", cells.join(" | "));
+                }
+            }
+            Self::AsciiDoc => {
+                let _ = writeln!(out, "[options=\"header\"]\n|===");
+                let _ = writeln!(out, "|{}", headers.join(" |"));
+                for row in rows {
+                    let cells = row.iter().map(|cell| self.cell(cell)).collect::<Vec<_>>();
+                    let _ = writeln!(out, "|{}", cells.join(" |"));
+                }
+                let _ = writeln!(out, "|===");
+            }
+        }
+        out.push('\n');
+    }
+
+    /// Escape table cell content. Line breaks within the content are preserved.
+    fn cell(self, text: &str) -> String {
+        let text = text.trim().replace('|', "\\|");
+        match self {
+            Self::Markdown => text.replace('\n', "<br>"),
+            Self::AsciiDoc => text.replace('\n', " +\n"),
+        }
+    }
+
+    fn code_block(self, out: &mut String, language: &str, code: &str) {
+        match self {
+            Self::Markdown => {
+                let _ = writeln!(out, "```{language}\n{code}\n```\n");
+            }
+            Self::AsciiDoc => {
+                let _ = writeln!(out, "[source,{language}]\n----\n{code}\n----\n");
+            }
+        }
+    }
+
+    fn code(self, text: &str) -> String {
+        match self {
+            Self::Markdown => format!("`{text}`"),
+            // passthrough prevents e.g. `{id}` from being substituted as an attribute
+            Self::AsciiDoc => format!("`+{text}+`"),
+        }
+    }
+
+    fn strong(self, text: &str) -> String {
+        match self {
+            Self::Markdown => format!("**{text}**"),
+            Self::AsciiDoc => format!("*{text}*"),
+        }
+    }
+
+    fn link(self, anchor: &str, text: &str) -> String {
+        match self {
+            Self::Markdown => format!("[{text}](#{anchor})"),
+            Self::AsciiDoc => format!("<<{anchor},{text}>>"),
+        }
+    }
+}
+
+/// Operation listed in a tag section.
+struct OperationEntry<'a> {
+    path: &'a str,
+    path_item: &'a PathItem,
+    http_method: HttpMethod,
+    operation: &'a Operation,
+}
+
+struct Renderer<'a> {
+    api: &'a OpenApi,
+    syntax: Syntax,
+    out: String,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(api: &'a OpenApi, syntax: Syntax) -> Self {
+        Self {
+            api,
+            syntax,
+            out: String::new(),
+        }
+    }
+
+    fn render(mut self) -> String {
+        let info = &self.api.info;
+        self.syntax.heading(&mut self.out, 1, &info.title, None);
+        let version = format!("Version: {}", self.syntax.code(&info.version));
+        self.syntax.paragraph(&mut self.out, &version);
+        if let Some(description) = &info.description {
+            self.syntax.paragraph(&mut self.out, description);
+        }
+        if let Some(servers) = self
+            .api
+            .servers
+            .as_ref()
+            .filter(|servers| !servers.is_empty())
+        {
+            let servers = servers
+                .iter()
+                .map(|server| match &server.description {
+                    Some(description) => {
+                        format!("{}: {description}", self.syntax.code(&server.url))
+                    }
+                    None => self.syntax.code(&server.url),
+                })
+                .collect::<Vec<_>>();
+            self.syntax.list(&mut self.out, &servers);
+        }
+
+        for (tag, operations) in self.tag_sections() {
+            self.syntax.heading(&mut self.out, 2, &tag, None);
+            if let Some(description) = self
+                .api
+                .tags
+                .iter()
+                .flatten()
+                .find(|declared| declared.name == tag)
+                .and_then(|declared| declared.description.as_ref())
+            {
+                self.syntax.paragraph(&mut self.out, description);
+            }
+            for entry in operations {
+                self.render_operation(&entry);
+            }
+        }
+
+        self.render_schemas();
+
+        let mut out = self.out;
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    /// Group operations by their tags. Declared tags come first in their declaration order
+    /// followed by undeclared tags in order of appearance and the default section.
+    fn tag_sections(&self) -> Vec<(String, Vec<OperationEntry<'a>>)> {
+        let mut sections = self
+            .api
+            .tags
+            .iter()
+            .flatten()
+            .map(|tag| (tag.name.clone(), Vec::new()))
+            .collect::<Vec<_>>();
+        let mut untagged = Vec::new();
+
+        let api = self.api;
+        for (path, path_item) in &api.paths.paths {
+            for (http_method, operation) in path_item.operations() {
+                let entry = || OperationEntry {
+                    path,
+                    path_item,
+                    http_method: http_method.clone(),
+                    operation,
+                };
+                match operation.tags.as_ref().filter(|tags| !tags.is_empty()) {
+                    Some(tags) => {
+                        for tag in tags {
+                            match sections.iter_mut().find(|(name, _)| name == tag) {
+                                Some((_, operations)) => operations.push(entry()),
+                                None => sections.push((tag.clone(), vec![entry()])),
+                            }
+                        }
+                    }
+                    None => untagged.push(entry()),
+                }
+            }
+        }
+
+        sections.retain(|(_, operations)| !operations.is_empty());
+        if !untagged.is_empty() {
+            sections.push((String::from(DEFAULT_TAG), untagged));
+        }
+
+        sections
+    }
+
+    fn render_operation(&mut self, entry: &OperationEntry<'a>) {
+        let syntax = self.syntax;
+        let operation = entry.operation;
+        let title = format!(
+            "{} {}",
+            entry.http_method.as_str().to_uppercase(),
+            syntax.code(entry.path)
+        );
+        syntax.heading(&mut self.out, 3, &title, None);
+
+        if matches!(operation.deprecated, Some(Deprecated::True)) {
+            syntax.paragraph(&mut self.out, &syntax.strong("Deprecated"));
+        }
+        if let Some(summary) = &operation.summary {
+            syntax.paragraph(&mut self.out, &syntax.strong(summary));
+        }
+        if let Some(description) = &operation.description {
+            syntax.paragraph(&mut self.out, description);
+        }
+        if let Some(operation_id) = &operation.operation_id {
+            let operation_id = format!("Operation ID: {}", syntax.code(operation_id));
+            syntax.paragraph(&mut self.out, &operation_id);
+        }
+
+        let parameters = self.parameters(entry);
+        if !parameters.is_empty() {
+            syntax.heading(&mut self.out, 4, "Parameters", None);
+            let rows = parameters
+                .iter()
+                .map(|parameter| {
+                    let mut description = parameter.description.clone().unwrap_or_default();
+                    if matches!(parameter.deprecated, Some(Deprecated::True)) {
+                        description.insert_str(0, &format!("{}\n", syntax.strong("Deprecated")));
+                    }
+                    if let Some(RefOr::T(schema)) = &parameter.schema {
+                        push_details(syntax, &mut description, schema);
+                    }
+                    vec![
+                        syntax.code(&parameter.name),
+                        String::from(parameter_in(&parameter.parameter_in)),
+                        parameter
+                            .schema
+                            .as_ref()
+                            .map(|schema| self.type_name(schema))
+                            .unwrap_or_default(),
+                        yes_no(matches!(parameter.required, Required::True)),
+                        description,
+                    ]
+                })
+                .collect::<Vec<_>>();
+            syntax.table(
+                &mut self.out,
+                &["Name", "In", "Type", "Required", "Description"],
+                &rows,
+            );
+        }
+
+        if let Some(request_body) = &operation.request_body {
+            syntax.heading(&mut self.out, 4, "Request body", None);
+            if matches!(request_body.required, Some(Required::True)) {
+                syntax.paragraph(&mut self.out, &syntax.strong("Required"));
+            }
+            if let Some(description) = &request_body.description {
+                syntax.paragraph(&mut self.out, description);
+            }
+            let rows = request_body
+                .content
+                .iter()
+                .map(|(content_type, content)| {
+                    vec![syntax.code(content_type), self.content_type_name(content)]
+                })
+                .collect::<Vec<_>>();
+            if !rows.is_empty() {
+                syntax.table(&mut self.out, &["Content type", "Type"], &rows);
+            }
+            for (content_type, content) in &request_body.content {
+                self.render_content_example(
+                    &format!("Example {}:", syntax.code(content_type)),
+                    content,
+                );
+            }
+        }
+
+        if !operation.responses.responses.is_empty() {
+            syntax.heading(&mut self.out, 4, "Responses", None);
+            let responses = operation
+                .responses
+                .responses
+                .iter()
+                .filter_map(|(status, response)| match response {
+                    RefOr::T(response) => Some((status, response)),
+                    RefOr::Ref(reference) => self
+                        .api
+                        .resolve_response(reference)
+                        .map(|response| (status, response)),
+                })
+                .collect::<Vec<_>>();
+            let rows = responses
+                .iter()
+                .map(|(status, response)| {
+                    let content = response
+                        .content
+                        .iter()
+                        .map(|(content_type, content)| {
+                            format!(
+                                "{}: {}",
+                                syntax.code(content_type),
+                                self.content_type_name(content)
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    vec![syntax.code(status), response.description.clone(), content]
+                })
+                .collect::<Vec<_>>();
+            syntax.table(&mut self.out, &["Status", "Description", "Content"], &rows);
+            for (status, response) in responses {
+                for (content_type, content) in &response.content {
+                    let title = format!(
+                        "Example {} {}:",
+                        syntax.code(status),
+                        syntax.code(content_type)
+                    );
+                    self.render_content_example(&title, content);
+                }
+            }
+        }
+    }
+
+    /// Resolve parameters of the operation. Operation parameters override the path item
+    /// parameters with the same name and location.
+    fn parameters(&self, entry: &OperationEntry<'a>) -> Vec<&'a Parameter> {
+        let api = self.api;
+        let resolve = |parameter: &'a RefOr<Parameter>| match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => api.resolve_parameter(reference),
+        };
+        let operation_parameters = entry
+            .operation
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .collect::<Vec<_>>();
+
+        let mut parameters = entry
+            .path_item
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(resolve)
+            .filter(|parameter| {
+                !operation_parameters.iter().any(|overriding| {
+                    overriding.name == parameter.name
+                        && overriding.parameter_in == parameter.parameter_in
+                })
+            })
+            .collect::<Vec<_>>();
+        parameters.extend(operation_parameters);
+
+        parameters
+    }
+
+    fn render_content_example(&mut self, title: &str, content: &Content) {
+        let Some(example) = &content.example else {
+            return;
+        };
+        self.syntax.paragraph(&mut self.out, title);
+        let example = match example {
+            Value::String(example) => example.clone(),
+            example => serde_json::to_string_pretty(example).unwrap_or_default(),
+        };
+        self.syntax.code_block(&mut self.out, "json", &example);
+    }
+
+    fn render_schemas(&mut self) {
+        let Some(components) = self
+            .api
+            .components
+            .as_ref()
+            .filter(|components| !components.schemas.is_empty())
+        else {
+            return;
+        };
+        let syntax = self.syntax;
+
+        syntax.heading(&mut self.out, 2, "Schemas", None);
+        for (name, schema) in &components.schemas {
+            syntax.heading(&mut self.out, 3, name, Some(&schema_anchor(name)));
+            let schema = match schema {
+                RefOr::Ref(_) => {
+                    let alias = format!("Alias of {}.", self.type_name(schema));
+                    syntax.paragraph(&mut self.out, &alias);
+                    continue;
+                }
+                RefOr::T(schema) => schema,
+            };
+
+            if let Some(description) = description(schema) {
+                syntax.paragraph(&mut self.out, description);
+            }
+            let schema_type = format!("Type: {}", self.schema_type_name(schema));
+            syntax.paragraph(&mut self.out, &schema_type);
+            let mut details = String::new();
+            push_details(syntax, &mut details, schema);
+            if !details.is_empty() {
+                syntax.paragraph(&mut self.out, &details.replace('\n', "\n\n"));
+            }
+
+            if let Schema::Object(object) = schema {
+                if !object.properties.is_empty() {
+                    let rows = object
+                        .properties
+                        .iter()
+                        .map(|(property, property_schema)| {
+                            let description = match property_schema {
+                                RefOr::T(schema) => {
+                                    let mut text =
+                                        description(schema).map(String::from).unwrap_or_default();
+                                    push_details(syntax, &mut text, schema);
+                                    text
+                                }
+                                RefOr::Ref(reference) => reference.description.clone(),
+                            };
+                            vec![
+                                syntax.code(property),
+                                self.type_name(property_schema),
+                                yes_no(object.required.contains(property)),
+                                description,
+                            ]
+                        })
+                        .collect::<Vec<_>>();
+                    syntax.table(
+                        &mut self.out,
+                        &["Property", "Type", "Required", "Description"],
+                        &rows,
+                    );
+                }
+            }
+        }
+    }
+
+    fn content_type_name(&self, content: &Content) -> String {
+        content
+            .schema
+            .as_ref()
+            .map(|schema| self.type_name(schema))
+            .unwrap_or_default()
+    }
+
+    fn type_name(&self, schema: &RefOr<Schema>) -> String {
+        match schema {
+            RefOr::Ref(reference) => {
+                match reference.ref_location.strip_prefix("#/components/schemas/") {
+                    Some(name) => self.syntax.link(&schema_anchor(name), name),
+                    None => self.syntax.code(&reference.ref_location),
+                }
+            }
+            RefOr::T(schema) => self.schema_type_name(schema),
+        }
+    }
+
+    fn schema_type_name(&self, schema: &Schema) -> String {
+        let join = |items: &[RefOr<Schema>], separator: &str| {
+            items
+                .iter()
+                .map(|item| self.type_name(item))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+
+        match schema {
+            Schema::Object(object) => {
+                let mut name = match (&object.schema_type, &object.additional_properties) {
+                    (SchemaType::Type(Type::Object), Some(additional_properties))
+                        if object.properties.is_empty() =>
+                    {
+                        match additional_properties.as_ref() {
+                            AdditionalProperties::RefOr(value) => {
+                                format!("map of {}", self.type_name(value))
+                            }
+                            AdditionalProperties::FreeForm(_) => String::from("object"),
+                        }
+                    }
+                    (schema_type, _) => schema_type_name(schema_type),
+                };
+                if let Some(format) = &object.format {
+                    let _ = write!(name, " ({})", format_name(format));
+                }
+                name
+            }
+            Schema::Array(array) => {
+                let mut name = match &array.items {
+                    ArrayItems::RefOrSchema(items) => format!("array of {}", self.type_name(items)),
+                    ArrayItems::False => String::from("array"),
+                };
+                if matches!(&array.schema_type, SchemaType::Array(types) if types.contains(&Type::Null))
+                {
+                    name.push_str(" | null");
+                }
+                name
+            }
+            Schema::OneOf(one_of) => join(&one_of.items, " | "),
+            Schema::AnyOf(any_of) => join(&any_of.items, " | "),
+            Schema::AllOf(all_of) => join(&all_of.items, " & "),
+            Schema::Bool(true) => String::from("any"),
+            Schema::Bool(false) => String::from("never"),
+        }
+    }
+}
+
+fn description(schema: &Schema) -> Option<&str> {
+    match schema {
+        Schema::Object(object) => object.description.as_deref(),
+        Schema::Array(array) => array.description.as_deref(),
+        Schema::OneOf(one_of) => one_of.description.as_deref(),
+        Schema::AllOf(all_of) => all_of.description.as_deref(),
+        Schema::AnyOf(any_of) => any_of.description.as_deref(),
+        Schema::Bool(_) => None,
+    }
+}
+
+/// Append allowed values, default and example of the `schema` to the `text` each on its own line.
+fn push_details(syntax: Syntax, text: &mut String, schema: &Schema) {
+    let (enum_values, default, example) = match schema {
+        Schema::Object(object) => (
+            object.enum_values.as_ref(),
+            object.default.as_ref(),
+            object.example.as_ref().or(object.examples.first()),
+        ),
+        Schema::Array(array) => (
+            None,
+            array.default.as_ref(),
+            array.example.as_ref().or(array.examples.first()),
+        ),
+        Schema::OneOf(one_of) => (None, one_of.default.as_ref(), one_of.example.as_ref()),
+        Schema::AllOf(all_of) => (None, all_of.default.as_ref(), all_of.example.as_ref()),
+        Schema::AnyOf(any_of) => (
+            None,
+            any_of.default.as_ref(),
+            any_of.example.as_ref().or(any_of.examples.first()),
+        ),
+        Schema::Bool(_) => (None, None, None),
+    };
+
+    let mut push_line = |line: String| {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&line);
+    };
+    if let Some(enum_values) = enum_values.filter(|values| !values.is_empty()) {
+        let values = enum_values
+            .iter()
+            .map(|value| syntax.code(&value.to_string()))
+            .collect::<Vec<_>>();
+        push_line(format!("Allowed values: {}", values.join(", ")));
+    }
+    if let Some(default) = default {
+        push_line(format!("Default: {}", syntax.code(&default.to_string())));
+    }
+    if let Some(example) = example {
+        push_line(format!("Example: {}", syntax.code(&example.to_string())));
+    }
+}
+
+fn schema_type_name(schema_type: &SchemaType) -> String {
+    match schema_type {
+        SchemaType::Type(schema_type) => String::from(type_name(schema_type)),
+        SchemaType::Array(types) => types.iter().map(type_name).collect::<Vec<_>>().join(" | "),
+        SchemaType::AnyValue => String::from("any"),
+    }
+}
+
+fn type_name(schema_type: &Type) -> &'static str {
+    match schema_type {
+        Type::Object => "object",
+        Type::String => "string",
+        Type::Integer => "integer",
+        Type::Number => "number",
+        Type::Boolean => "boolean",
+        Type::Array => "array",
+        Type::Null => "null",
+    }
+}
+
+fn format_name(format: &SchemaFormat) -> String {
+    match format {
+        SchemaFormat::KnownFormat(format) => serde_json::to_value(format)
+            .ok()
+            .and_then(|format| format.as_str().map(String::from))
+            .unwrap_or_default(),
+        SchemaFormat::Custom(format) => format.clone(),
+    }
+}
+
+fn parameter_in(parameter_in: &ParameterIn) -> &'static str {
+    match parameter_in {
+        ParameterIn::Query => "query",
+        ParameterIn::Path => "path",
+        ParameterIn::Header => "header",
+        ParameterIn::Cookie => "cookie",
+    }
+}
+
+fn yes_no(value: bool) -> String {
+    String::from(if value { "Yes" } else { "No" })
+}
+
+/// Anchor of component schema section. Characters not allowed in anchors are replaced with `-`.
+fn schema_anchor(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+
+    format!("schema-{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder};
+    use crate::openapi::request_body::RequestBodyBuilder;
+    use crate::openapi::tag::TagBuilder;
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, ContentBuilder, InfoBuilder, KnownFormat, ObjectBuilder,
+        OpenApiBuilder, PathItem, PathsBuilder, Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        let pet = ObjectBuilder::new()
+            .description(Some("Pet in the store"))
+            .property(
+                "kind",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .enum_values(Some(["dog", "cat"])),
+            )
+            .property(
+                "name",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .description(Some("Name of the pet"))
+                    .examples([json!("Lassie")]),
+            )
+            .required("name")
+            .property(
+                "tags",
+                ArrayBuilder::new().items(Ref::from_schema_name("Tag")),
+            )
+            .build();
+
+        OpenApiBuilder::new()
+            .info(
+                InfoBuilder::new()
+                    .title("Pet store")
+                    .version("1.0.0")
+                    .description(Some("Manage pets")),
+            )
+            .tags(Some([TagBuilder::new()
+                .name("pets")
+                .description(Some("Pet operations"))
+                .build()]))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets/{id}",
+                        PathItem::new(
+                            HttpMethod::Put,
+                            OperationBuilder::new()
+                                .tag("pets")
+                                .summary(Some("Update pet"))
+                                .operation_id(Some("update_pet"))
+                                .parameter(
+                                    ParameterBuilder::new()
+                                        .name("id")
+                                        .parameter_in(ParameterIn::Path)
+                                        .required(Required::True)
+                                        .description(Some("Id of the pet"))
+                                        .schema(Some(
+                                            ObjectBuilder::new().schema_type(Type::Integer).format(
+                                                Some(SchemaFormat::KnownFormat(KnownFormat::Int64)),
+                                            ),
+                                        )),
+                                )
+                                .request_body(Some(
+                                    RequestBodyBuilder::new()
+                                        .required(Some(Required::True))
+                                        .content(
+                                            "application/json",
+                                            ContentBuilder::new()
+                                                .schema(Some(Ref::from_schema_name("Pet")))
+                                                .example(Some(json!({"name": "Lassie"})))
+                                                .build(),
+                                        )
+                                        .build(),
+                                ))
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new().description("Pet updated").content(
+                                        "application/json",
+                                        ContentBuilder::new()
+                                            .schema(Some(Ref::from_schema_name("Pet")))
+                                            .build(),
+                                    ),
+                                )
+                                .response("404", ResponseBuilder::new().description("Not | found")),
+                        ),
+                    )
+                    .path(
+                        "/health",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new()
+                                .response("200", ResponseBuilder::new().description("Healthy")),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema("Pet", pet)
+                    .schema("Tag", ObjectBuilder::new().schema_type(Type::String))
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn render_markdown() {
+        let markdown = api().to_markdown();
+
+        assert!(markdown.starts_with("# Pet store\n\nVersion: `1.0.0`\n\nManage pets\n\n"));
+        assert!(markdown.contains("## pets\n\nPet operations\n\n### PUT `/pets/{id}`\n\n"));
+        assert!(markdown.contains(concat!(
+            "| Name | In | Type | Required | Description |\n",
+            "| --- | --- | --- | --- | --- |\n",
+            "| `id` | path | integer (int64) | Yes | Id of the pet |\n",
+        )));
+        assert!(markdown.contains("| `application/json` | [Pet](#schema-Pet) |\n"));
+        assert!(markdown.contains("```json\n{\n  \"name\": \"Lassie\"\n}\n```\n"));
+        assert!(markdown.contains("| `404` | Not \\| found |  |\n"));
+        assert!(markdown.contains("## default\n\n### GET `/health`\n\n"));
+        assert!(markdown.contains("<a id=\"schema-Pet\"></a>\n### Pet\n\nPet in the store\n\n"));
+        assert!(markdown.contains(concat!(
+            "| `kind` | string | No | Allowed values: `\"dog\"`, `\"cat\"` |\n",
+            "| `name` | string | Yes | Name of the pet<br>Example: `\"Lassie\"` |\n",
+            "| `tags` | array of [Tag](#schema-Tag) | No |  |\n",
+        )));
+        assert!(markdown.ends_with("### Tag\n\nType: string\n"));
+    }
+
+    #[test]
+    fn render_asciidoc() {
+        let asciidoc = api().to_asciidoc();
+
+        assert!(asciidoc.starts_with("= Pet store\n\nVersion: `+1.0.0+`\n\n"));
+        assert!(asciidoc.contains("== pets\n\nPet operations\n\n=== PUT `+/pets/{id}+`\n\n"));
+        assert!(asciidoc.contains(concat!(
+            "[options=\"header\"]\n",
+            "|===\n",
+            "|Name |In |Type |Required |Description\n",
+            "|`+id+` |path |integer (int64) |Yes |Id of the pet\n",
+            "|===\n",
+        )));
+        assert!(
+            asciidoc.contains("|`+200+` |Pet updated |`+application/json+`: <<schema-Pet,Pet>>\n")
+        );
+        assert!(asciidoc.contains("[source,json]\n----\n{\n  \"name\": \"Lassie\"\n}\n----\n"));
+        assert!(asciidoc.contains("[[schema-Pet]]\n=== Pet\n\n"));
+        assert!(asciidoc
+            .contains("|`+name+` |string |Yes |Name of the pet +\nExample: `+\"Lassie\"+`\n"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Implements resolution of local [`Ref`]s and dereferencing of [`OpenApi`] documents.
+use super::callback::Callback;
+use super::example::Example;
+use super::header::Header;
+use super::link::Link;
+use super::path::{Parameter, PathItem};
+use super::request_body::RequestBody;
+use super::response::Response;
+use super::schema::{Components, Schema};
+use super::validation::unescape_pointer_segment;
+use super::visit::{self, Component, Location, VisitMut};
+use super::{OpenApi, Ref, RefOr};
+
+impl OpenApi {
+    /// Resolve local [`Ref`] e.g. _`#/components/schemas/Pet`_ to the [`Schema`] in
+    /// [`OpenApi::components`].
+    ///
+    /// If the component is itself a reference it will be followed until a [`Schema`] is found.
+    /// Returns `None` if the reference is not a local schema reference, the schema does not
+    /// exist or the references form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Ref, Schema};
+    /// let api = OpenApiBuilder::new()
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .schema("Pet", ObjectBuilder::new())
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// let pet = api.resolve_schema(&Ref::from_schema_name("Pet"));
+    /// assert!(matches!(pet, Some(Schema::Object(_))));
+    /// ```
+    pub fn resolve_schema(&self, reference: &Ref) -> Option<&Schema> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Response`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_response(&self, reference: &Ref) -> Option<&Response> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Parameter`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_parameter(&self, reference: &Ref) -> Option<&Parameter> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Example`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_example(&self, reference: &Ref) -> Option<&Example> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`RequestBody`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_request_body(&self, reference: &Ref) -> Option<&RequestBody> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Header`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_header(&self, reference: &Ref) -> Option<&Header> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Link`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_link(&self, reference: &Ref) -> Option<&Link> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`Callback`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_callback(&self, reference: &Ref) -> Option<&Callback> {
+        self.resolve(reference)
+    }
+
+    /// Resolve local [`Ref`] to the [`PathItem`] in [`OpenApi::components`]. See
+    /// [`OpenApi::resolve_schema`] for more details.
+    pub fn resolve_path_item(&self, reference: &Ref) -> Option<&PathItem> {
+        self.resolve(reference)
+    }
+
+    /// Replace every local [`Ref`] of [`OpenApi::paths`], [`OpenApi::webhooks`] and
+    /// [`OpenApi::components`] with a copy of the referenced component.
+    ///
+    /// References of recursive types are left as is when the referenced component is already
+    /// being inlined, thus the result is always finite. Unresolvable and non local references
+    /// are left as is as well. [`OpenApi::components`] are not removed since recursive
+    /// references may still point to them, use [`OpenApi::prune_unused_components`] to remove
+    /// the components no longer referenced.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder,
+    /// #    OpenApiBuilder, PathItem, PathsBuilder, Ref, ResponseBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut api = OpenApiBuilder::new()
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().response(
+    ///             "200",
+    ///             ResponseBuilder::new().content(
+    ///                 "application/json",
+    ///                 ContentBuilder::new().schema(Some(Ref::from_schema_name("Pet"))).build(),
+    ///             ),
+    ///         )),
+    ///     ))
+    ///     .components(Some(ComponentsBuilder::new().schema("Pet", ObjectBuilder::new()).build()))
+    ///     .build();
+    ///
+    /// api.dereference();
+    ///
+    /// let json = api.to_json().unwrap();
+    /// assert!(!json.contains("$ref"));
+    /// ```
+    pub fn dereference(&mut self) {
+        let Some(components) = self.components.clone() else {
+            return;
+        };
+        let mut dereferencer = Dereferencer {
+            components: &components,
+            stack: Vec::new(),
+        };
+
+        dereferencer.visit_openapi_mut(self, &mut Location::new());
+    }
+
+    fn resolve<T: Component>(&self, reference: &Ref) -> Option<&T> {
+        let components = self.components.as_ref()?;
+        let mut visited = Vec::new();
+        let mut reference = reference;
+
+        loop {
+            let name = component_name(reference, T::KIND)?;
+            if visited.contains(&name) {
+                return None;
+            }
+            match T::components(components).get(&name)? {
+                RefOr::T(component) => return Some(component),
+                RefOr::Ref(next) => reference = next,
+            }
+            visited.push(name);
+        }
+    }
+}
+
+/// Get name of the component from local reference of given `kind` e.g. `Pet` from
+/// _`#/components/schemas/Pet`_.
+fn component_name(reference: &Ref, kind: &str) -> Option<String> {
+    reference
+        .ref_location
+        .strip_prefix("#/components/")?
+        .strip_prefix(kind)?
+        .strip_prefix('/')
+        .map(unescape_pointer_segment)
+}
+
+struct Dereferencer<'c> {
+    /// Snapshot of the original components used for resolving references.
+    components: &'c Components,
+    /// `(kind, name)` of components currently being inlined.
+    stack: Vec<(&'static str, String)>,
+}
+
+impl VisitMut for Dereferencer<'_> {
+    fn visit_ref_or_mut<T: Component>(&mut self, ref_or: &mut RefOr<T>, location: &mut Location) {
+        // component is marked as being inlined while dereferencing its own definition
+        let component = match location.segments() {
+            [components, kind, name] if components == "components" && kind == T::KIND => {
+                Some((T::KIND, name.clone()))
+            }
+            _ => None,
+        };
+        let is_component = component.is_some();
+
+        self.stack.extend(component);
+        self.dereference(ref_or, location);
+        if is_component {
+            self.stack.pop();
+        }
+    }
+}
+
+impl Dereferencer<'_> {
+    fn dereference<T: Component>(&mut self, ref_or: &mut RefOr<T>, location: &mut Location) {
+        let RefOr::Ref(reference) = ref_or else {
+            visit::walk_ref_or_mut(self, ref_or, location);
+            return;
+        };
+
+        let Some(name) = component_name(reference, T::KIND) else {
+            return;
+        };
+        let is_recursive = self
+            .stack
+            .iter()
+            .any(|(kind, inlined)| *kind == T::KIND && *inlined == name);
+        if is_recursive {
+            return;
+        }
+        let Some(component) = T::components(self.components).get(&name) else {
+            return;
+        };
+
+        let mut component = component.clone();
+        self.stack.push((T::KIND, name));
+        self.dereference(&mut component, location);
+        self.stack.pop();
+
+        // recursive reference chain is left as a reference
+        if let RefOr::T(_) = component {
+            *ref_or = component;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder,
+        PathsBuilder, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .parameter(Ref::from_parameter_name("limit"))
+                            .response("200", Ref::from_response_name("Pets")),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .property("parent", Ref::from_schema_name("Pet"))
+                            .property("owner", Ref::from_schema_name("Person")),
+                    )
+                    .schema("Person", Ref::from_schema_name("Owner"))
+                    .schema("Owner", ObjectBuilder::new())
+                    .schema("Loop", Ref::from_schema_name("Loop"))
+                    .response(
+                        "Pets",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            ContentBuilder::new()
+                                .schema(Some(
+                                    ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+                                ))
+                                .build(),
+                        ),
+                    )
+                    .parameter(
+                        "limit",
+                        ParameterBuilder::new()
+                            .name("limit")
+                            .parameter_in(ParameterIn::Query),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn resolve_components_by_reference() {
+        let api = api();
+
+        assert!(api.resolve_schema(&Ref::from_schema_name("Pet")).is_some());
+        assert!(matches!(
+            api.resolve_schema(&Ref::from_schema_name("Person")),
+            Some(Schema::Object(object)) if object.properties.is_empty()
+        ));
+        assert!(api
+            .resolve_response(&Ref::from_response_name("Pets"))
+            .is_some());
+        assert_eq!(
+            api.resolve_parameter(&Ref::from_parameter_name("limit"))
+                .map(|parameter| parameter.name.as_str()),
+            Some("limit")
+        );
+        assert!(api.resolve_schema(&Ref::from_schema_name("Loop")).is_none());
+        assert!(api
+            .resolve_schema(&Ref::from_schema_name("Missing"))
+            .is_none());
+        assert!(api
+            .resolve_schema(&Ref::from_response_name("Pets"))
+            .is_none());
+    }
+
+    #[test]
+    fn dereference_inlines_references_and_keeps_recursive() {
+        let mut api = api();
+
+        api.dereference();
+
+        let value = serde_json::to_value(&api).expect("OpenApi must serialize");
+        let operation = &value["paths"]["/pets"]["get"];
+        assert_eq!(operation["parameters"][0]["name"], "limit");
+        let pet = &operation["responses"]["200"]["content"]["application/json"]["schema"]["items"];
+        assert_eq!(pet["properties"]["name"]["type"], "string");
+        assert_eq!(pet["properties"]["owner"]["type"], "object");
+        assert_eq!(
+            pet["properties"]["parent"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+
+        let schemas = &value["components"]["schemas"];
+        assert_eq!(
+            schemas["Pet"]["properties"]["parent"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+        assert_eq!(schemas["Person"]["type"], "object");
+        assert_eq!(schemas["Loop"]["$ref"], "#/components/schemas/Loop");
+    }
+}
@@ -9,8 +9,20 @@ use serde_json::Value;
 
 use super::extensions::Extensions;
 use super::RefOr;
-use super::{builder, security::SecurityScheme, set_value, xml::Xml, Deprecated, Response};
-use crate::{ToResponse, ToSchema};
+use super::{
+    builder,
+    callback::Callback,
+    example::Example,
+    header::Header,
+    link::Link,
+    path::{Parameter, PathItem},
+    request_body::RequestBody,
+    security::SecurityScheme,
+    set_value,
+    xml::Xml,
+    Deprecated, Response,
+};
+use crate::{IntoParams, ToResponse, ToSchema};
 
 macro_rules! component_from_builder {
     ( $name:ident ) => {
@@ -73,12 +85,71 @@ builder! {
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub responses: BTreeMap<String, RefOr<Response>>,
 
+        /// Map of reusable parameter name, to [OpenAPI Parameter Object][parameter]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Parameter Object][parameter]s.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub parameters: BTreeMap<String, RefOr<Parameter>>,
+
+        /// Map of reusable example name, to [OpenAPI Example Object][example]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Example Object][example]s.
+        ///
+        /// [example]: https://spec.openapis.org/oas/latest.html#example-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
+
+        /// Map of reusable request body name, to [OpenAPI Request Body Object][request_body]s or
+        /// [OpenAPI Reference][reference]s to [OpenAPI Request Body Object][request_body]s.
+        ///
+        /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub request_bodies: BTreeMap<String, RefOr<RequestBody>>,
+
+        /// Map of reusable header name, to [OpenAPI Header Object][header]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Header Object][header]s.
+        ///
+        /// [header]: https://spec.openapis.org/oas/latest.html#header-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub headers: BTreeMap<String, RefOr<Header>>,
+
         /// Map of reusable [OpenAPI Security Scheme Object][security_scheme]s.
         ///
         /// [security_scheme]: https://spec.openapis.org/oas/latest.html#security-scheme-object
         #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
         pub security_schemes: BTreeMap<String, SecurityScheme>,
 
+        /// Map of reusable link name, to [OpenAPI Link Object][link]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Link Object][link]s.
+        ///
+        /// [link]: https://spec.openapis.org/oas/latest.html#link-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub links: BTreeMap<String, RefOr<Link>>,
+
+        /// Map of reusable callback name, to [OpenAPI Callback Object][callback]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Callback Object][callback]s.
+        ///
+        /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub callbacks: BTreeMap<String, RefOr<Callback>>,
+
+        /// Map of reusable path item name, to [OpenAPI Path Item Object][path_item]s or [OpenAPI
+        /// Reference][reference]s to [OpenAPI Path Item Object][path_item]s.
+        ///
+        /// Path items are only supported by OpenAPI 3.1 and are omitted from the OpenAPI 3.0
+        /// output.
+        ///
+        /// [path_item]: https://spec.openapis.org/oas/latest.html#path-item-object
+        /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub path_items: BTreeMap<String, RefOr<PathItem>>,
+
         /// Optional extensions "x-something".
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         pub extensions: Option<Extensions>,
@@ -242,6 +313,176 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Parameter`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable parameter and `parameter` which is the
+    /// reusable parameter itself. The parameter can be referenced with
+    /// [`Ref::from_parameter_name`].
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+        self
+    }
+
+    /// Add [`Parameter`]s to [`Components`] from type implementing [`trait@IntoParams`] trait.
+    ///
+    /// Each parameter is added with name _`{type}.{parameter}`_ where _`type`_ is
+    /// [`IntoParams::name`][name] and _`parameter`_ is the [`Parameter::name`]. This way
+    /// parameters with the same name from different types do not overwrite each other. Method is
+    /// expected to be called with one generic argument that implements the
+    /// trait. The parameter location is resolved from the `IntoParams` implementation itself so
+    /// the type should define it e.g. with `#[into_params(parameter_in = Query)]`.
+    ///
+    /// # Examples
+    ///
+    /// _**Add `Pagination` query parameters as reusable parameters.**_
+    /// ```rust
+    /// # use utoipa::{IntoParams, openapi::schema::ComponentsBuilder};
+    /// #[derive(IntoParams)]
+    /// #[into_params(parameter_in = Query)]
+    /// struct Pagination {
+    ///     page: Option<u64>,
+    ///     page_size: Option<u64>,
+    /// }
+    ///
+    /// let components = ComponentsBuilder::new().parameters_from::<Pagination>().build();
+    /// assert!(components.parameters.contains_key("Pagination.page"));
+    /// assert!(components.parameters.contains_key("Pagination.page_size"));
+    /// ```
+    ///
+    /// [name]: ../../trait.IntoParams.html#method.name
+    pub fn parameters_from<I: IntoParams>(self) -> Self {
+        let type_name = I::name();
+        self.parameters_from_iter(
+            I::into_params(|| None)
+                .into_iter()
+                .map(|parameter| (format!("{type_name}.{}", parameter.name), parameter)),
+        )
+    }
+
+    /// Add multiple [`Parameter`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple parameters by
+    /// any iterator what returns tuples of (name, parameter) values.
+    pub fn parameters_from_iter<
+        I: IntoIterator<Item = (S, P)>,
+        S: Into<String>,
+        P: Into<RefOr<Parameter>>,
+    >(
+        mut self,
+        parameters: I,
+    ) -> Self {
+        self.parameters.extend(
+            parameters
+                .into_iter()
+                .map(|(name, parameter)| (name.into(), parameter.into())),
+        );
+
+        self
+    }
+
+    /// Add [`Example`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable example and `example` which is the
+    /// reusable example itself. The example can be referenced with [`Ref::from_example_name`].
+    pub fn example<S: Into<String>, E: Into<RefOr<Example>>>(
+        mut self,
+        name: S,
+        example: E,
+    ) -> Self {
+        self.examples.insert(name.into(), example.into());
+        self
+    }
+
+    /// Add multiple [`Example`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple examples by
+    /// any iterator what returns tuples of (name, example) values.
+    pub fn examples_from_iter<
+        I: IntoIterator<Item = (S, E)>,
+        S: Into<String>,
+        E: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: I,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
+
+    /// Add [`RequestBody`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable request body and `request_body` which
+    /// is the reusable request body itself. The request body can be referenced with
+    /// [`Ref::from_request_body_name`].
+    pub fn request_body<S: Into<String>, R: Into<RefOr<RequestBody>>>(
+        mut self,
+        name: S,
+        request_body: R,
+    ) -> Self {
+        self.request_bodies.insert(name.into(), request_body.into());
+        self
+    }
+
+    /// Add multiple [`RequestBody`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple request
+    /// bodies by any iterator what returns tuples of (name, request_body) values.
+    pub fn request_bodies_from_iter<
+        I: IntoIterator<Item = (S, R)>,
+        S: Into<String>,
+        R: Into<RefOr<RequestBody>>,
+    >(
+        mut self,
+        request_bodies: I,
+    ) -> Self {
+        self.request_bodies.extend(
+            request_bodies
+                .into_iter()
+                .map(|(name, request_body)| (name.into(), request_body.into())),
+        );
+
+        self
+    }
+
+    /// Add [`Header`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable header and `header` which is the
+    /// reusable header itself. The header can be referenced with [`Ref::from_header_name`].
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+        self
+    }
+
+    /// Add multiple [`Header`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple headers by
+    /// any iterator what returns tuples of (name, header) values.
+    pub fn headers_from_iter<
+        I: IntoIterator<Item = (S, H)>,
+        S: Into<String>,
+        H: Into<RefOr<Header>>,
+    >(
+        mut self,
+        headers: I,
+    ) -> Self {
+        self.headers.extend(
+            headers
+                .into_iter()
+                .map(|(name, header)| (name.into(), header.into())),
+        );
+
+        self
+    }
+
     /// Add [`SecurityScheme`] to [`Components`].
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -259,6 +500,106 @@ impl ComponentsBuilder {
         self
     }
 
+    /// Add [`Link`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable link and `link` which is the
+    /// reusable link itself. The link can be referenced with [`Ref::from_link_name`].
+    pub fn link<S: Into<String>, L: Into<RefOr<Link>>>(mut self, name: S, link: L) -> Self {
+        self.links.insert(name.into(), link.into());
+        self
+    }
+
+    /// Add multiple [`Link`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple links by
+    /// any iterator what returns tuples of (name, link) values.
+    pub fn links_from_iter<
+        I: IntoIterator<Item = (S, L)>,
+        S: Into<String>,
+        L: Into<RefOr<Link>>,
+    >(
+        mut self,
+        links: I,
+    ) -> Self {
+        self.links.extend(
+            links
+                .into_iter()
+                .map(|(name, link)| (name.into(), link.into())),
+        );
+
+        self
+    }
+
+    /// Add [`Callback`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable callback and `callback` which is the
+    /// reusable callback itself. The callback can be referenced with
+    /// [`Ref::from_callback_name`].
+    pub fn callback<S: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: S,
+        callback: C,
+    ) -> Self {
+        self.callbacks.insert(name.into(), callback.into());
+        self
+    }
+
+    /// Add multiple [`Callback`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple callbacks by
+    /// any iterator what returns tuples of (name, callback) values.
+    pub fn callbacks_from_iter<
+        I: IntoIterator<Item = (S, C)>,
+        S: Into<String>,
+        C: Into<RefOr<Callback>>,
+    >(
+        mut self,
+        callbacks: I,
+    ) -> Self {
+        self.callbacks.extend(
+            callbacks
+                .into_iter()
+                .map(|(name, callback)| (name.into(), callback.into())),
+        );
+
+        self
+    }
+
+    /// Add [`PathItem`] to [`Components`].
+    ///
+    /// Method accepts two arguments; `name` of the reusable path item and `path_item` which is
+    /// the reusable path item itself. The path item can be referenced with
+    /// [`Ref::from_path_item_name`].
+    pub fn path_item<S: Into<String>, P: Into<RefOr<PathItem>>>(
+        mut self,
+        name: S,
+        path_item: P,
+    ) -> Self {
+        self.path_items.insert(name.into(), path_item.into());
+        self
+    }
+
+    /// Add multiple [`PathItem`]s to [`Components`] from iterator.
+    ///
+    /// Like the [`ComponentsBuilder::schemas_from_iter`] this allows adding multiple path items
+    /// by any iterator what returns tuples of (name, path_item) values.
+    pub fn path_items_from_iter<
+        I: IntoIterator<Item = (S, P)>,
+        S: Into<String>,
+        P: Into<RefOr<PathItem>>,
+    >(
+        mut self,
+        path_items: I,
+    ) -> Self {
+        self.path_items.extend(
+            path_items
+                .into_iter()
+                .map(|(name, path_item)| (name.into(), path_item.into())),
+        );
+
+        self
+    }
+
     /// Add openapi extensions (x-something) of the API.
     pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
         set_value!(self extensions extensions)
@@ -296,6 +637,12 @@ pub enum Schema {
     ///
     /// [composite]: https://spec.openapis.org/oas/latest.html#components-object
     AnyOf(AnyOf),
+
+    /// Defines [boolean JSON Schema][boolean_schema]. Schema `true` accepts any value and schema
+    /// `false` accepts no value at all.
+    ///
+    /// [boolean_schema]: https://json-schema.org/draft/2020-12/json-schema-core#name-boolean-json-schemas
+    Bool(bool),
 }
 
 impl Default for Schema {
@@ -304,6 +651,12 @@ impl Default for Schema {
     }
 }
 
+impl From<bool> for Schema {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
 /// OpenAPI [Discriminator][discriminator] object which can be optionally used together with
 /// [`OneOf`] composite object.
 ///
@@ -947,6 +1300,17 @@ builder! {
         #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
         pub enum_values: Option<Vec<Value>>,
 
+        /// Single constant value the [`Object`] must be equal to. Unlike
+        /// [`Object::enum_values`] this allows also constant `null` value. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-validation#name-const>
+        #[serde(
+            rename = "const",
+            skip_serializing_if = "Option::is_none",
+            default,
+            deserialize_with = "deserialize_some"
+        )]
+        pub const_value: Option<Value>,
+
         /// Vector of required field names.
         #[serde(skip_serializing_if = "Vec::is_empty", default = "Vec::new")]
         pub required: Vec<String>,
@@ -970,6 +1334,68 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub property_names: Option<Box<Schema>>,
 
+        /// Map of regular expressions with [`Schema`]s. Value of each property whose name matches
+        /// the regular expression must be valid against the corresponding [`Schema`]. See more
+        /// details <https://json-schema.org/draft/2020-12/json-schema-core#name-patternproperties>
+        #[serde(skip_serializing_if = "ObjectPropertiesMap::is_empty", default = "ObjectPropertiesMap::new")]
+        pub pattern_properties: ObjectPropertiesMap<String, RefOr<Schema>>,
+
+        /// Map of property names with list of property names which are required when the property
+        /// is present. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-validation#name-dependentrequired>
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub dependent_required: BTreeMap<String, Vec<String>>,
+
+        /// Map of property names with [`Schema`]s the whole [`Object`] must be valid against when
+        /// the property is present. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-dependentschemas>
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub dependent_schemas: BTreeMap<String, RefOr<Schema>>,
+
+        /// [`Schema`] for properties not evaluated by any other keyword including the keywords of
+        /// the subschemas e.g. in [`AllOf`]. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-unevaluatedproperties>
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unevaluated_properties: Option<Box<AdditionalProperties<Schema>>>,
+
+        /// [`Schema`] the value must **not** be valid against. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-not>
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub not: Option<Box<RefOr<Schema>>>,
+
+        /// Conditional [`Schema`]. When the value is valid against the _`if`_ schema it must
+        /// be valid against [`Object::then_schema`], otherwise it must be valid against
+        /// [`Object::else_schema`]. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-if>
+        #[serde(rename = "if", skip_serializing_if = "Option::is_none")]
+        pub if_schema: Option<Box<RefOr<Schema>>>,
+
+        /// [`Schema`] the value must be valid against when it is valid against the
+        /// [`Object::if_schema`].
+        #[serde(rename = "then", skip_serializing_if = "Option::is_none")]
+        pub then_schema: Option<Box<RefOr<Schema>>>,
+
+        /// [`Schema`] the value must be valid against when it is not valid against the
+        /// [`Object::if_schema`].
+        #[serde(rename = "else", skip_serializing_if = "Option::is_none")]
+        pub else_schema: Option<Box<RefOr<Schema>>>,
+
+        /// Map of reusable [`Schema`]s local to this [`Object`]. These can be referenced with
+        /// JSON pointer e.g. `#/components/schemas/Pet/$defs/Name`. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-schema-re-use-with-defs>
+        #[serde(rename = "$defs", skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub defs: BTreeMap<String, RefOr<Schema>>,
+
+        /// Canonical URI of the [`Object`] schema resource. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-the-id-keyword>
+        #[serde(rename = "$id", skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// Plain name fragment which can be used to reference the [`Object`] schema. See more
+        /// details <https://json-schema.org/draft/2020-12/json-schema-core#name-defining-location-independe>
+        #[serde(rename = "$anchor", skip_serializing_if = "Option::is_none")]
+        pub anchor: Option<String>,
+
         /// Changes the [`Object`] deprecated status.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub deprecated: Option<Deprecated>,
@@ -1074,6 +1500,16 @@ fn is_false(value: &bool) -> bool {
     !*value
 }
 
+/// Deserialize present value as [`Some`] even if it is `null`. Used with `default` to distinguish
+/// `null` from missing value.
+fn deserialize_some<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
 impl Object {
     /// Initialize a new [`Object`] with default [`SchemaType`]. This effectively same as calling
     /// `Object::with_type(SchemaType::Object)`.
@@ -1152,6 +1588,113 @@ impl ObjectBuilder {
         set_value!(self property_names property_name.map(|property_name| Box::new(property_name.into())))
     }
 
+    /// Add new pattern property to the [`Object`].
+    ///
+    /// Method accepts regular expression and [`Schema`] the values of matching properties must be
+    /// valid against.
+    pub fn pattern_property<S: Into<String>, I: Into<RefOr<Schema>>>(
+        mut self,
+        pattern: S,
+        component: I,
+    ) -> Self {
+        self.pattern_properties
+            .insert(pattern.into(), component.into());
+
+        self
+    }
+
+    /// Add list of properties which are required when _`property`_ is present in the [`Object`].
+    pub fn dependent_required<S: Into<String>, I: IntoIterator<Item = R>, R: Into<String>>(
+        mut self,
+        property: S,
+        required: I,
+    ) -> Self {
+        self.dependent_required.insert(
+            property.into(),
+            required.into_iter().map(Into::into).collect(),
+        );
+
+        self
+    }
+
+    /// Add [`Schema`] the [`Object`] must be valid against when _`property`_ is present in the
+    /// [`Object`].
+    pub fn dependent_schema<S: Into<String>, I: Into<RefOr<Schema>>>(
+        mut self,
+        property: S,
+        component: I,
+    ) -> Self {
+        self.dependent_schemas
+            .insert(property.into(), component.into());
+
+        self
+    }
+
+    /// Add or change [`Schema`] for properties not evaluated by any other keyword. Use `false` to
+    /// disallow any unevaluated properties.
+    pub fn unevaluated_properties<I: Into<AdditionalProperties<Schema>>>(
+        mut self,
+        unevaluated_properties: Option<I>,
+    ) -> Self {
+        set_value!(self unevaluated_properties unevaluated_properties.map(|unevaluated_properties| Box::new(unevaluated_properties.into())))
+    }
+
+    /// Add or change [`Schema`] the value must **not** be valid against.
+    pub fn not<I: Into<RefOr<Schema>>>(mut self, not: Option<I>) -> Self {
+        set_value!(self not not.map(|not| Box::new(not.into())))
+    }
+
+    /// Add or change the _`if`_ [`Schema`] of conditional validation.
+    ///
+    /// # Examples
+    ///
+    /// _**Require `postal_code` to be numeric only when `country` is `"FI"`.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ObjectBuilder, Type};
+    /// let schema = ObjectBuilder::new()
+    ///     .property("country", ObjectBuilder::new().schema_type(Type::String))
+    ///     .property("postal_code", ObjectBuilder::new().schema_type(Type::String))
+    ///     .if_schema(Some(
+    ///         ObjectBuilder::new()
+    ///             .property("country", ObjectBuilder::new().const_value(Some("FI"))),
+    ///     ))
+    ///     .then_schema(Some(
+    ///         ObjectBuilder::new()
+    ///             .property("postal_code", ObjectBuilder::new().pattern(Some("^[0-9]{5}$"))),
+    ///     ))
+    ///     .build();
+    /// ```
+    pub fn if_schema<I: Into<RefOr<Schema>>>(mut self, if_schema: Option<I>) -> Self {
+        set_value!(self if_schema if_schema.map(|if_schema| Box::new(if_schema.into())))
+    }
+
+    /// Add or change the _`then`_ [`Schema`] of conditional validation.
+    pub fn then_schema<I: Into<RefOr<Schema>>>(mut self, then_schema: Option<I>) -> Self {
+        set_value!(self then_schema then_schema.map(|then_schema| Box::new(then_schema.into())))
+    }
+
+    /// Add or change the _`else`_ [`Schema`] of conditional validation.
+    pub fn else_schema<I: Into<RefOr<Schema>>>(mut self, else_schema: Option<I>) -> Self {
+        set_value!(self else_schema else_schema.map(|else_schema| Box::new(else_schema.into())))
+    }
+
+    /// Add new local reusable [`Schema`] to the _`$defs`_ of the [`Object`].
+    pub fn def<S: Into<String>, I: Into<RefOr<Schema>>>(mut self, name: S, component: I) -> Self {
+        self.defs.insert(name.into(), component.into());
+
+        self
+    }
+
+    /// Add or change canonical URI _`$id`_ of the [`Object`].
+    pub fn id<S: Into<String>>(mut self, id: Option<S>) -> Self {
+        set_value!(self id id.map(Into::into))
+    }
+
+    /// Add or change plain name fragment _`$anchor`_ of the [`Object`].
+    pub fn anchor<S: Into<String>>(mut self, anchor: Option<S>) -> Self {
+        set_value!(self anchor anchor.map(Into::into))
+    }
+
     /// Add field to the required fields of [`Object`].
     pub fn required<I: Into<String>>(mut self, required_field: I) -> Self {
         self.required.push(required_field.into());
@@ -1188,6 +1731,22 @@ impl ObjectBuilder {
             enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
     }
 
+    /// Add or change constant value the [`Object`] must be equal to.
+    ///
+    /// # Examples
+    ///
+    /// _**Create `string` schema that only accepts value `"cat"`.**_
+    /// ```rust
+    /// # use utoipa::openapi::schema::{ObjectBuilder, Type};
+    /// let schema = ObjectBuilder::new()
+    ///     .schema_type(Type::String)
+    ///     .const_value(Some("cat"))
+    ///     .build();
+    /// ```
+    pub fn const_value<V: Into<Value>>(mut self, const_value: Option<V>) -> Self {
+        set_value!(self const_value const_value.map(Into::into))
+    }
+
     /// Add or change example shown in UI of the value for richer documentation.
     ///
     /// **Deprecated since 3.0.x. Prefer [`Object::examples`] instead**
@@ -1324,10 +1883,18 @@ impl From<ObjectBuilder> for ArrayItems {
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(untagged)]
 pub enum AdditionalProperties<T> {
-    /// Use when value type of the map is a known [`Schema`] or [`Ref`] to the [`Schema`].
-    RefOr(RefOr<T>),
+    // `FreeForm` is declared first so that boolean values are not deserialized as boolean
+    // `Schema`s.
     /// Use _`AdditionalProperties::FreeForm(true)`_ when any value is allowed in the map.
     FreeForm(bool),
+    /// Use when value type of the map is a known [`Schema`] or [`Ref`] to the [`Schema`].
+    RefOr(RefOr<T>),
+}
+
+impl<T> From<bool> for AdditionalProperties<T> {
+    fn from(value: bool) -> Self {
+        Self::FreeForm(value)
+    }
 }
 
 impl<T> From<RefOr<T>> for AdditionalProperties<T> {
@@ -1422,6 +1989,51 @@ impl Ref {
         Self::new(format!("#/components/responses/{}", response_name.into()))
     }
 
+    /// Construct a new [`Ref`] from provided parameter name. This will create a [`Ref`] that
+    /// references the reusable parameter.
+    pub fn from_parameter_name<I: Into<String>>(parameter_name: I) -> Self {
+        Self::new(format!("#/components/parameters/{}", parameter_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided example name. This will create a [`Ref`] that
+    /// references the reusable example.
+    pub fn from_example_name<I: Into<String>>(example_name: I) -> Self {
+        Self::new(format!("#/components/examples/{}", example_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided request body name. This will create a [`Ref`] that
+    /// references the reusable request body.
+    pub fn from_request_body_name<I: Into<String>>(request_body_name: I) -> Self {
+        Self::new(format!(
+            "#/components/requestBodies/{}",
+            request_body_name.into()
+        ))
+    }
+
+    /// Construct a new [`Ref`] from provided header name. This will create a [`Ref`] that
+    /// references the reusable header.
+    pub fn from_header_name<I: Into<String>>(header_name: I) -> Self {
+        Self::new(format!("#/components/headers/{}", header_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided link name. This will create a [`Ref`] that
+    /// references the reusable link.
+    pub fn from_link_name<I: Into<String>>(link_name: I) -> Self {
+        Self::new(format!("#/components/links/{}", link_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided callback name. This will create a [`Ref`] that
+    /// references the reusable callback.
+    pub fn from_callback_name<I: Into<String>>(callback_name: I) -> Self {
+        Self::new(format!("#/components/callbacks/{}", callback_name.into()))
+    }
+
+    /// Construct a new [`Ref`] from provided path item name. This will create a [`Ref`] that
+    /// references the reusable path item.
+    pub fn from_path_item_name<I: Into<String>>(path_item_name: I) -> Self {
+        Self::new(format!("#/components/pathItems/{}", path_item_name.into()))
+    }
+
     to_array_builder!();
 }
 
@@ -1531,8 +2143,7 @@ where
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(untagged)]
 pub enum ArrayItems {
-    /// Defines [`Array::items`] as [`RefOr::T(Schema)`]. This is the default for [`Array`].
-    RefOrSchema(Box<RefOr<Schema>>),
+    // `False` is declared first so that `false` is not deserialized as boolean `Schema`.
     /// Defines [`Array::items`] as `false` indicating that no extra items are allowed to the
     /// [`Array`]. This can be used together with [`Array::prefix_items`] to disallow [additional
     /// items][additional_items] in [`Array`].
@@ -1540,6 +2151,8 @@ pub enum ArrayItems {
     /// [additional_items]: <https://json-schema.org/understanding-json-schema/reference/array#additionalitems>
     #[serde(with = "array_items_false")]
     False,
+    /// Defines [`Array::items`] as [`RefOr::T(Schema)`]. This is the default for [`Array`].
+    RefOrSchema(Box<RefOr<Schema>>),
 }
 
 mod array_items_false {
@@ -1652,6 +2265,21 @@ builder! {
         #[serde(default, skip_serializing_if = "is_false")]
         pub unique_items: bool,
 
+        /// [`Schema`] at least one item of the [`Array`] must be valid against. The amount of
+        /// matching items can be adjusted with [`Array::min_contains`] and
+        /// [`Array::max_contains`]. See more details
+        /// <https://json-schema.org/draft/2020-12/json-schema-core#name-contains>
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub contains: Option<Box<RefOr<Schema>>>,
+
+        /// Minimum number of items that must be valid against [`Array::contains`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min_contains: Option<usize>,
+
+        /// Maximum number of items that can be valid against [`Array::contains`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max_contains: Option<usize>,
+
         /// Xml format of the array.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub xml: Option<Xml>,
@@ -1686,6 +2314,9 @@ impl Default for Array {
             title: Default::default(),
             schema_type: Type::Array.into(),
             unique_items: bool::default(),
+            contains: Default::default(),
+            min_contains: Default::default(),
+            max_contains: Default::default(),
             items: Default::default(),
             prefix_items: Vec::default(),
             description: Default::default(),
@@ -1822,6 +2453,21 @@ impl ArrayBuilder {
         set_value!(self unique_items unique_items)
     }
 
+    /// Set or change [`Schema`] at least one item of the [`Array`] must be valid against.
+    pub fn contains<I: Into<RefOr<Schema>>>(mut self, contains: Option<I>) -> Self {
+        set_value!(self contains contains.map(|contains| Box::new(contains.into())))
+    }
+
+    /// Set or change minimum number of items that must be valid against [`Array::contains`].
+    pub fn min_contains(mut self, min_contains: Option<usize>) -> Self {
+        set_value!(self min_contains min_contains)
+    }
+
+    /// Set or change maximum number of items that can be valid against [`Array::contains`].
+    pub fn max_contains(mut self, max_contains: Option<usize>) -> Self {
+        set_value!(self max_contains max_contains)
+    }
+
     /// Set [`Xml`] formatting for [`Array`].
     pub fn xml(mut self, xml: Option<Xml>) -> Self {
         set_value!(self xml xml)
@@ -2131,6 +2777,323 @@ mod tests {
     use super::*;
     use crate::openapi::*;
 
+    #[test]
+    fn components_with_reusable_objects() {
+        let components = ComponentsBuilder::new()
+            .parameter(
+                "page",
+                path::ParameterBuilder::new()
+                    .name("page")
+                    .parameter_in(path::ParameterIn::Query),
+            )
+            .example(
+                "pet",
+                example::ExampleBuilder::new().value(Some(json!({"name": "Tom"}))),
+            )
+            .request_body(
+                "Pet",
+                request_body::RequestBodyBuilder::new().description(Some("Pet to store")),
+            )
+            .header(
+                "X-Rate-Limit",
+                Header::new(Object::with_type(Type::Integer)),
+            )
+            .link("GetPet", Ref::from_link_name("GetPetById"))
+            .callback("onEvent", Ref::from_callback_name("onOtherEvent"))
+            .path_item(
+                "Pets",
+                PathItem::new(
+                    HttpMethod::Get,
+                    path::OperationBuilder::new()
+                        .parameter(Ref::from_parameter_name("page"))
+                        .response("200", Response::new("Pets")),
+                ),
+            )
+            .build();
+
+        assert_json_eq!(
+            &components,
+            json!({
+                "parameters": {
+                    "page": {
+                        "name": "page",
+                        "in": "query",
+                        "required": false
+                    }
+                },
+                "examples": {
+                    "pet": {
+                        "value": {
+                            "name": "Tom"
+                        }
+                    }
+                },
+                "requestBodies": {
+                    "Pet": {
+                        "description": "Pet to store",
+                        "content": {}
+                    }
+                },
+                "headers": {
+                    "X-Rate-Limit": {
+                        "schema": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "links": {
+                    "GetPet": {
+                        "$ref": "#/components/links/GetPetById"
+                    }
+                },
+                "callbacks": {
+                    "onEvent": {
+                        "$ref": "#/components/callbacks/onOtherEvent"
+                    }
+                },
+                "pathItems": {
+                    "Pets": {
+                        "get": {
+                            "parameters": [
+                                {
+                                    "$ref": "#/components/parameters/page"
+                                }
+                            ],
+                            "responses": {
+                                "200": {
+                                    "description": "Pets"
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+
+        let value = serde_json::to_value(&components).expect("components must serialize");
+        let deserialized = serde_json::from_value::<Components>(value.clone())
+            .expect("components must deserialize");
+        assert_json_eq!(deserialized, value);
+    }
+
+    #[test]
+    fn object_with_json_schema_keywords() {
+        let schema = Schema::from(
+            ObjectBuilder::new()
+                .id(Some("https://example.com/pet.json"))
+                .anchor(Some("pet"))
+                .def("Name", ObjectBuilder::new().schema_type(Type::String))
+                .property(
+                    "kind",
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .const_value(Some("pet")),
+                )
+                .property("country", ObjectBuilder::new().schema_type(Type::String))
+                .property(
+                    "postal_code",
+                    ObjectBuilder::new().schema_type(Type::String),
+                )
+                .property(
+                    "tags",
+                    ArrayBuilder::new()
+                        .items(ObjectBuilder::new().schema_type(Type::String))
+                        .contains(Some(
+                            ObjectBuilder::new()
+                                .schema_type(Type::String)
+                                .const_value(Some("cute")),
+                        ))
+                        .min_contains(Some(1))
+                        .max_contains(Some(2)),
+                )
+                .pattern_property("^x-", ObjectBuilder::new().schema_type(Type::String))
+                .dependent_required("postal_code", ["country"])
+                .dependent_schema("country", ObjectBuilder::new().required("postal_code"))
+                .unevaluated_properties(Some(false))
+                .not(Some(ObjectBuilder::new().required("deleted")))
+                .if_schema(Some(
+                    ObjectBuilder::new().property(
+                        "country",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .const_value(Some("FI")),
+                    ),
+                ))
+                .then_schema(Some(
+                    ObjectBuilder::new().property(
+                        "postal_code",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .pattern(Some("^[0-9]{5}$")),
+                    ),
+                ))
+                .else_schema(Some(Schema::Bool(true)))
+                .build(),
+        );
+
+        let json_value = json!({
+            "type": "object",
+            "$id": "https://example.com/pet.json",
+            "$anchor": "pet",
+            "$defs": {
+                "Name": {
+                    "type": "string"
+                }
+            },
+            "properties": {
+                "kind": {
+                    "type": "string",
+                    "const": "pet"
+                },
+                "country": {
+                    "type": "string"
+                },
+                "postal_code": {
+                    "type": "string"
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "contains": {
+                        "type": "string",
+                        "const": "cute"
+                    },
+                    "minContains": 1,
+                    "maxContains": 2
+                }
+            },
+            "patternProperties": {
+                "^x-": {
+                    "type": "string"
+                }
+            },
+            "dependentRequired": {
+                "postal_code": ["country"]
+            },
+            "dependentSchemas": {
+                "country": {
+                    "type": "object",
+                    "required": ["postal_code"]
+                }
+            },
+            "unevaluatedProperties": false,
+            "not": {
+                "type": "object",
+                "required": ["deleted"]
+            },
+            "if": {
+                "type": "object",
+                "properties": {
+                    "country": {
+                        "type": "string",
+                        "const": "FI"
+                    }
+                }
+            },
+            "then": {
+                "type": "object",
+                "properties": {
+                    "postal_code": {
+                        "type": "string",
+                        "pattern": "^[0-9]{5}$"
+                    }
+                }
+            },
+            "else": true
+        });
+        assert_json_eq!(&schema, json_value);
+
+        let deserialized: Schema =
+            serde_json::from_value(json_value.clone()).expect("schema must deserialize");
+        assert_json_eq!(deserialized, json_value);
+    }
+
+    #[test]
+    fn object_with_null_const_value() {
+        let schema = ObjectBuilder::new()
+            .schema_type(Type::Null)
+            .const_value(Some(Value::Null))
+            .build();
+
+        let value = serde_json::to_value(&schema).expect("object must serialize");
+        assert_json_eq!(value, json!({ "type": "null", "const": null }));
+
+        let deserialized: Object = serde_json::from_value(value).expect("object must deserialize");
+        assert_eq!(deserialized.const_value, Some(Value::Null));
+        let deserialized: Object =
+            serde_json::from_value(json!({ "type": "string" })).expect("object must deserialize");
+        assert_eq!(deserialized.const_value, None);
+    }
+
+    #[test]
+    fn boolean_schemas() {
+        let schema = ObjectBuilder::new()
+            .property("any", Schema::Bool(true))
+            .property("none", Schema::from(false))
+            .additional_properties(Some(false))
+            .property("empty", ArrayBuilder::new().items(ArrayItems::False))
+            .build();
+
+        let value = serde_json::to_value(&schema).expect("object must serialize");
+        assert_json_eq!(
+            &value,
+            json!({
+                "type": "object",
+                "properties": {
+                    "any": true,
+                    "none": false,
+                    "empty": {
+                        "type": "array",
+                        "items": false
+                    }
+                },
+                "additionalProperties": false
+            })
+        );
+
+        let deserialized: Object =
+            serde_json::from_value(value.clone()).expect("object must deserialize");
+        assert_json_eq!(&deserialized, value);
+        assert!(matches!(
+            deserialized.additional_properties.as_deref(),
+            Some(AdditionalProperties::FreeForm(false))
+        ));
+    }
+
+    #[test]
+    fn reusable_object_refs() {
+        assert_eq!(
+            Ref::from_parameter_name("page").ref_location,
+            "#/components/parameters/page"
+        );
+        assert_eq!(
+            Ref::from_example_name("pet").ref_location,
+            "#/components/examples/pet"
+        );
+        assert_eq!(
+            Ref::from_request_body_name("Pet").ref_location,
+            "#/components/requestBodies/Pet"
+        );
+        assert_eq!(
+            Ref::from_header_name("X-Rate-Limit").ref_location,
+            "#/components/headers/X-Rate-Limit"
+        );
+        assert_eq!(
+            Ref::from_link_name("GetPet").ref_location,
+            "#/components/links/GetPet"
+        );
+        assert_eq!(
+            Ref::from_callback_name("onEvent").ref_location,
+            "#/components/callbacks/onEvent"
+        );
+        assert_eq!(
+            Ref::from_path_item_name("Pets").ref_location,
+            "#/components/pathItems/Pets"
+        );
+    }
+
     #[test]
     fn create_schema_serializes_json() -> Result<(), serde_json::Error> {
         let openapi = OpenApiBuilder::new()
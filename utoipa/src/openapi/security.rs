@@ -28,7 +28,7 @@ use super::{builder, extensions::Extensions};
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct SecurityRequirement {
     #[serde(flatten)]
-    value: BTreeMap<String, Vec<String>>,
+    pub(crate) value: BTreeMap<String, Vec<String>>,
 }
 
 impl SecurityRequirement {
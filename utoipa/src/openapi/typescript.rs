@@ -0,0 +1,820 @@
+//! Implements export of [`Components::schemas`] to TypeScript type definitions.
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use super::schema::{
+    AdditionalProperties, ArrayItems, Components, Discriminator, Object, Schema, SchemaType, Type,
+};
+use super::{Deprecated, RefOr};
+
+impl Components {
+    /// Export [`Components::schemas`] as TypeScript type definitions.
+    ///
+    /// Every schema is exported with its name as either an _`interface`_ or a _`type`_ alias:
+    /// * Object schemas with properties are exported as _`interface`_s where properties that are
+    ///   not required are optional.
+    /// * _`enum`_ values are exported as union of literal types e.g. _`"dog" | "cat"`_.
+    /// * _`OneOf`_ and _`AnyOf`_ are exported as unions and _`AllOf`_ as intersection. If the
+    ///   composite schema has a [`Discriminator`] the referenced schemas are tagged with the
+    ///   discriminator value e.g. _`({ kind: "cat" } & Cat) | ({ kind: "dog" } & Dog)`_.
+    /// * Nullable values e.g. `Option<T>` are exported as _`T | null`_.
+    /// * Maps with _`additionalProperties`_ are exported as _`Record<string, T>`_.
+    ///
+    /// If a schema has _`readOnly`_ or _`writeOnly`_ properties directly or via references
+    /// to other schemas, separate _`{name}Request`_ and _`{name}Response`_ variants are exported
+    /// in addition to the schema itself. The request variant omits read only properties and the
+    /// response variant omits write only properties.
+    ///
+    /// Descriptions are exported as doc comments and characters of schema names that are not
+    /// valid in TypeScript identifiers are replaced with _`_`_. If an identifier is already taken
+    /// by another declaration a number is appended to it e.g. _`PetRequest2`_. Schemas whose
+    /// name is a valid identifier keep it, followed by other schemas and finally the request and
+    /// response variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, Type};
+    /// let components = ComponentsBuilder::new()
+    ///     .schema(
+    ///         "Pet",
+    ///         ObjectBuilder::new()
+    ///             .property(
+    ///                 "kind",
+    ///                 ObjectBuilder::new()
+    ///                     .schema_type(Type::String)
+    ///                     .enum_values(Some(["dog", "cat"])),
+    ///             )
+    ///             .property("name", ObjectBuilder::new().schema_type(Type::String))
+    ///             .required("name"),
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     components.to_typescript(),
+    ///     r#"export interface Pet {
+    ///   kind?: "dog" | "cat";
+    ///   name: string;
+    /// }
+    /// "#
+    /// );
+    /// ```
+    pub fn to_typescript(&self) -> String {
+        let variants = schemas_with_variants(self);
+        let exporter = Exporter {
+            variants: &variants,
+            identifiers: identifiers(self, &variants),
+        };
+
+        let mut out = String::new();
+        for (name, schema) in &self.schemas {
+            for variant in Variant::of(name, &variants) {
+                if !out.is_empty() {
+                    out.push('\n');
+                }
+                exporter.write_declaration(&mut out, name, schema, *variant);
+            }
+        }
+
+        out
+    }
+}
+
+/// Variant of the exported schema.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Variant {
+    /// All properties.
+    All,
+    /// Properties without read only properties.
+    Request,
+    /// Properties without write only properties.
+    Response,
+}
+
+impl Variant {
+    /// Variants exported for the schema with `name`.
+    fn of(name: &str, variants: &BTreeSet<&str>) -> &'static [Self] {
+        if variants.contains(name) {
+            &[Self::All, Self::Request, Self::Response]
+        } else {
+            &[Self::All]
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::All => "",
+            Self::Request => "Request",
+            Self::Response => "Response",
+        }
+    }
+
+    fn includes(self, schema: &RefOr<Schema>) -> bool {
+        let RefOr::T(Schema::Object(object)) = schema else {
+            return true;
+        };
+        match self {
+            Self::All => true,
+            Self::Request => object.read_only != Some(true),
+            Self::Response => object.write_only != Some(true),
+        }
+    }
+}
+
+/// Resolve unique TypeScript identifier for every exported variant of the schemas.
+fn identifiers<'a>(
+    components: &'a Components,
+    variants: &BTreeSet<&str>,
+) -> HashMap<(&'a str, Variant), String> {
+    let mut declarations = components
+        .schemas
+        .keys()
+        .flat_map(|name| {
+            Variant::of(name, variants)
+                .iter()
+                .map(move |variant| (name.as_str(), *variant))
+        })
+        .collect::<Vec<_>>();
+    // stable sort keeps the schema order within the same priority
+    declarations.sort_by_key(|(name, variant)| match variant {
+        Variant::All if identifier(name) == *name => 0,
+        Variant::All => 1,
+        Variant::Request | Variant::Response => 2,
+    });
+
+    let mut taken = HashSet::new();
+    declarations
+        .into_iter()
+        .map(|(name, variant)| {
+            let base = format!("{}{}", identifier(name), variant.suffix());
+            let identifier = (1..)
+                .map(|index| match index {
+                    1 => base.clone(),
+                    index => format!("{base}{index}"),
+                })
+                .find(|identifier| taken.insert(identifier.clone()))
+                .unwrap_or(base);
+            ((name, variant), identifier)
+        })
+        .collect()
+}
+
+/// Find names of the schemas which have read only or write only properties directly or via
+/// references to other such schemas.
+fn schemas_with_variants(components: &Components) -> BTreeSet<&str> {
+    let schemas = components
+        .schemas
+        .iter()
+        .map(|(name, schema)| {
+            let value = serde_json::to_value(schema).unwrap_or_default();
+            let mut references = Vec::new();
+            collect_references(&value, &mut references);
+            (name.as_str(), has_read_or_write_only(&value), references)
+        })
+        .collect::<Vec<_>>();
+
+    let mut variants = schemas
+        .iter()
+        .filter(|(_, direct, _)| *direct)
+        .map(|(name, _, _)| *name)
+        .collect::<BTreeSet<_>>();
+    loop {
+        let found = schemas
+            .iter()
+            .filter(|(name, _, references)| {
+                !variants.contains(name)
+                    && references
+                        .iter()
+                        .any(|reference| variants.contains(reference.as_str()))
+            })
+            .map(|(name, _, _)| *name)
+            .collect::<Vec<_>>();
+        if found.is_empty() {
+            break variants;
+        }
+        variants.extend(found);
+    }
+}
+
+fn has_read_or_write_only(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object.iter().any(|(key, value)| {
+            ((key == "readOnly" || key == "writeOnly") && value == &Value::Bool(true))
+                || has_read_or_write_only(value)
+        }),
+        Value::Array(values) => values.iter().any(has_read_or_write_only),
+        _ => false,
+    }
+}
+
+fn collect_references(value: &Value, references: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match value {
+                    Value::String(reference) if key == "$ref" => {
+                        if let Some(name) = reference.strip_prefix("#/components/schemas/") {
+                            references.push(String::from(name));
+                        }
+                    }
+                    value => collect_references(value, references),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_references(value, references)),
+        _ => (),
+    }
+}
+
+struct Exporter<'a> {
+    variants: &'a BTreeSet<&'a str>,
+    identifiers: HashMap<(&'a str, Variant), String>,
+}
+
+impl Exporter<'_> {
+    /// Get identifier of the `variant` of the schema with `name`. Schemas without variants
+    /// are always referenced with their only identifier.
+    fn identifier(&self, name: &str, variant: Variant) -> String {
+        let variant = if self.variants.contains(name) {
+            variant
+        } else {
+            Variant::All
+        };
+        self.identifiers
+            .get(&(name, variant))
+            .cloned()
+            .unwrap_or_else(|| format!("{}{}", identifier(name), variant.suffix()))
+    }
+
+    fn write_declaration(
+        &self,
+        out: &mut String,
+        name: &str,
+        schema: &RefOr<Schema>,
+        variant: Variant,
+    ) {
+        let identifier = self.identifier(name, variant);
+        if let RefOr::T(schema) = schema {
+            write_doc(out, "", description(schema), deprecated(schema));
+        }
+
+        match schema {
+            RefOr::T(Schema::Object(object)) if is_interface(object) => {
+                let _ = writeln!(out, "export interface {identifier} {{");
+                self.write_properties(out, object, variant);
+                out.push_str("}\n");
+            }
+            schema => {
+                let _ = writeln!(
+                    out,
+                    "export type {identifier} = {};",
+                    self.type_of(schema, variant)
+                );
+            }
+        }
+    }
+
+    fn write_properties(&self, out: &mut String, object: &Object, variant: Variant) {
+        for (name, schema) in &object.properties {
+            if !variant.includes(schema) {
+                continue;
+            }
+            match schema {
+                RefOr::T(schema) => {
+                    write_doc(out, "  ", description(schema), deprecated(schema));
+                }
+                RefOr::Ref(reference) if !reference.description.is_empty() => {
+                    write_doc(out, "  ", Some(&reference.description), false);
+                }
+                RefOr::Ref(_) => (),
+            }
+            let _ = writeln!(
+                out,
+                "  {}{}: {};",
+                property_name(name),
+                if object.required.contains(name) {
+                    ""
+                } else {
+                    "?"
+                },
+                self.type_of(schema, variant)
+            );
+        }
+        if let Some(value_type) = self.additional_properties(object, variant) {
+            let _ = writeln!(out, "  [key: string]: {value_type};");
+        }
+    }
+
+    fn type_of(&self, schema: &RefOr<Schema>, variant: Variant) -> String {
+        match schema {
+            RefOr::Ref(reference) => {
+                match reference.ref_location.strip_prefix("#/components/schemas/") {
+                    Some(name) => self.identifier(name, variant),
+                    None => String::from("unknown"),
+                }
+            }
+            RefOr::T(schema) => self.schema_type_of(schema, variant),
+        }
+    }
+
+    fn schema_type_of(&self, schema: &Schema, variant: Variant) -> String {
+        match schema {
+            Schema::Object(object) => self.object_type_of(object, variant),
+            Schema::Array(array) => {
+                let rest = match &array.items {
+                    ArrayItems::RefOrSchema(items) => Some(group(&self.type_of(items, variant))),
+                    ArrayItems::False => None,
+                };
+                let array_type = if array.prefix_items.is_empty() {
+                    rest.map(|rest| format!("{rest}[]"))
+                        .unwrap_or_else(|| String::from("[]"))
+                } else {
+                    let mut items = array
+                        .prefix_items
+                        .iter()
+                        .map(|item| self.schema_type_of(item, variant))
+                        .collect::<Vec<_>>();
+                    if let Some(rest) = rest {
+                        items.push(format!("...{rest}[]"));
+                    }
+                    format!("[{}]", items.join(", "))
+                };
+
+                let mut types = vec![array_type];
+                if matches!(&array.schema_type, SchemaType::Array(schema_types) if schema_types.contains(&Type::Null))
+                {
+                    types.push(String::from("null"));
+                }
+                union(types)
+            }
+            Schema::OneOf(one_of) => {
+                self.composite_type_of(&one_of.items, one_of.discriminator.as_ref(), variant)
+            }
+            Schema::AnyOf(any_of) => {
+                self.composite_type_of(&any_of.items, any_of.discriminator.as_ref(), variant)
+            }
+            Schema::AllOf(all_of) => {
+                let types = all_of
+                    .items
+                    .iter()
+                    .map(|item| group(&self.type_of(item, variant)))
+                    .collect::<Vec<_>>();
+                match types.len() {
+                    0 => String::from("unknown"),
+                    _ => types.join(" & "),
+                }
+            }
+            Schema::Bool(true) => String::from("unknown"),
+            Schema::Bool(false) => String::from("never"),
+        }
+    }
+
+    fn object_type_of(&self, object: &Object, variant: Variant) -> String {
+        if let Some(const_value) = &object.const_value {
+            return literal(const_value);
+        }
+        if let Some(enum_values) = object
+            .enum_values
+            .as_ref()
+            .filter(|values| !values.is_empty())
+        {
+            return union(enum_values.iter().map(literal).collect());
+        }
+
+        let schema_types = match &object.schema_type {
+            SchemaType::Type(schema_type) => vec![schema_type],
+            SchemaType::Array(schema_types) => schema_types.iter().collect(),
+            SchemaType::AnyValue => return String::from("unknown"),
+        };
+        union(
+            schema_types
+                .into_iter()
+                .map(|schema_type| match schema_type {
+                    Type::Object => self.inline_object_type_of(object, variant),
+                    Type::String => String::from("string"),
+                    Type::Integer | Type::Number => String::from("number"),
+                    Type::Boolean => String::from("boolean"),
+                    Type::Array => String::from("unknown[]"),
+                    Type::Null => String::from("null"),
+                })
+                .collect(),
+        )
+    }
+
+    fn inline_object_type_of(&self, object: &Object, variant: Variant) -> String {
+        if object.properties.is_empty() {
+            return format!(
+                "Record<string, {}>",
+                self.additional_properties(object, variant)
+                    .unwrap_or_else(|| String::from("unknown"))
+            );
+        }
+
+        let mut properties = object
+            .properties
+            .iter()
+            .filter(|(_, schema)| variant.includes(schema))
+            .map(|(name, schema)| {
+                format!(
+                    "{}{}: {}",
+                    property_name(name),
+                    if object.required.contains(name) {
+                        ""
+                    } else {
+                        "?"
+                    },
+                    self.type_of(schema, variant)
+                )
+            })
+            .collect::<Vec<_>>();
+        if let Some(value_type) = self.additional_properties(object, variant) {
+            properties.push(format!("[key: string]: {value_type}"));
+        }
+
+        format!("{{ {} }}", properties.join("; "))
+    }
+
+    /// Type of additional properties of the `object` or `None` if not defined.
+    fn additional_properties(&self, object: &Object, variant: Variant) -> Option<String> {
+        object
+            .additional_properties
+            .as_deref()
+            .map(|additional_properties| match additional_properties {
+                AdditionalProperties::RefOr(schema) if object.properties.is_empty() => {
+                    self.type_of(schema, variant)
+                }
+                // other properties must be assignable to the index signature
+                AdditionalProperties::RefOr(_) | AdditionalProperties::FreeForm(true) => {
+                    String::from("unknown")
+                }
+                AdditionalProperties::FreeForm(false) => String::from("never"),
+            })
+    }
+
+    fn composite_type_of(
+        &self,
+        items: &[RefOr<Schema>],
+        discriminator: Option<&Discriminator>,
+        variant: Variant,
+    ) -> String {
+        if items.is_empty() {
+            return String::from("unknown");
+        }
+
+        union(
+            items
+                .iter()
+                .map(|item| {
+                    let item_type = self.type_of(item, variant);
+                    match (discriminator, item) {
+                        (Some(discriminator), RefOr::Ref(reference)) => {
+                            let tag = discriminator
+                                .mapping
+                                .iter()
+                                .find(|(_, location)| **location == reference.ref_location)
+                                .map(|(tag, _)| tag.as_str())
+                                .unwrap_or_else(|| {
+                                    reference
+                                        .ref_location
+                                        .rsplit('/')
+                                        .next()
+                                        .unwrap_or_default()
+                                });
+                            format!(
+                                "({{ {}: {} }} & {item_type})",
+                                property_name(&discriminator.property_name),
+                                literal(&Value::from(tag))
+                            )
+                        }
+                        _ => item_type,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Whether the schema is exported as an _`interface`_ instead of a _`type`_ alias.
+fn is_interface(object: &Object) -> bool {
+    object.schema_type == SchemaType::Type(Type::Object)
+        && !object.properties.is_empty()
+        && object.enum_values.is_none()
+        && object.const_value.is_none()
+}
+
+/// Join types as union. Duplicates are removed and _`null`_ is placed last.
+fn union(types: Vec<String>) -> String {
+    let mut unique = Vec::<String>::new();
+    for schema_type in types {
+        if !unique.contains(&schema_type) {
+            unique.push(schema_type);
+        }
+    }
+    if let Some(index) = unique.iter().position(|schema_type| schema_type == "null") {
+        let null = unique.remove(index);
+        unique.push(null);
+    }
+
+    match unique.len() {
+        0 => String::from("never"),
+        _ => unique.join(" | "),
+    }
+}
+
+/// Wrap union and intersection types with parentheses.
+fn group(schema_type: &str) -> String {
+    let mut depth = 0;
+    let mut is_compound = false;
+    for c in schema_type.chars() {
+        match c {
+            '(' | '{' | '[' | '<' => depth += 1,
+            ')' | '}' | ']' | '>' => depth -= 1,
+            '|' | '&' if depth == 0 => is_compound = true,
+            _ => (),
+        }
+    }
+
+    if is_compound {
+        format!("({schema_type})")
+    } else {
+        String::from(schema_type)
+    }
+}
+
+fn literal(value: &Value) -> String {
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => value.to_string(),
+        Value::Array(_) | Value::Object(_) => String::from("unknown"),
+    }
+}
+
+fn description(schema: &Schema) -> Option<&str> {
+    match schema {
+        Schema::Object(object) => object.description.as_deref(),
+        Schema::Array(array) => array.description.as_deref(),
+        Schema::OneOf(one_of) => one_of.description.as_deref(),
+        Schema::AllOf(all_of) => all_of.description.as_deref(),
+        Schema::AnyOf(any_of) => any_of.description.as_deref(),
+        Schema::Bool(_) => None,
+    }
+}
+
+fn deprecated(schema: &Schema) -> bool {
+    let deprecated = match schema {
+        Schema::Object(object) => object.deprecated.as_ref(),
+        Schema::Array(array) => array.deprecated.as_ref(),
+        _ => None,
+    };
+
+    matches!(deprecated, Some(Deprecated::True))
+}
+
+fn write_doc(out: &mut String, indent: &str, description: Option<&str>, deprecated: bool) {
+    let mut lines = description
+        .map(|description| {
+            description
+                .trim()
+                .lines()
+                .map(|line| line.trim_end().replace("*/", "*\\/"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if deprecated {
+        lines.push(String::from("@deprecated"));
+    }
+
+    match lines.as_slice() {
+        [] => (),
+        [line] => {
+            let _ = writeln!(out, "{indent}/** {line} */");
+        }
+        lines => {
+            let _ = writeln!(out, "{indent}/**");
+            for line in lines {
+                let _ = writeln!(out, "{}", format!("{indent} * {line}").trim_end());
+            }
+            let _ = writeln!(out, "{indent} */");
+        }
+    }
+}
+
+/// Convert schema name to valid TypeScript identifier.
+fn identifier(name: &str) -> String {
+    let mut identifier = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) || identifier.is_empty() {
+        identifier.insert(0, '_');
+    }
+
+    identifier
+}
+
+/// Quote property name if it is not a valid TypeScript identifier.
+fn property_name(name: &str) -> String {
+    let is_identifier = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        String::from(name)
+    } else {
+        Value::from(name).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::schema::{ArrayBuilder, ObjectBuilder, OneOfBuilder};
+    use crate::openapi::{ComponentsBuilder, Ref};
+
+    use super::*;
+
+    #[test]
+    fn to_typescript_exports_interfaces_and_type_aliases() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Pet",
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .item(Ref::from_schema_name("Dog"))
+                    .discriminator(Some(Discriminator::with_mapping(
+                        "kind",
+                        [("dog", "#/components/schemas/Dog")],
+                    ))),
+            )
+            .schema(
+                "Cat",
+                ObjectBuilder::new()
+                    .description(Some("A cat.\n\nLikes to sleep."))
+                    .property(
+                        "lives",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .description(Some("Remaining lives")),
+                    )
+                    .required("lives")
+                    .property(
+                        "name",
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::from_iter([Type::String, Type::Null])),
+                    ),
+            )
+            .schema(
+                "Dog",
+                ObjectBuilder::new()
+                    .property(
+                        "id",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .read_only(Some(true)),
+                    )
+                    .required("id")
+                    .property(
+                        "password",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .write_only(Some(true)),
+                    )
+                    .property(
+                        "tricks",
+                        ArrayBuilder::new().items(
+                            ObjectBuilder::new()
+                                .schema_type(Type::String)
+                                .enum_values(Some(["sit", "roll-over"])),
+                        ),
+                    )
+                    .property(
+                        "x-owner",
+                        OneOfBuilder::new()
+                            .item(ObjectBuilder::new().schema_type(Type::Null))
+                            .item(Ref::from_schema_name("Owner")),
+                    ),
+            )
+            .schema(
+                "Owner",
+                ObjectBuilder::new()
+                    .property("name", ObjectBuilder::new().schema_type(Type::String))
+                    .required("name"),
+            )
+            .schema(
+                "Scores",
+                ObjectBuilder::new()
+                    .additional_properties(Some(ObjectBuilder::new().schema_type(Type::Number))),
+            )
+            .schema(
+                "Page_Pet",
+                ArrayBuilder::new().items(Ref::from_schema_name("Pet")),
+            )
+            .build();
+
+        assert_eq!(
+            components.to_typescript(),
+            r#"/**
+ * A cat.
+ *
+ * Likes to sleep.
+ */
+export interface Cat {
+  /** Remaining lives */
+  lives: number;
+  name?: string | null;
+}
+
+export interface Dog {
+  id: number;
+  password?: string;
+  tricks?: ("sit" | "roll-over")[];
+  "x-owner"?: Owner | null;
+}
+
+export interface DogRequest {
+  password?: string;
+  tricks?: ("sit" | "roll-over")[];
+  "x-owner"?: Owner | null;
+}
+
+export interface DogResponse {
+  id: number;
+  tricks?: ("sit" | "roll-over")[];
+  "x-owner"?: Owner | null;
+}
+
+export interface Owner {
+  name: string;
+}
+
+export type Page_Pet = Pet[];
+
+export type Page_PetRequest = PetRequest[];
+
+export type Page_PetResponse = PetResponse[];
+
+export type Pet = ({ kind: "Cat" } & Cat) | ({ kind: "dog" } & Dog);
+
+export type PetRequest = ({ kind: "Cat" } & Cat) | ({ kind: "dog" } & DogRequest);
+
+export type PetResponse = ({ kind: "Cat" } & Cat) | ({ kind: "dog" } & DogResponse);
+
+export type Scores = Record<string, number>;
+"#
+        );
+    }
+
+    #[test]
+    fn to_typescript_disambiguates_clashing_identifiers() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Pet",
+                ObjectBuilder::new()
+                    .property(
+                        "id",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .read_only(Some(true)),
+                    )
+                    .required("id"),
+            )
+            .schema(
+                "PetRequest",
+                ObjectBuilder::new()
+                    .property("pet", Ref::from_schema_name("Pet"))
+                    .required("pet"),
+            )
+            .schema("a.b", ObjectBuilder::new().schema_type(Type::String))
+            .schema("a_b", ObjectBuilder::new().schema_type(Type::Integer))
+            .schema(
+                "Refs",
+                ObjectBuilder::new()
+                    .property("first", Ref::from_schema_name("a.b"))
+                    .required("first")
+                    .property("second", Ref::from_schema_name("a_b"))
+                    .required("second"),
+            )
+            .build();
+
+        let typescript = components.to_typescript();
+
+        assert!(typescript.contains("export interface PetRequest2 {\n}\n"));
+        assert!(typescript.contains("export interface PetRequest {\n  pet: Pet;\n}\n"));
+        assert!(
+            typescript.contains("export interface PetRequestRequest {\n  pet: PetRequest2;\n}\n")
+        );
+        assert!(typescript.contains("export type a_b2 = string;\n"));
+        assert!(typescript.contains("export type a_b = number;\n"));
+        assert!(typescript.contains("  first: a_b2;\n  second: a_b;\n"));
+    }
+}
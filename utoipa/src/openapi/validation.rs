@@ -0,0 +1,642 @@
+//! Implements structural validation of [`OpenApi`] documents.
+//!
+//! Validation is performed with [`OpenApi::validate`] which returns list of [`ValidationIssue`]s
+//! found from the document. Each issue carries a [JSON Pointer][json_pointer] location of the
+//! offending object within the serialized document and a typed [`ValidationIssueKind`].
+//!
+//! [json_pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use super::path::{Operation, Parameter, ParameterIn, PathItem};
+use super::schema::Schema;
+use super::security::SecurityRequirement;
+use super::visit::{self, Location, Visit};
+use super::{Components, OpenApi, Ref, RefOr};
+
+/// Single issue found by [`OpenApi::validate`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ValidationIssue {
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the object within the
+    /// serialized [`OpenApi`] document where the issue was found. E.g.
+    /// _`/paths/~1pets~1{id}/get`_.
+    pub location: String,
+
+    /// Kind of the issue.
+    pub kind: ValidationIssueKind,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+/// Kind of the [`ValidationIssue`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ValidationIssueKind {
+    /// Local `$ref` points to a component that does not exist in [`Components`].
+    UnresolvedReference {
+        /// Reference location e.g. _`#/components/schemas/Pet`_.
+        reference: String,
+    },
+    /// Same `operationId` is used by more than one [`Operation`].
+    DuplicateOperationId {
+        /// The duplicated operation id.
+        operation_id: String,
+        /// Location of the operation where the operation id was first defined.
+        first_location: String,
+    },
+    /// Path template parameter e.g. `{id}` has no matching [`ParameterIn::Path`] parameter
+    /// defined in [`Operation`] or [`PathItem`].
+    MissingPathParameter {
+        /// Name of the path template parameter.
+        name: String,
+    },
+    /// [`SecurityRequirement`] names a security scheme that is not declared in
+    /// [`Components::security_schemes`].
+    UndeclaredSecurityScheme {
+        /// Name of the security scheme.
+        name: String,
+    },
+    /// Object schema lists a `required` property that is not defined in its `properties`.
+    MissingRequiredProperty {
+        /// Name of the required property.
+        property: String,
+    },
+}
+
+impl Display for ValidationIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnresolvedReference { reference } => {
+                write!(
+                    f,
+                    "reference `{reference}` does not resolve to any component"
+                )
+            }
+            Self::DuplicateOperationId {
+                operation_id,
+                first_location,
+            } => write!(
+                f,
+                "operationId `{operation_id}` is already used by operation at {first_location}"
+            ),
+            Self::MissingPathParameter { name } => write!(
+                f,
+                "path parameter `{name}` is not defined in operation or path item parameters"
+            ),
+            Self::UndeclaredSecurityScheme { name } => write!(
+                f,
+                "security scheme `{name}` is not declared in components.securitySchemes"
+            ),
+            Self::MissingRequiredProperty { property } => write!(
+                f,
+                "required property `{property}` is not defined in properties"
+            ),
+        }
+    }
+}
+
+impl OpenApi {
+    /// Validate the [`OpenApi`] document and return all found [`ValidationIssue`]s. Empty list
+    /// means that no issues were found.
+    ///
+    /// Following checks are performed:
+    /// * Every local `$ref` to _`#/components/...`_ resolves to a defined component. External
+    ///   references are not checked.
+    /// * Every `operationId` is unique across paths, webhooks and callbacks.
+    /// * Every path template parameter e.g. `{id}` has a matching [`ParameterIn::Path`]
+    ///   parameter defined either in the [`Operation`] or in the [`PathItem`].
+    /// * Every [`SecurityRequirement`] only names security schemes declared in
+    ///   [`Components::security_schemes`].
+    /// * Every `required` property of an object schema with `properties` is defined in its
+    ///   `properties`.
+    ///
+    /// See also [`utoipa::testing::assert_valid`][assert_valid] for asserting clean document in
+    /// tests.
+    ///
+    /// # Examples
+    ///
+    /// _**Find path parameter that is not documented.**_
+    /// ```rust
+    /// # use utoipa::openapi::{HttpMethod, OpenApiBuilder, PathItem, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// # use utoipa::openapi::validation::ValidationIssueKind;
+    /// let api = OpenApiBuilder::new()
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets/{id}",
+    ///         PathItem::new(HttpMethod::Get, OperationBuilder::new().operation_id(Some("get_pet"))),
+    ///     ))
+    ///     .build();
+    ///
+    /// let issues = api.validate();
+    /// assert_eq!(issues.len(), 1);
+    /// assert_eq!(issues[0].location, "/paths/~1pets~1{id}/get");
+    /// assert!(matches!(
+    ///     &issues[0].kind,
+    ///     ValidationIssueKind::MissingPathParameter { name } if name == "id"
+    /// ));
+    /// ```
+    ///
+    /// [assert_valid]: ../../testing/fn.assert_valid.html
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut validator = Validator {
+            components: self.components.as_ref(),
+            path: None,
+            operation_ids: BTreeMap::new(),
+            issues: Vec::new(),
+        };
+
+        validator.visit_openapi(self, &mut Location::new());
+
+        validator.issues
+    }
+}
+
+struct Validator<'a> {
+    components: Option<&'a Components>,
+    /// Path template and [`PathItem`] of _`paths`_ currently being visited.
+    path: Option<(String, &'a PathItem)>,
+    operation_ids: BTreeMap<String, String>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<'a> Visit<'a> for Validator<'a> {
+    fn visit_path_item(&mut self, path_item: &'a PathItem, location: &mut Location) {
+        // only path items of paths have path template parameters, webhooks, callbacks and
+        // component path items are not checked
+        let path = match location.segments() {
+            [paths, path] if paths == "paths" => Some((path.clone(), path_item)),
+            _ => None,
+        };
+        let previous = std::mem::replace(&mut self.path, path);
+        visit::walk_path_item(self, path_item, location);
+        self.path = previous;
+    }
+
+    fn visit_operation(&mut self, operation: &'a Operation, location: &mut Location) {
+        if let Some(operation_id) = &operation.operation_id {
+            if let Some(first_location) = self.operation_ids.get(operation_id) {
+                let first_location = first_location.clone();
+                self.issue(
+                    location,
+                    ValidationIssueKind::DuplicateOperationId {
+                        operation_id: operation_id.clone(),
+                        first_location,
+                    },
+                );
+            } else {
+                self.operation_ids
+                    .insert(operation_id.clone(), location.pointer());
+            }
+        }
+
+        // path is taken for the duration of the walk so that path items of callbacks are not
+        // considered as part of the path
+        let path = self.path.take();
+        visit::walk_operation(self, operation, location);
+
+        if let Some((path, path_item)) = &path {
+            self.validate_path_parameters(path, path_item, operation, location);
+        }
+        self.path = path;
+    }
+
+    fn visit_security_requirement(
+        &mut self,
+        security_requirement: &'a SecurityRequirement,
+        location: &mut Location,
+    ) {
+        for name in security_requirement.value.keys() {
+            let is_declared = self
+                .components
+                .is_some_and(|components| components.security_schemes.contains_key(name));
+            if !is_declared {
+                self.issue(
+                    location,
+                    ValidationIssueKind::UndeclaredSecurityScheme { name: name.clone() },
+                );
+            }
+        }
+    }
+
+    fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+        if let Schema::Object(object) = schema {
+            if !object.properties.is_empty() {
+                location.push("required");
+                for property in &object.required {
+                    if !object.properties.contains_key(property) {
+                        self.issue(
+                            location,
+                            ValidationIssueKind::MissingRequiredProperty {
+                                property: property.clone(),
+                            },
+                        );
+                    }
+                }
+                location.pop();
+            }
+        }
+
+        visit::walk_schema(self, schema, location)
+    }
+
+    fn visit_ref(&mut self, reference: &'a Ref, location: &mut Location) {
+        let Some(pointer) = reference.ref_location.strip_prefix("#/components/") else {
+            // only local component references are validated
+            return;
+        };
+
+        let mut segments = pointer.splitn(3, '/');
+        let is_resolved = match (segments.next(), segments.next(), self.components) {
+            (Some(kind), Some(name), Some(components)) => {
+                let name = unescape_pointer_segment(name);
+                let rest = segments.next();
+
+                match kind {
+                    "schemas" => resolves(&components.schemas, &name, rest),
+                    "responses" => resolves(&components.responses, &name, rest),
+                    "parameters" => resolves(&components.parameters, &name, rest),
+                    "examples" => resolves(&components.examples, &name, rest),
+                    "requestBodies" => resolves(&components.request_bodies, &name, rest),
+                    "headers" => resolves(&components.headers, &name, rest),
+                    "securitySchemes" => resolves(&components.security_schemes, &name, rest),
+                    "links" => resolves(&components.links, &name, rest),
+                    "callbacks" => resolves(&components.callbacks, &name, rest),
+                    "pathItems" => resolves(&components.path_items, &name, rest),
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
+        if !is_resolved {
+            self.issue(
+                location,
+                ValidationIssueKind::UnresolvedReference {
+                    reference: reference.ref_location.clone(),
+                },
+            );
+        }
+    }
+}
+
+/// Check that component `name` exists in `components` and that the `rest` of the reference
+/// e.g. _`properties/name`_ of _`#/components/schemas/Pet/properties/name`_ resolves to a value
+/// within the serialized component.
+fn resolves<T: Serialize>(
+    components: &BTreeMap<String, T>,
+    name: &str,
+    rest: Option<&str>,
+) -> bool {
+    match (components.get(name), rest) {
+        (Some(_), None) => true,
+        (Some(component), Some(rest)) => serde_json::to_value(component)
+            .is_ok_and(|component| component.pointer(&format!("/{rest}")).is_some()),
+        (None, _) => false,
+    }
+}
+
+impl<'a> Validator<'a> {
+    fn validate_path_parameters(
+        &mut self,
+        path: &str,
+        path_item: &'a PathItem,
+        operation: &'a Operation,
+        location: &Location,
+    ) {
+        let parameters = path_item
+            .parameters
+            .iter()
+            .chain(operation.parameters.iter())
+            .flatten()
+            .filter_map(|parameter| self.resolve_parameter(parameter))
+            .filter(|parameter| parameter.parameter_in == ParameterIn::Path)
+            .map(|parameter| parameter.name.as_str())
+            .collect::<Vec<_>>();
+
+        for name in path_template_parameters(path) {
+            if !parameters.contains(&name) {
+                self.issue(
+                    location,
+                    ValidationIssueKind::MissingPathParameter {
+                        name: name.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn resolve_parameter(&self, parameter: &'a RefOr<Parameter>) -> Option<&'a Parameter> {
+        match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => {
+                let name = reference
+                    .ref_location
+                    .strip_prefix("#/components/parameters/")?;
+                match self
+                    .components?
+                    .parameters
+                    .get(&unescape_pointer_segment(name))?
+                {
+                    RefOr::T(parameter) => Some(parameter),
+                    RefOr::Ref(_) => None,
+                }
+            }
+        }
+    }
+
+    fn issue(&mut self, location: &Location, kind: ValidationIssueKind) {
+        self.issues.push(ValidationIssue {
+            location: location.pointer(),
+            kind,
+        });
+    }
+}
+
+/// Get names of template parameters e.g. `id` of `/pets/{id}` from the path.
+fn path_template_parameters(path: &str) -> impl Iterator<Item = &str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|segment| segment.split_once('}').map(|(name, _)| name))
+}
+
+pub(super) fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+pub(crate) fn unescape_pointer_segment(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    use crate::openapi::{
+        ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder, PathItem,
+        PathsBuilder, Ref, ResponseBuilder, SecurityRequirement, Type,
+    };
+
+    use super::*;
+
+    fn issues(api: &OpenApi) -> Vec<(String, ValidationIssueKind)> {
+        api.validate()
+            .into_iter()
+            .map(|issue| (issue.location, issue.kind))
+            .collect()
+    }
+
+    #[test]
+    fn validate_valid_openapi_has_no_issues() {
+        let api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .operation_id(Some("get_pet"))
+                            .parameter(Ref::from_parameter_name("id"))
+                            .response(
+                                "200",
+                                ResponseBuilder::new().content(
+                                    "application/json",
+                                    ContentBuilder::new()
+                                        .schema(Some(Ref::from_schema_name("Pet")))
+                                        .build(),
+                                ),
+                            )
+                            .security(SecurityRequirement::new("api_key", [] as [&str; 0])),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .required("name"),
+                    )
+                    .parameter(
+                        "id",
+                        ParameterBuilder::new()
+                            .name("id")
+                            .parameter_in(ParameterIn::Path),
+                    )
+                    .security_scheme(
+                        "api_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(issues(&api), Vec::new());
+    }
+
+    #[test]
+    fn validate_unresolved_references() {
+        let api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        HttpMethod::Post,
+                        OperationBuilder::new()
+                            .response("200", Ref::from_response_name("Pets"))
+                            .response(
+                                "201",
+                                ResponseBuilder::new().content(
+                                    "application/json",
+                                    ContentBuilder::new()
+                                        .schema(Some(Ref::from_schema_name("Pet")))
+                                        .build(),
+                                ),
+                            ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new().property("owner", Ref::from_schema_name("a/b")),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![
+                (
+                    "/paths/~1pets/post/responses/200".to_string(),
+                    ValidationIssueKind::UnresolvedReference {
+                        reference: "#/components/responses/Pets".to_string()
+                    }
+                ),
+                (
+                    "/components/schemas/Pet/properties/owner".to_string(),
+                    ValidationIssueKind::UnresolvedReference {
+                        reference: "#/components/schemas/a/b".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_references_within_components() {
+        let api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .property("tag", Ref::new("#/components/schemas/Pet/$defs/Tag"))
+                            .def("Tag", ObjectBuilder::new().schema_type(Type::String)),
+                    )
+                    .schema(
+                        "Owner",
+                        ObjectBuilder::new()
+                            .property("name", Ref::new("#/components/schemas/Pet/properties/name"))
+                            .property("age", Ref::new("#/components/schemas/Pet/properties/age")),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![(
+                "/components/schemas/Owner/properties/age".to_string(),
+                ValidationIssueKind::UnresolvedReference {
+                    reference: "#/components/schemas/Pet/properties/age".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_operation_ids() {
+        let api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new().operation_id(Some("pets")),
+                        ),
+                    )
+                    .path(
+                        "/users",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new().operation_id(Some("pets")),
+                        ),
+                    ),
+            )
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![(
+                "/paths/~1users/get".to_string(),
+                ValidationIssueKind::DuplicateOperationId {
+                    operation_id: "pets".to_string(),
+                    first_location: "/paths/~1pets/get".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_path_parameters() {
+        let api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/owners/{owner_id}/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .parameter(
+                                ParameterBuilder::new()
+                                    .name("id")
+                                    .parameter_in(ParameterIn::Query),
+                            )
+                            .parameter(
+                                ParameterBuilder::new()
+                                    .name("owner_id")
+                                    .parameter_in(ParameterIn::Path),
+                            ),
+                    ),
+                ),
+            )
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![(
+                "/paths/~1owners~1{owner_id}~1pets~1{id}/get".to_string(),
+                ValidationIssueKind::MissingPathParameter {
+                    name: "id".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_undeclared_security_schemes() {
+        let api = OpenApiBuilder::new()
+            .security(Some([SecurityRequirement::new("oauth", ["read:pets"])]))
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![(
+                "/security/0".to_string(),
+                ValidationIssueKind::UndeclaredSecurityScheme {
+                    name: "oauth".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_missing_required_properties() {
+        let api = OpenApiBuilder::new()
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .required("name")
+                            .required("age"),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            issues(&api),
+            vec![(
+                "/components/schemas/Pet/required".to_string(),
+                ValidationIssueKind::MissingRequiredProperty {
+                    property: "age".to_string()
+                }
+            )]
+        );
+    }
+}
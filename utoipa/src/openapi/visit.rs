@@ -0,0 +1,1374 @@
+//! Implements traversal of the [`OpenApi`] document with [`Visit`] and [`VisitMut`] traits.
+//!
+//! Both traits have a method per node type of the document with a default implementation
+//! walking the children of the node with corresponding _`walk_*`_ function. Implementors only
+//! override the methods of the nodes they are interested in. Each method is given the
+//! [`Location`] of the node in the document which can be formatted as
+//! [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+//!
+//! Overridden method must call the corresponding _`walk_*`_ function to continue the traversal
+//! to the children of the node.
+//!
+//! # Examples
+//!
+//! _**Collect all schema references of the document.**_
+//! ```rust
+//! # use utoipa::openapi::{OpenApi, Ref};
+//! # use utoipa::openapi::visit::{Location, Visit};
+//! #[derive(Default)]
+//! struct References(Vec<(String, String)>);
+//!
+//! impl<'a> Visit<'a> for References {
+//!     fn visit_ref(&mut self, reference: &'a Ref, location: &mut Location) {
+//!         self.0.push((location.pointer(), reference.ref_location.clone()));
+//!     }
+//! }
+//!
+//! # let api = OpenApi::default();
+//! let mut references = References::default();
+//! references.visit_openapi(&api, &mut Location::new());
+//! ```
+//!
+//! _**Add description to every operation missing one in [`Modify`][modify].**_
+//! ```rust
+//! # use utoipa::Modify;
+//! # use utoipa::openapi::OpenApi;
+//! # use utoipa::openapi::path::Operation;
+//! # use utoipa::openapi::visit::{self, Location, VisitMut};
+//! struct DefaultDescription;
+//!
+//! impl VisitMut for DefaultDescription {
+//!     fn visit_operation_mut(&mut self, operation: &mut Operation, location: &mut Location) {
+//!         operation.description.get_or_insert_with(|| "No description".to_string());
+//!         visit::walk_operation_mut(self, operation, location);
+//!     }
+//! }
+//!
+//! impl Modify for DefaultDescription {
+//!     fn modify(&self, openapi: &mut OpenApi) {
+//!         DefaultDescription.visit_openapi_mut(openapi, &mut Location::new());
+//!     }
+//! }
+//! ```
+//!
+//! [modify]: ../../trait.Modify.html
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use super::callback::Callback;
+use super::content::Content;
+use super::example::Example;
+use super::header::Header;
+use super::link::Link;
+use super::path::{Operation, Parameter, PathItem};
+use super::request_body::RequestBody;
+use super::response::Response;
+use super::schema::{AdditionalProperties, ArrayItems, Components, Schema};
+use super::security::{SecurityRequirement, SecurityScheme};
+use super::validation::escape_pointer_segment;
+use super::{OpenApi, Ref, RefOr};
+
+/// Location of the visited node in the [`OpenApi`] document.
+///
+/// Location consists of the unescaped reference tokens of
+/// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) e.g. `["paths", "/pets",
+/// "get"]` which is formatted as `/paths/~1pets/get`.
+#[non_exhaustive]
+#[derive(Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Location {
+    segments: Vec<String>,
+}
+
+impl Location {
+    /// Construct a new empty [`Location`] pointing to the root of the document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get unescaped segments of the location.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Format the location as JSON Pointer e.g. _`/paths/~1pets/get`_.
+    pub fn pointer(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| format!("/{}", escape_pointer_segment(segment)))
+            .collect()
+    }
+
+    /// Append new `segment` to the end of the location.
+    pub fn push<S: ToString>(&mut self, segment: S) {
+        self.segments.push(segment.to_string());
+    }
+
+    /// Remove the last segment of the location.
+    pub fn pop(&mut self) -> Option<String> {
+        self.segments.pop()
+    }
+
+    fn with<S: ToString>(&mut self, segment: S, f: impl FnOnce(&mut Self)) {
+        self.push(segment);
+        f(self);
+        self.pop();
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.pointer())
+    }
+}
+
+/// Traverse [`OpenApi`] document by shared reference.
+///
+/// See the [module level documentation][self] for more details.
+pub trait Visit<'a> {
+    /// Visit the root [`OpenApi`] document.
+    fn visit_openapi(&mut self, openapi: &'a OpenApi, location: &mut Location) {
+        walk_openapi(self, openapi, location)
+    }
+
+    /// Visit [`PathItem`] of paths, webhooks, callbacks or components.
+    fn visit_path_item(&mut self, path_item: &'a PathItem, location: &mut Location) {
+        walk_path_item(self, path_item, location)
+    }
+
+    /// Visit [`Operation`] of a [`PathItem`].
+    fn visit_operation(&mut self, operation: &'a Operation, location: &mut Location) {
+        walk_operation(self, operation, location)
+    }
+
+    /// Visit [`Parameter`] of a [`PathItem`], [`Operation`] or components.
+    fn visit_parameter(&mut self, parameter: &'a Parameter, location: &mut Location) {
+        walk_parameter(self, parameter, location)
+    }
+
+    /// Visit [`RequestBody`] of an [`Operation`] or components.
+    fn visit_request_body(&mut self, request_body: &'a RequestBody, location: &mut Location) {
+        walk_request_body(self, request_body, location)
+    }
+
+    /// Visit [`Response`] of an [`Operation`] or components.
+    fn visit_response(&mut self, response: &'a Response, location: &mut Location) {
+        walk_response(self, response, location)
+    }
+
+    /// Visit [`Content`] of a [`RequestBody`] or [`Response`].
+    fn visit_content(&mut self, content: &'a Content, location: &mut Location) {
+        walk_content(self, content, location)
+    }
+
+    /// Visit [`Header`] of a [`Response`], encoding or components.
+    fn visit_header(&mut self, header: &'a Header, location: &mut Location) {
+        walk_header(self, header, location)
+    }
+
+    /// Visit [`Callback`] of an [`Operation`] or components.
+    fn visit_callback(&mut self, callback: &'a Callback, location: &mut Location) {
+        walk_callback(self, callback, location)
+    }
+
+    /// Visit [`Components`] of the [`OpenApi`] document.
+    fn visit_components(&mut self, components: &'a Components, location: &mut Location) {
+        walk_components(self, components, location)
+    }
+
+    /// Visit [`Example`] of a [`Content`] or components.
+    fn visit_example(&mut self, _example: &'a Example, _location: &mut Location) {}
+
+    /// Visit [`Link`] of a [`Response`] or components.
+    fn visit_link(&mut self, _link: &'a Link, _location: &mut Location) {}
+
+    /// Visit [`SecurityScheme`] of components.
+    fn visit_security_scheme(
+        &mut self,
+        _security_scheme: &'a SecurityScheme,
+        _location: &mut Location,
+    ) {
+    }
+
+    /// Visit [`SecurityRequirement`] of the [`OpenApi`] document or an [`Operation`].
+    fn visit_security_requirement(
+        &mut self,
+        _security_requirement: &'a SecurityRequirement,
+        _location: &mut Location,
+    ) {
+    }
+
+    /// Visit [`Schema`] including all nested schemas.
+    fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+        walk_schema(self, schema, location)
+    }
+
+    /// Visit [`Ref`] found in place of any referenceable node.
+    fn visit_ref(&mut self, _reference: &'a Ref, _location: &mut Location) {}
+}
+
+/// Walk paths, webhooks, components and security requirements of the [`OpenApi`] document.
+pub fn walk_openapi<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    openapi: &'a OpenApi,
+    location: &mut Location,
+) {
+    location.with("paths", |location| {
+        for (path, path_item) in &openapi.paths.paths {
+            location.with(path, |location| {
+                visitor.visit_path_item(path_item, location)
+            });
+        }
+    });
+    location.with("webhooks", |location| {
+        for (name, path_item) in &openapi.webhooks {
+            location.with(name, |location| {
+                visitor.visit_path_item(path_item, location)
+            });
+        }
+    });
+    if let Some(components) = &openapi.components {
+        location.with("components", |location| {
+            visitor.visit_components(components, location)
+        });
+    }
+    if let Some(security) = &openapi.security {
+        walk_security(visitor, security, location);
+    }
+}
+
+/// Walk parameters and operations of the [`PathItem`].
+pub fn walk_path_item<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    path_item: &'a PathItem,
+    location: &mut Location,
+) {
+    if let Some(parameters) = &path_item.parameters {
+        walk_parameters(visitor, parameters, location);
+    }
+    for (http_method, operation) in path_item.operations() {
+        location.with(http_method.as_str(), |location| {
+            visitor.visit_operation(operation, location)
+        });
+    }
+}
+
+/// Walk parameters, request body, responses, callbacks and security requirements of the
+/// [`Operation`].
+pub fn walk_operation<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    operation: &'a Operation,
+    location: &mut Location,
+) {
+    if let Some(parameters) = &operation.parameters {
+        walk_parameters(visitor, parameters, location);
+    }
+    if let Some(request_body) = &operation.request_body {
+        location.with("requestBody", |location| {
+            visitor.visit_request_body(request_body, location)
+        });
+    }
+    location.with("responses", |location| {
+        for (status, response) in &operation.responses.responses {
+            location.with(status, |location| {
+                walk_ref_or(visitor, response, location, V::visit_response)
+            });
+        }
+    });
+    if let Some(callbacks) = &operation.callbacks {
+        location.with("callbacks", |location| {
+            for (name, callback) in callbacks {
+                location.with(name, |location| {
+                    walk_ref_or(visitor, callback, location, V::visit_callback)
+                });
+            }
+        });
+    }
+    if let Some(security) = &operation.security {
+        walk_security(visitor, security, location);
+    }
+}
+
+/// Walk schema of the [`Parameter`].
+pub fn walk_parameter<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    parameter: &'a Parameter,
+    location: &mut Location,
+) {
+    if let Some(schema) = &parameter.schema {
+        location.with("schema", |location| {
+            walk_ref_or(visitor, schema, location, V::visit_schema)
+        });
+    }
+}
+
+/// Walk content of the [`RequestBody`].
+pub fn walk_request_body<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    request_body: &'a RequestBody,
+    location: &mut Location,
+) {
+    location.with("content", |location| {
+        for (content_type, content) in &request_body.content {
+            location.with(content_type, |location| {
+                visitor.visit_content(content, location)
+            });
+        }
+    });
+}
+
+/// Walk headers, content and links of the [`Response`].
+pub fn walk_response<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    response: &'a Response,
+    location: &mut Location,
+) {
+    location.with("headers", |location| {
+        for (name, header) in &response.headers {
+            location.with(name, |location| visitor.visit_header(header, location));
+        }
+    });
+    location.with("content", |location| {
+        for (content_type, content) in &response.content {
+            location.with(content_type, |location| {
+                visitor.visit_content(content, location)
+            });
+        }
+    });
+    location.with("links", |location| {
+        for (name, link) in &response.links {
+            location.with(name, |location| {
+                walk_ref_or(visitor, link, location, V::visit_link)
+            });
+        }
+    });
+}
+
+/// Walk schema, examples and encoding headers of the [`Content`].
+pub fn walk_content<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    content: &'a Content,
+    location: &mut Location,
+) {
+    if let Some(schema) = &content.schema {
+        location.with("schema", |location| {
+            walk_ref_or(visitor, schema, location, V::visit_schema)
+        });
+    }
+    location.with("examples", |location| {
+        for (name, example) in &content.examples {
+            location.with(name, |location| {
+                walk_ref_or(visitor, example, location, V::visit_example)
+            });
+        }
+    });
+    location.with("encoding", |location| {
+        for (property, encoding) in &content.encoding {
+            location.with(property, |location| {
+                location.with("headers", |location| {
+                    for (name, header) in &encoding.headers {
+                        location.with(name, |location| visitor.visit_header(header, location));
+                    }
+                })
+            });
+        }
+    });
+}
+
+/// Walk schema of the [`Header`].
+pub fn walk_header<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    header: &'a Header,
+    location: &mut Location,
+) {
+    location.with("schema", |location| {
+        walk_ref_or(visitor, &header.schema, location, V::visit_schema)
+    });
+}
+
+/// Walk path items of the [`Callback`].
+pub fn walk_callback<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    callback: &'a Callback,
+    location: &mut Location,
+) {
+    for (expression, path_item) in &callback.paths {
+        location.with(expression, |location| {
+            visitor.visit_path_item(path_item, location)
+        });
+    }
+}
+
+/// Walk all reusable objects of the [`Components`].
+pub fn walk_components<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    components: &'a Components,
+    location: &mut Location,
+) {
+    location.with("schemas", |location| {
+        for (name, schema) in &components.schemas {
+            location.with(name, |location| {
+                walk_ref_or(visitor, schema, location, V::visit_schema)
+            });
+        }
+    });
+    location.with("responses", |location| {
+        for (name, response) in &components.responses {
+            location.with(name, |location| {
+                walk_ref_or(visitor, response, location, V::visit_response)
+            });
+        }
+    });
+    location.with("parameters", |location| {
+        for (name, parameter) in &components.parameters {
+            location.with(name, |location| {
+                walk_ref_or(visitor, parameter, location, V::visit_parameter)
+            });
+        }
+    });
+    location.with("examples", |location| {
+        for (name, example) in &components.examples {
+            location.with(name, |location| {
+                walk_ref_or(visitor, example, location, V::visit_example)
+            });
+        }
+    });
+    location.with("requestBodies", |location| {
+        for (name, request_body) in &components.request_bodies {
+            location.with(name, |location| {
+                walk_ref_or(visitor, request_body, location, V::visit_request_body)
+            });
+        }
+    });
+    location.with("headers", |location| {
+        for (name, header) in &components.headers {
+            location.with(name, |location| {
+                walk_ref_or(visitor, header, location, V::visit_header)
+            });
+        }
+    });
+    location.with("securitySchemes", |location| {
+        for (name, security_scheme) in &components.security_schemes {
+            location.with(name, |location| {
+                visitor.visit_security_scheme(security_scheme, location)
+            });
+        }
+    });
+    location.with("links", |location| {
+        for (name, link) in &components.links {
+            location.with(name, |location| {
+                walk_ref_or(visitor, link, location, V::visit_link)
+            });
+        }
+    });
+    location.with("callbacks", |location| {
+        for (name, callback) in &components.callbacks {
+            location.with(name, |location| {
+                walk_ref_or(visitor, callback, location, V::visit_callback)
+            });
+        }
+    });
+    location.with("pathItems", |location| {
+        for (name, path_item) in &components.path_items {
+            location.with(name, |location| {
+                walk_ref_or(visitor, path_item, location, V::visit_path_item)
+            });
+        }
+    });
+}
+
+/// Walk all nested schemas of the [`Schema`] e.g. properties, array items and composite
+/// schema items.
+pub fn walk_schema<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    schema: &'a Schema,
+    location: &mut Location,
+) {
+    let walk_schemas =
+        |visitor: &mut V,
+         location: &mut Location,
+         key: &str,
+         schemas: &mut dyn Iterator<Item = (String, &'a RefOr<Schema>)>| {
+            location.with(key, |location| {
+                for (segment, schema) in schemas {
+                    location.with(segment, |location| {
+                        walk_ref_or(visitor, schema, location, V::visit_schema)
+                    });
+                }
+            });
+        };
+
+    match schema {
+        Schema::Object(object) => {
+            walk_schemas(
+                visitor,
+                location,
+                "properties",
+                &mut object.properties.iter().map(|(k, v)| (k.clone(), v)),
+            );
+            if let Some(additional_properties) = &object.additional_properties {
+                location.with("additionalProperties", |location| {
+                    walk_additional_properties(visitor, additional_properties, location)
+                });
+            }
+            if let Some(property_names) = &object.property_names {
+                location.with("propertyNames", |location| {
+                    visitor.visit_schema(property_names, location)
+                });
+            }
+            walk_schemas(
+                visitor,
+                location,
+                "patternProperties",
+                &mut object
+                    .pattern_properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v)),
+            );
+            walk_schemas(
+                visitor,
+                location,
+                "dependentSchemas",
+                &mut object.dependent_schemas.iter().map(|(k, v)| (k.clone(), v)),
+            );
+            if let Some(unevaluated_properties) = &object.unevaluated_properties {
+                location.with("unevaluatedProperties", |location| {
+                    walk_additional_properties(visitor, unevaluated_properties, location)
+                });
+            }
+            for (key, schema) in [
+                ("not", &object.not),
+                ("if", &object.if_schema),
+                ("then", &object.then_schema),
+                ("else", &object.else_schema),
+            ] {
+                if let Some(schema) = schema {
+                    location.with(key, |location| {
+                        walk_ref_or(visitor, schema, location, V::visit_schema)
+                    });
+                }
+            }
+            walk_schemas(
+                visitor,
+                location,
+                "$defs",
+                &mut object.defs.iter().map(|(k, v)| (k.clone(), v)),
+            );
+        }
+        Schema::Array(array) => {
+            if let ArrayItems::RefOrSchema(items) = &array.items {
+                location.with("items", |location| {
+                    walk_ref_or(visitor, items, location, V::visit_schema)
+                });
+            }
+            location.with("prefixItems", |location| {
+                for (index, item) in array.prefix_items.iter().enumerate() {
+                    location.with(index, |location| visitor.visit_schema(item, location));
+                }
+            });
+            if let Some(contains) = &array.contains {
+                location.with("contains", |location| {
+                    walk_ref_or(visitor, contains, location, V::visit_schema)
+                });
+            }
+        }
+        Schema::OneOf(one_of) => walk_schemas(
+            visitor,
+            location,
+            "oneOf",
+            &mut one_of
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::AllOf(all_of) => walk_schemas(
+            visitor,
+            location,
+            "allOf",
+            &mut all_of
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::AnyOf(any_of) => walk_schemas(
+            visitor,
+            location,
+            "anyOf",
+            &mut any_of
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::Bool(_) => (),
+    }
+}
+
+fn walk_parameters<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    parameters: &'a [RefOr<Parameter>],
+    location: &mut Location,
+) {
+    location.with("parameters", |location| {
+        for (index, parameter) in parameters.iter().enumerate() {
+            location.with(index, |location| {
+                walk_ref_or(visitor, parameter, location, V::visit_parameter)
+            });
+        }
+    });
+}
+
+fn walk_security<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    security: &'a [SecurityRequirement],
+    location: &mut Location,
+) {
+    location.with("security", |location| {
+        for (index, requirement) in security.iter().enumerate() {
+            location.with(index, |location| {
+                visitor.visit_security_requirement(requirement, location)
+            });
+        }
+    });
+}
+
+fn walk_additional_properties<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    additional_properties: &'a AdditionalProperties<Schema>,
+    location: &mut Location,
+) {
+    if let AdditionalProperties::RefOr(schema) = additional_properties {
+        walk_ref_or(visitor, schema, location, V::visit_schema);
+    }
+}
+
+pub(super) fn walk_ref_or<'a, V: Visit<'a> + ?Sized, T>(
+    visitor: &mut V,
+    ref_or: &'a RefOr<T>,
+    location: &mut Location,
+    visit: impl FnOnce(&mut V, &'a T, &mut Location),
+) {
+    match ref_or {
+        RefOr::Ref(reference) => visitor.visit_ref(reference, location),
+        RefOr::T(value) => visit(visitor, value, location),
+    }
+}
+
+/// Traverse [`OpenApi`] document by mutable reference.
+///
+/// See the [module level documentation][self] for more details.
+pub trait VisitMut {
+    /// Visit the root [`OpenApi`] document.
+    fn visit_openapi_mut(&mut self, openapi: &mut OpenApi, location: &mut Location) {
+        walk_openapi_mut(self, openapi, location)
+    }
+
+    /// Visit [`PathItem`] of paths, webhooks, callbacks or components.
+    fn visit_path_item_mut(&mut self, path_item: &mut PathItem, location: &mut Location) {
+        walk_path_item_mut(self, path_item, location)
+    }
+
+    /// Visit [`Operation`] of a [`PathItem`].
+    fn visit_operation_mut(&mut self, operation: &mut Operation, location: &mut Location) {
+        walk_operation_mut(self, operation, location)
+    }
+
+    /// Visit [`Parameter`] of a [`PathItem`], [`Operation`] or components.
+    fn visit_parameter_mut(&mut self, parameter: &mut Parameter, location: &mut Location) {
+        walk_parameter_mut(self, parameter, location)
+    }
+
+    /// Visit [`RequestBody`] of an [`Operation`] or components.
+    fn visit_request_body_mut(&mut self, request_body: &mut RequestBody, location: &mut Location) {
+        walk_request_body_mut(self, request_body, location)
+    }
+
+    /// Visit [`Response`] of an [`Operation`] or components.
+    fn visit_response_mut(&mut self, response: &mut Response, location: &mut Location) {
+        walk_response_mut(self, response, location)
+    }
+
+    /// Visit [`Content`] of a [`RequestBody`] or [`Response`].
+    fn visit_content_mut(&mut self, content: &mut Content, location: &mut Location) {
+        walk_content_mut(self, content, location)
+    }
+
+    /// Visit [`Header`] of a [`Response`], encoding or components.
+    fn visit_header_mut(&mut self, header: &mut Header, location: &mut Location) {
+        walk_header_mut(self, header, location)
+    }
+
+    /// Visit [`Callback`] of an [`Operation`] or components.
+    fn visit_callback_mut(&mut self, callback: &mut Callback, location: &mut Location) {
+        walk_callback_mut(self, callback, location)
+    }
+
+    /// Visit [`Components`] of the [`OpenApi`] document.
+    fn visit_components_mut(&mut self, components: &mut Components, location: &mut Location) {
+        walk_components_mut(self, components, location)
+    }
+
+    /// Visit [`Example`] of a [`Content`] or components.
+    fn visit_example_mut(&mut self, _example: &mut Example, _location: &mut Location) {}
+
+    /// Visit [`Link`] of a [`Response`] or components.
+    fn visit_link_mut(&mut self, _link: &mut Link, _location: &mut Location) {}
+
+    /// Visit [`SecurityScheme`] of components.
+    fn visit_security_scheme_mut(
+        &mut self,
+        _security_scheme: &mut SecurityScheme,
+        _location: &mut Location,
+    ) {
+    }
+
+    /// Visit [`SecurityRequirement`] of the [`OpenApi`] document or an [`Operation`].
+    fn visit_security_requirement_mut(
+        &mut self,
+        _security_requirement: &mut SecurityRequirement,
+        _location: &mut Location,
+    ) {
+    }
+
+    /// Visit [`Schema`] including all nested schemas.
+    fn visit_schema_mut(&mut self, schema: &mut Schema, location: &mut Location) {
+        walk_schema_mut(self, schema, location)
+    }
+
+    /// Visit [`Ref`] found in place of any referenceable node.
+    fn visit_ref_mut(&mut self, _reference: &mut Ref, _location: &mut Location) {}
+
+    /// Visit referenceable node which is either a [`Ref`] or an inline [`Component`]. Can be
+    /// used to replace the reference with the referenced component.
+    fn visit_ref_or_mut<T: Component>(&mut self, ref_or: &mut RefOr<T>, location: &mut Location) {
+        walk_ref_or_mut(self, ref_or, location)
+    }
+}
+
+/// Walk paths, webhooks, components and security requirements of the [`OpenApi`] document.
+pub fn walk_openapi_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    openapi: &mut OpenApi,
+    location: &mut Location,
+) {
+    location.with("paths", |location| {
+        for (path, path_item) in openapi.paths.paths.iter_mut() {
+            location.with(path, |location| {
+                visitor.visit_path_item_mut(path_item, location)
+            });
+        }
+    });
+    location.with("webhooks", |location| {
+        for (name, path_item) in openapi.webhooks.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_path_item_mut(path_item, location)
+            });
+        }
+    });
+    if let Some(components) = &mut openapi.components {
+        location.with("components", |location| {
+            visitor.visit_components_mut(components, location)
+        });
+    }
+    if let Some(security) = &mut openapi.security {
+        walk_security_mut(visitor, security, location);
+    }
+}
+
+/// Walk parameters and operations of the [`PathItem`].
+pub fn walk_path_item_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    path_item: &mut PathItem,
+    location: &mut Location,
+) {
+    if let Some(parameters) = &mut path_item.parameters {
+        walk_parameters_mut(visitor, parameters, location);
+    }
+    for (http_method, operation) in path_item.operations_mut() {
+        location.with(http_method.as_str(), |location| {
+            visitor.visit_operation_mut(operation, location)
+        });
+    }
+}
+
+/// Walk parameters, request body, responses, callbacks and security requirements of the
+/// [`Operation`].
+pub fn walk_operation_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    operation: &mut Operation,
+    location: &mut Location,
+) {
+    if let Some(parameters) = &mut operation.parameters {
+        walk_parameters_mut(visitor, parameters, location);
+    }
+    if let Some(request_body) = &mut operation.request_body {
+        location.with("requestBody", |location| {
+            visitor.visit_request_body_mut(request_body, location)
+        });
+    }
+    location.with("responses", |location| {
+        for (status, response) in operation.responses.responses.iter_mut() {
+            location.with(status, |location| {
+                visitor.visit_ref_or_mut(response, location)
+            });
+        }
+    });
+    if let Some(callbacks) = &mut operation.callbacks {
+        location.with("callbacks", |location| {
+            for (name, callback) in callbacks.iter_mut() {
+                location.with(name, |location| {
+                    visitor.visit_ref_or_mut(callback, location)
+                });
+            }
+        });
+    }
+    if let Some(security) = &mut operation.security {
+        walk_security_mut(visitor, security, location);
+    }
+}
+
+/// Walk schema of the [`Parameter`].
+pub fn walk_parameter_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    parameter: &mut Parameter,
+    location: &mut Location,
+) {
+    if let Some(schema) = &mut parameter.schema {
+        location.with("schema", |location| {
+            visitor.visit_ref_or_mut(schema, location)
+        });
+    }
+}
+
+/// Walk content of the [`RequestBody`].
+pub fn walk_request_body_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    request_body: &mut RequestBody,
+    location: &mut Location,
+) {
+    location.with("content", |location| {
+        for (content_type, content) in request_body.content.iter_mut() {
+            location.with(content_type, |location| {
+                visitor.visit_content_mut(content, location)
+            });
+        }
+    });
+}
+
+/// Walk headers, content and links of the [`Response`].
+pub fn walk_response_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    response: &mut Response,
+    location: &mut Location,
+) {
+    location.with("headers", |location| {
+        for (name, header) in response.headers.iter_mut() {
+            location.with(name, |location| visitor.visit_header_mut(header, location));
+        }
+    });
+    location.with("content", |location| {
+        for (content_type, content) in response.content.iter_mut() {
+            location.with(content_type, |location| {
+                visitor.visit_content_mut(content, location)
+            });
+        }
+    });
+    location.with("links", |location| {
+        for (name, link) in response.links.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(link, location));
+        }
+    });
+}
+
+/// Walk schema, examples and encoding headers of the [`Content`].
+pub fn walk_content_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    content: &mut Content,
+    location: &mut Location,
+) {
+    if let Some(schema) = &mut content.schema {
+        location.with("schema", |location| {
+            visitor.visit_ref_or_mut(schema, location)
+        });
+    }
+    location.with("examples", |location| {
+        for (name, example) in content.examples.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(example, location));
+        }
+    });
+    location.with("encoding", |location| {
+        for (property, encoding) in content.encoding.iter_mut() {
+            location.with(property, |location| {
+                location.with("headers", |location| {
+                    for (name, header) in encoding.headers.iter_mut() {
+                        location.with(name, |location| visitor.visit_header_mut(header, location));
+                    }
+                })
+            });
+        }
+    });
+}
+
+/// Walk schema of the [`Header`].
+pub fn walk_header_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    header: &mut Header,
+    location: &mut Location,
+) {
+    location.with("schema", |location| {
+        visitor.visit_ref_or_mut(&mut header.schema, location)
+    });
+}
+
+/// Walk path items of the [`Callback`].
+pub fn walk_callback_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    callback: &mut Callback,
+    location: &mut Location,
+) {
+    for (expression, path_item) in callback.paths.iter_mut() {
+        location.with(expression, |location| {
+            visitor.visit_path_item_mut(path_item, location)
+        });
+    }
+}
+
+/// Walk all reusable objects of the [`Components`].
+pub fn walk_components_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    components: &mut Components,
+    location: &mut Location,
+) {
+    location.with("schemas", |location| {
+        for (name, schema) in components.schemas.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(schema, location));
+        }
+    });
+    location.with("responses", |location| {
+        for (name, response) in components.responses.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_ref_or_mut(response, location)
+            });
+        }
+    });
+    location.with("parameters", |location| {
+        for (name, parameter) in components.parameters.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_ref_or_mut(parameter, location)
+            });
+        }
+    });
+    location.with("examples", |location| {
+        for (name, example) in components.examples.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(example, location));
+        }
+    });
+    location.with("requestBodies", |location| {
+        for (name, request_body) in components.request_bodies.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_ref_or_mut(request_body, location)
+            });
+        }
+    });
+    location.with("headers", |location| {
+        for (name, header) in components.headers.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(header, location));
+        }
+    });
+    location.with("securitySchemes", |location| {
+        for (name, security_scheme) in components.security_schemes.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_security_scheme_mut(security_scheme, location)
+            });
+        }
+    });
+    location.with("links", |location| {
+        for (name, link) in components.links.iter_mut() {
+            location.with(name, |location| visitor.visit_ref_or_mut(link, location));
+        }
+    });
+    location.with("callbacks", |location| {
+        for (name, callback) in components.callbacks.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_ref_or_mut(callback, location)
+            });
+        }
+    });
+    location.with("pathItems", |location| {
+        for (name, path_item) in components.path_items.iter_mut() {
+            location.with(name, |location| {
+                visitor.visit_ref_or_mut(path_item, location)
+            });
+        }
+    });
+}
+
+/// Walk all nested schemas of the [`Schema`] e.g. properties, array items and composite
+/// schema items.
+pub fn walk_schema_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    schema: &mut Schema,
+    location: &mut Location,
+) {
+    let walk_schemas =
+        |visitor: &mut V,
+         location: &mut Location,
+         key: &str,
+         schemas: &mut dyn Iterator<Item = (String, &mut RefOr<Schema>)>| {
+            location.with(key, |location| {
+                for (segment, schema) in schemas {
+                    location.with(segment, |location| {
+                        visitor.visit_ref_or_mut(schema, location)
+                    });
+                }
+            });
+        };
+
+    match schema {
+        Schema::Object(object) => {
+            walk_schemas(
+                visitor,
+                location,
+                "properties",
+                &mut object.properties.iter_mut().map(|(k, v)| (k.clone(), v)),
+            );
+            if let Some(additional_properties) = &mut object.additional_properties {
+                location.with("additionalProperties", |location| {
+                    walk_additional_properties_mut(visitor, additional_properties, location)
+                });
+            }
+            if let Some(property_names) = &mut object.property_names {
+                location.with("propertyNames", |location| {
+                    visitor.visit_schema_mut(property_names, location)
+                });
+            }
+            walk_schemas(
+                visitor,
+                location,
+                "patternProperties",
+                &mut object
+                    .pattern_properties
+                    .iter_mut()
+                    .map(|(k, v)| (k.clone(), v)),
+            );
+            walk_schemas(
+                visitor,
+                location,
+                "dependentSchemas",
+                &mut object
+                    .dependent_schemas
+                    .iter_mut()
+                    .map(|(k, v)| (k.clone(), v)),
+            );
+            if let Some(unevaluated_properties) = &mut object.unevaluated_properties {
+                location.with("unevaluatedProperties", |location| {
+                    walk_additional_properties_mut(visitor, unevaluated_properties, location)
+                });
+            }
+            for (key, schema) in [
+                ("not", &mut object.not),
+                ("if", &mut object.if_schema),
+                ("then", &mut object.then_schema),
+                ("else", &mut object.else_schema),
+            ] {
+                if let Some(schema) = schema {
+                    location.with(key, |location| visitor.visit_ref_or_mut(schema, location));
+                }
+            }
+            walk_schemas(
+                visitor,
+                location,
+                "$defs",
+                &mut object.defs.iter_mut().map(|(k, v)| (k.clone(), v)),
+            );
+        }
+        Schema::Array(array) => {
+            if let ArrayItems::RefOrSchema(items) = &mut array.items {
+                location.with("items", |location| {
+                    visitor.visit_ref_or_mut(items, location)
+                });
+            }
+            location.with("prefixItems", |location| {
+                for (index, item) in array.prefix_items.iter_mut().enumerate() {
+                    location.with(index, |location| visitor.visit_schema_mut(item, location));
+                }
+            });
+            if let Some(contains) = &mut array.contains {
+                location.with("contains", |location| {
+                    visitor.visit_ref_or_mut(contains, location)
+                });
+            }
+        }
+        Schema::OneOf(one_of) => walk_schemas(
+            visitor,
+            location,
+            "oneOf",
+            &mut one_of
+                .items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::AllOf(all_of) => walk_schemas(
+            visitor,
+            location,
+            "allOf",
+            &mut all_of
+                .items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::AnyOf(any_of) => walk_schemas(
+            visitor,
+            location,
+            "anyOf",
+            &mut any_of
+                .items
+                .iter_mut()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v)),
+        ),
+        Schema::Bool(_) => (),
+    }
+}
+
+fn walk_parameters_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    parameters: &mut [RefOr<Parameter>],
+    location: &mut Location,
+) {
+    location.with("parameters", |location| {
+        for (index, parameter) in parameters.iter_mut().enumerate() {
+            location.with(index, |location| {
+                visitor.visit_ref_or_mut(parameter, location)
+            });
+        }
+    });
+}
+
+fn walk_security_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    security: &mut [SecurityRequirement],
+    location: &mut Location,
+) {
+    location.with("security", |location| {
+        for (index, requirement) in security.iter_mut().enumerate() {
+            location.with(index, |location| {
+                visitor.visit_security_requirement_mut(requirement, location)
+            });
+        }
+    });
+}
+
+fn walk_additional_properties_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    additional_properties: &mut AdditionalProperties<Schema>,
+    location: &mut Location,
+) {
+    if let AdditionalProperties::RefOr(schema) = additional_properties {
+        visitor.visit_ref_or_mut(schema, location);
+    }
+}
+
+/// Walk the [`Ref`] with [`VisitMut::visit_ref_mut`] or the inline value with the
+/// corresponding method of [`VisitMut`] e.g. [`VisitMut::visit_schema_mut`].
+pub fn walk_ref_or_mut<V: VisitMut + ?Sized, T: Component>(
+    visitor: &mut V,
+    ref_or: &mut RefOr<T>,
+    location: &mut Location,
+) {
+    match ref_or {
+        RefOr::Ref(reference) => visitor.visit_ref_mut(reference, location),
+        RefOr::T(value) => T::visit_mut(visitor, value, location),
+    }
+}
+
+/// Reusable object of [`Components`] which can be defined either inline or as [`Ref`] to the
+/// components e.g. [`Schema`] or [`Response`].
+pub trait Component: Clone + 'static {
+    /// Name of the components field in the serialized document e.g. _`schemas`_.
+    const KIND: &'static str;
+
+    /// Get all reusable objects of this type from the [`Components`].
+    fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>>;
+
+    /// Visit the object with the corresponding method of the [`VisitMut`].
+    fn visit_mut<V: VisitMut + ?Sized>(visitor: &mut V, value: &mut Self, location: &mut Location);
+}
+
+macro_rules! impl_component {
+    ( $( $ty:ty => $kind:literal, $field:ident, $visit:ident );* $(;)? ) => {
+        $(
+            impl Component for $ty {
+                const KIND: &'static str = $kind;
+
+                fn components(components: &Components) -> &BTreeMap<String, RefOr<Self>> {
+                    &components.$field
+                }
+
+                fn visit_mut<V: VisitMut + ?Sized>(
+                    visitor: &mut V,
+                    value: &mut Self,
+                    location: &mut Location,
+                ) {
+                    visitor.$visit(value, location)
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(
+    Schema => "schemas", schemas, visit_schema_mut;
+    Response => "responses", responses, visit_response_mut;
+    Parameter => "parameters", parameters, visit_parameter_mut;
+    Example => "examples", examples, visit_example_mut;
+    RequestBody => "requestBodies", request_bodies, visit_request_body_mut;
+    Header => "headers", headers, visit_header_mut;
+    Link => "links", links, visit_link_mut;
+    Callback => "callbacks", callbacks, visit_callback_mut;
+    PathItem => "pathItems", path_items, visit_path_item_mut;
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, ParameterBuilder, ParameterIn};
+    use crate::openapi::{
+        ArrayBuilder, ComponentsBuilder, ContentBuilder, HttpMethod, ObjectBuilder, OpenApiBuilder,
+        PathItem, PathsBuilder, ResponseBuilder, Type,
+    };
+
+    use super::*;
+
+    fn api() -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        HttpMethod::Get,
+                        OperationBuilder::new()
+                            .parameter(
+                                ParameterBuilder::new()
+                                    .name("id")
+                                    .parameter_in(ParameterIn::Path)
+                                    .schema(Some(ObjectBuilder::new().schema_type(Type::Integer))),
+                            )
+                            .response(
+                                "200",
+                                ResponseBuilder::new().content(
+                                    "application/json",
+                                    ContentBuilder::new()
+                                        .schema(Some(Ref::from_schema_name("Pet")))
+                                        .build(),
+                                ),
+                            ),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .schema(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property("name", ObjectBuilder::new().schema_type(Type::String))
+                            .property(
+                                "tags",
+                                ArrayBuilder::new().items(Ref::from_schema_name("Tag")),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[derive(Default)]
+    struct Collector {
+        schemas: Vec<String>,
+        references: Vec<(String, String)>,
+    }
+
+    impl<'a> Visit<'a> for Collector {
+        fn visit_schema(&mut self, schema: &'a Schema, location: &mut Location) {
+            self.schemas.push(location.pointer());
+            walk_schema(self, schema, location);
+        }
+
+        fn visit_ref(&mut self, reference: &'a Ref, location: &mut Location) {
+            self.references
+                .push((location.pointer(), reference.ref_location.clone()));
+        }
+    }
+
+    #[test]
+    fn visit_collects_nodes_with_locations() {
+        let api = api();
+        let mut collector = Collector::default();
+
+        collector.visit_openapi(&api, &mut Location::new());
+
+        assert_eq!(
+            collector.schemas,
+            [
+                "/paths/~1pets~1{id}/get/parameters/0/schema",
+                "/components/schemas/Pet",
+                "/components/schemas/Pet/properties/name",
+                "/components/schemas/Pet/properties/tags",
+            ]
+        );
+        assert_eq!(
+            collector.references,
+            [
+                (
+                    "/paths/~1pets~1{id}/get/responses/200/content/application~1json/schema"
+                        .to_string(),
+                    "#/components/schemas/Pet".to_string()
+                ),
+                (
+                    "/components/schemas/Pet/properties/tags/items".to_string(),
+                    "#/components/schemas/Tag".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn visit_mut_modifies_nodes() {
+        struct DescribeOperations;
+
+        impl VisitMut for DescribeOperations {
+            fn visit_operation_mut(&mut self, operation: &mut Operation, location: &mut Location) {
+                operation.description = Some(location.pointer());
+                walk_operation_mut(self, operation, location);
+            }
+
+            fn visit_ref_mut(&mut self, reference: &mut Ref, _: &mut Location) {
+                reference.ref_location = reference.ref_location.replace("Pet", "Animal");
+            }
+        }
+
+        let mut api = api();
+
+        DescribeOperations.visit_openapi_mut(&mut api, &mut Location::new());
+
+        let operation = api
+            .paths
+            .get_path_operation("/pets/{id}", HttpMethod::Get)
+            .expect("operation must exist");
+        assert_eq!(
+            operation.description.as_deref(),
+            Some("/paths/~1pets~1{id}/get")
+        );
+        let content = match &operation.responses.responses["200"] {
+            RefOr::T(response) => &response.content["application/json"],
+            RefOr::Ref(_) => unreachable!("response must be inline"),
+        };
+        assert_eq!(
+            content.schema,
+            Some(RefOr::Ref(Ref::new("#/components/schemas/Animal")))
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Test helpers for asserting generated OpenAPI documents in unit and integration tests.
+
+use crate::lint::{Linter, Severity};
+use crate::openapi::OpenApi;
+
+/// Assert that the [`OpenApi`] document has no issues reported by [`OpenApi::validate`].
+///
+/// # Panics
+///
+/// Panics listing every found [`ValidationIssue`][issue] if the document is not valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::openapi::OpenApiBuilder;
+/// let api = OpenApiBuilder::new().build();
+///
+/// utoipa::testing::assert_valid(&api);
+/// ```
+///
+/// [issue]: ../openapi/validation/struct.ValidationIssue.html
+#[track_caller]
+pub fn assert_valid(api: &OpenApi) {
+    let issues = api.validate();
+
+    if !issues.is_empty() {
+        let issues = issues
+            .iter()
+            .map(|issue| format!("  * {issue}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("OpenAPI document has validation issues:\n{issues}");
+    }
+}
+
+/// Assert that every declared example of the [`OpenApi`] document is valid against its schema
+/// as reported by [`OpenApi::validate_examples`].
+///
+/// This is useful for catching examples left stale after changing the documented types.
+///
+/// # Panics
+///
+/// Panics listing every found [`ExampleError`][error] if any of the examples is not valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use serde_json::json;
+/// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, OpenApiBuilder, Type};
+/// let api = OpenApiBuilder::new()
+///     .components(Some(
+///         ComponentsBuilder::new()
+///             .schema(
+///                 "Pet",
+///                 ObjectBuilder::new()
+///                     .property("name", ObjectBuilder::new().schema_type(Type::String))
+///                     .examples([json!({"name": "Garfield"})]),
+///             )
+///             .build(),
+///     ))
+///     .build();
+///
+/// utoipa::testing::assert_examples_valid(&api);
+/// ```
+///
+/// [error]: ../openapi/instance_validation/struct.ExampleError.html
+#[cfg(feature = "instance_validation")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "instance_validation")))]
+#[track_caller]
+pub fn assert_examples_valid(api: &OpenApi) {
+    let errors = api.validate_examples();
+
+    if !errors.is_empty() {
+        let errors = errors
+            .iter()
+            .map(|error| format!("  * {error}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("OpenAPI document has invalid examples:\n{errors}");
+    }
+}
+
+/// Assert that [`Linter`] reports no issues with [`Severity::Error`] for the [`OpenApi`]
+/// document.
+///
+/// Issues with lower severity are ignored thus the severities of the enforced rules should be
+/// raised with [`Linter::severity`].
+///
+/// # Panics
+///
+/// Panics listing every found error if any of the rules reports an issue with
+/// [`Severity::Error`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::lint::{Linter, Severity};
+/// # use utoipa::openapi::OpenApiBuilder;
+/// let api = OpenApiBuilder::new().build();
+/// let linter = Linter::default().severity("operation-id", Severity::Error);
+///
+/// utoipa::testing::assert_no_lint_errors(&api, &linter);
+/// ```
+#[track_caller]
+pub fn assert_no_lint_errors(api: &OpenApi, linter: &Linter) {
+    let report = linter.lint(api);
+
+    if report.has_errors() {
+        let errors = report
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .map(|issue| format!("  * {issue}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        panic!("OpenAPI document has lint errors:\n{errors}");
+    }
+}